@@ -20,38 +20,55 @@
 //! - [`markets`] - Market availability endpoints
 //! - [`player`] - Playback control endpoints
 //! - [`playlists`] - Playlist-related endpoints
+//! - [`recommendations`] - Track recommendation endpoints
 //! - [`search`] - Search endpoints
 //! - [`shows`] - Podcast show endpoints
 //! - [`tracks`] - Track-related endpoints
 //! - [`users`] - User profile and follow endpoints
 
+mod batch;
+#[cfg(any(test, feature = "cache"))]
+mod cache;
 mod client;
+mod dry_run;
 mod endpoint;
 mod error;
 mod ignore;
 mod paged;
 mod params;
 mod raw;
+#[cfg(any(test, feature = "record"))]
+mod record;
 
 pub mod common;
 pub(crate) mod query;
 
 pub mod albums;
 pub mod artists;
+#[cfg(feature = "audiobooks")]
 pub mod audiobooks;
 pub mod categories;
+#[cfg(feature = "audiobooks")]
 pub mod chapters;
+#[cfg(feature = "shows")]
 pub mod episodes;
 pub mod genres;
 pub mod markets;
+#[cfg(feature = "player")]
 pub mod player;
 pub mod playlists;
+pub mod recommendations;
 pub mod search;
+#[cfg(feature = "shows")]
 pub mod shows;
 pub mod tracks;
 pub mod users;
 
+pub use batch::*;
+#[cfg(any(test, feature = "cache"))]
+pub use cache::*;
 pub use client::*;
+pub use dry_run::*;
 pub use endpoint::*;
 pub use error::*;
 pub use ignore::*;
@@ -59,11 +76,14 @@ pub use paged::*;
 pub use params::*;
 pub use query::{AsyncQuery, Query};
 pub use raw::*;
+#[cfg(any(test, feature = "record"))]
+pub use record::*;
 
 mod prelude {
-    pub use super::Pageable;
+    pub use super::{CursorPageable, Pageable};
     pub use crate::{
-        api::{BodyError, Endpoint, JsonParams, QueryParams},
+        api::{BodyError, Endpoint, IdsPlacement, JsonParams, QueryParams},
+        auth::scopes::Scope,
         model::Market,
     };
     pub use http::Method;
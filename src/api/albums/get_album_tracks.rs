@@ -48,8 +48,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_album_tracks_endpoint() {
@@ -63,4 +64,40 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_album_tracks_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("albums/66q0q162WOFuidbucN1Jdp/tracks")
+            .add_query_params(&[("market", "JP")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (1..=53).map(|track_number| json!({ "track_number": track_number })),
+        );
+
+        let endpoint = GetAlbumTracks {
+            market: Some("JP".parse().unwrap()),
+            ..GetAlbumTracks::from("66q0q162WOFuidbucN1Jdp")
+        };
+
+        let tracks: Vec<Value> = api::paged_all(endpoint).query(&client).unwrap();
+        assert_eq!(tracks.len(), 53);
+        assert_eq!(tracks[52], json!({ "track_number": 53 }));
+
+        let queries = client
+            .requests()
+            .iter()
+            .map(|url| url.query().unwrap_or_default().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            queries,
+            [
+                "market=JP&offset=0&limit=50",
+                "market=JP&offset=50&limit=50"
+            ]
+        );
+    }
 }
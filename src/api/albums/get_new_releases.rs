@@ -2,7 +2,25 @@ use crate::api::prelude::*;
 
 /// Get a list of new album releases featured in Spotify (shown, for example, on a Spotify player’s “Browse” tab).
 #[derive(Default, Debug, Clone)]
-pub struct GetNewReleases;
+pub struct GetNewReleases {
+    /// The maximum number of items to return. Default: 20. Minimum: 1. Maximum: 50.
+    pub limit: Option<u8>,
+
+    /// The index of the first item to return. Default: 0 (the first item). Use with `limit` to get the next set of items.
+    pub offset: Option<u32>,
+}
+
+impl GetNewReleases {
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
 
 impl Endpoint for GetNewReleases {
     fn method(&self) -> Method {
@@ -12,13 +30,20 @@ impl Endpoint for GetNewReleases {
     fn endpoint(&self) -> Cow<'static, str> {
         "browse/new-releases".into()
     }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("limit", self.limit);
+        params.push_opt("offset", self.offset);
+        params
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        api::Query as _,
+        api::{self, Query as _},
         model::NewReleases,
         test::client::{ExpectedUrl, SingleTestClient},
     };
@@ -83,8 +108,22 @@ mod tests {
 
         let client = SingleTestClient::new_raw(endpoint, RESPONSE);
 
-        let new_releases: NewReleases = GetNewReleases.query(&client).unwrap();
+        let new_releases: NewReleases = GetNewReleases::default().query(&client).unwrap();
 
         assert!(new_releases.albums.total == 1);
     }
+
+    #[test]
+    fn test_get_new_releases_endpoint_with_limit_and_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/new-releases")
+            .add_query_params(&[("limit", "10"), ("offset", "5")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        api::ignore(GetNewReleases::default().limit(10).offset(5))
+            .query(&client)
+            .unwrap();
+    }
 }
@@ -1,4 +1,4 @@
-use crate::api::prelude::*;
+use crate::{api::prelude::*, model::AlbumId};
 
 /// Get Spotify catalog information for multiple albums identified by their Spotify IDs.
 #[derive(Debug, Clone)]
@@ -29,6 +29,22 @@ where
     }
 }
 
+impl GetSeveralAlbums {
+    /// Creates the endpoint from validated album IDs.
+    ///
+    /// IDs of other item types are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use spotify_web_api::{api::albums::GetSeveralAlbums, model::TrackId};
+    ///
+    /// let id = TrackId::from_id("11dFghVXANMlKmJXsNCbNl").unwrap();
+    /// let endpoint = GetSeveralAlbums::from([id]);
+    /// ```
+    pub fn from_ids(ids: impl IntoIterator<Item = AlbumId>) -> Self {
+        Self::from(ids.into_iter().map(|id| id.id().to_owned()))
+    }
+}
+
 impl Endpoint for GetSeveralAlbums {
     fn method(&self) -> Method {
         Method::GET
@@ -51,6 +67,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
+        model::AlbumId,
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -74,4 +91,30 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_several_albums_endpoint_from_typed_ids() {
+        let ids = [
+            "382ObEPsp2rxGrnsizN5TX",
+            "1A2GTWGtFfWp7KSQTwWOyo",
+            "2noRn2Aes5aoNVsU6iWThc",
+        ];
+
+        let typed = GetSeveralAlbums::from_ids(ids.map(|id| AlbumId::from_id(id).unwrap()));
+        let strings = GetSeveralAlbums::from(ids);
+
+        assert_eq!(typed.ids, strings.ids);
+
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("albums")
+            .add_query_params(&[(
+                "ids",
+                "382ObEPsp2rxGrnsizN5TX,1A2GTWGtFfWp7KSQTwWOyo,2noRn2Aes5aoNVsU6iWThc",
+            )])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        api::ignore(typed).query(&client).unwrap();
+    }
 }
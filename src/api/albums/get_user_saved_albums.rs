@@ -47,8 +47,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_user_saved_albums_endpoint() {
@@ -60,4 +61,39 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_user_saved_albums_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/albums")
+            .add_query_params(&[("market", "ES")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..60).map(|i| json!({ "id": i })));
+
+        let albums: Vec<Value> = api::paged_all(GetUserSavedAlbums::from("ES"))
+            .query(&client)
+            .unwrap();
+        assert_eq!(albums.len(), 60);
+        assert_eq!(client.page_params(), [(0, 50), (50, 50)]);
+    }
+
+    #[test]
+    fn test_get_user_saved_albums_endpoint_with_limit_and_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/albums")
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..60).map(|i| json!({ "id": i })));
+
+        let albums: Vec<Value> =
+            api::paged_with_limit_and_offset(GetUserSavedAlbums::default(), 20, 40)
+                .query(&client)
+                .unwrap();
+        assert_eq!(albums.len(), 20);
+        assert_eq!(albums[0], json!({ "id": 40 }));
+        assert_eq!(client.page_params(), [(40, 20)]);
+    }
 }
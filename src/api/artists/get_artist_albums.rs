@@ -83,8 +83,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_artist_albums_endpoint() {
@@ -114,4 +115,25 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_artist_albums_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("artists/0TnOYISbd1XYRBk9myaseg/albums")
+            .add_query_params(&[("include_groups", "album,compilation"), ("market", "ES")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..60).map(|i| json!({ "id": i })));
+
+        let endpoint = GetArtistAlbums::new("0TnOYISbd1XYRBk9myaseg")
+            .include_group(AlbumType::Album)
+            .include_group(AlbumType::Compilation)
+            .market("ES".parse().unwrap());
+
+        let albums: Vec<Value> = api::paged_all(endpoint).query(&client).unwrap();
+        assert_eq!(albums.len(), 60);
+
+        assert_eq!(client.page_params(), [(0, 50), (50, 50)]);
+    }
 }
@@ -61,4 +61,21 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_artist_top_tracks_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("artists/0TnOYISbd1XYRBk9myaseg/top-tracks")
+            .add_query_params(&[("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetArtistTopTracks {
+            market: Some("ES".parse().unwrap()),
+            ..GetArtistTopTracks::from("0TnOYISbd1XYRBk9myaseg")
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -1,4 +1,4 @@
-use crate::api::prelude::*;
+use crate::{api::prelude::*, model::ArtistId};
 
 /// Get Spotify catalog information for several artists based on their Spotify IDs.
 #[derive(Debug, Clone)]
@@ -19,6 +19,13 @@ where
     }
 }
 
+impl GetSeveralArtists {
+    /// Creates the endpoint from validated artist IDs.
+    pub fn from_ids(ids: impl IntoIterator<Item = ArtistId>) -> Self {
+        Self::from(ids.into_iter().map(|id| id.id().to_owned()))
+    }
+}
+
 impl Endpoint for GetSeveralArtists {
     fn method(&self) -> Method {
         Method::GET
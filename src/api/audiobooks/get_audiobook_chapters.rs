@@ -49,8 +49,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_audiobook_chapters_endpoint() {
@@ -64,4 +65,26 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_audiobook_chapters_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audiobooks/7iHfbu1YPACw6oZPAFJtqe/chapters")
+            .add_query_params(&[("market", "US")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..60).map(|i| json!({ "id": i })));
+
+        let endpoint = GetAudiobookChapters {
+            market: Some("US".parse().unwrap()),
+            ..GetAudiobookChapters::from("7iHfbu1YPACw6oZPAFJtqe")
+        };
+
+        let chapters: Vec<Value> = api::paged_all(endpoint).query(&client).unwrap();
+        assert_eq!(chapters.len(), 60);
+        assert_eq!(chapters[59], json!({ "id": 59 }));
+
+        assert_eq!(client.page_params(), [(0, 50), (50, 50)]);
+    }
 }
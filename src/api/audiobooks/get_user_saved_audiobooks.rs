@@ -21,8 +21,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_user_saved_audiobooks_endpoint() {
@@ -30,4 +31,21 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
         api::ignore(GetUserSavedAudiobooks).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_user_saved_audiobooks_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/audiobooks")
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..120).map(|i| json!({ "id": i })));
+
+        let audiobooks: Vec<Value> = api::paged_take(GetUserSavedAudiobooks, 75)
+            .query(&client)
+            .unwrap();
+        assert_eq!(audiobooks.len(), 75);
+
+        assert_eq!(client.page_params(), [(0, 50), (50, 25)]);
+    }
 }
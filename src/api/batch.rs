@@ -0,0 +1,411 @@
+#[cfg(feature = "audiobooks")]
+use crate::api::audiobooks::CheckUserSavedAudiobooks;
+use crate::api::{
+    ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query as _, albums::CheckUserSavedAlbums,
+    ignore, tracks::CheckUserSavedTracks,
+};
+#[cfg(feature = "shows")]
+use crate::api::{episodes::CheckUserSavedEpisodes, shows::CheckUserSavedShows};
+use futures_util::{StreamExt as _, TryStreamExt as _, stream};
+use serde::de::DeserializeOwned;
+use std::error::Error;
+
+/// The outcome of an operation split into several chunked requests.
+///
+/// Chunks are sent in order and the operation stops at the first failing chunk, so the
+/// items before [`BatchOutcome::succeeded`] have been applied and the rest have not.
+#[derive(Debug)]
+pub struct BatchOutcome<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The number of items whose chunks succeeded.
+    pub succeeded: usize,
+
+    /// The index of the chunk which failed, along with its error.
+    pub failed_at: Option<(usize, ApiError<E>)>,
+}
+
+impl<E> BatchOutcome<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Whether every chunk succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failed_at.is_none()
+    }
+
+    /// Converts the outcome into a `Result`, discarding how far the operation got on failure.
+    ///
+    /// # Errors
+    /// Returns the error of the failed chunk, if any.
+    pub fn into_result(self) -> Result<usize, ApiError<E>> {
+        match self.failed_at {
+            Some((_, err)) => Err(err),
+            None => Ok(self.succeeded),
+        }
+    }
+}
+
+/// Apply an endpoint to `items` in chunks of at most `chunk_size`, one request per chunk.
+///
+/// This is useful for mutations which accept a limited number of items per request, such
+/// as [`SaveTracksForCurrentUser`](super::tracks::SaveTracksForCurrentUser) (50 IDs) or
+/// [`AddItemsToPlaylist`](super::playlists::AddItemsToPlaylist) (100 URIs). The responses
+/// are ignored. Sending stops at the first failing chunk, and the returned [`BatchOutcome`]
+/// reports how many items were applied, so the operation can be resumed from there.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::{batched, tracks::SaveTracksForCurrentUser};
+///
+/// # fn example(client: &impl spotify_web_api::api::Client, ids: Vec<String>) {
+/// let outcome = batched(client, &ids, 50, |chunk| SaveTracksForCurrentUser::from(chunk));
+///
+/// if let Some((chunk, err)) = &outcome.failed_at {
+///     eprintln!("chunk {chunk} failed: {err}");
+///     let remaining = &ids[outcome.succeeded..];
+/// }
+/// # }
+/// ```
+///
+/// # Panics
+/// Panics if `chunk_size` is zero.
+pub fn batched<C, T, E, F>(
+    client: &C,
+    items: &[T],
+    chunk_size: usize,
+    mut endpoint: F,
+) -> BatchOutcome<C::Error>
+where
+    C: Client,
+    E: Endpoint,
+    F: FnMut(&[T]) -> E,
+{
+    let mut succeeded = 0;
+
+    for (index, chunk) in items.chunks(chunk_size).enumerate() {
+        if let Err(err) = ignore(endpoint(chunk)).query(client) {
+            return BatchOutcome {
+                succeeded,
+                failed_at: Some((index, err)),
+            };
+        }
+        succeeded += chunk.len();
+    }
+
+    BatchOutcome {
+        succeeded,
+        failed_at: None,
+    }
+}
+
+/// Asynchronously apply an endpoint to `items` in chunks of at most `chunk_size`.
+///
+/// See [`batched`] for details. Chunks are still sent one after another, so that a failure
+/// leaves a well-defined prefix of the items applied.
+///
+/// # Panics
+/// Panics if `chunk_size` is zero.
+pub async fn batched_async<C, T, E, F>(
+    client: &C,
+    items: &[T],
+    chunk_size: usize,
+    mut endpoint: F,
+) -> BatchOutcome<C::Error>
+where
+    C: AsyncClient + Sync,
+    E: Endpoint + Sync,
+    F: FnMut(&[T]) -> E,
+{
+    let mut succeeded = 0;
+
+    for (index, chunk) in items.chunks(chunk_size).enumerate() {
+        if let Err(err) = ignore(endpoint(chunk)).query_async(client).await {
+            return BatchOutcome {
+                succeeded,
+                failed_at: Some((index, err)),
+            };
+        }
+        succeeded += chunk.len();
+    }
+
+    BatchOutcome {
+        succeeded,
+        failed_at: None,
+    }
+}
+
+/// Asynchronously query a "contains" endpoint for `items` in chunks of at most `chunk_size`,
+/// with at most `max_concurrent` requests in flight at once.
+///
+/// Endpoints such as [`CheckUserSavedTracks`] return one boolean per ID, but only accept a
+/// limited number of IDs per request. The answers for each chunk are reassembled in the order
+/// of `items`. If any chunk fails, the whole query fails with its error and the requests
+/// still in flight are dropped. A `max_concurrent` of zero is treated as one.
+///
+/// # Panics
+/// Panics if `chunk_size` is zero.
+pub async fn batched_contains_async<C, T, E, F>(
+    client: &C,
+    items: &[T],
+    chunk_size: usize,
+    max_concurrent: usize,
+    endpoint: F,
+) -> Result<Vec<bool>, ApiError<C::Error>>
+where
+    C: AsyncClient + Sync,
+    E: Endpoint + Sync,
+    F: FnMut(&[T]) -> E,
+{
+    let chunks: Vec<Vec<bool>> =
+        query_chunks_async(client, items, chunk_size, max_concurrent, endpoint).await?;
+
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Asynchronously query an endpoint for `items` in chunks of at most `chunk_size`, with at most
+/// `max_concurrent` requests in flight at once, returning the response of each chunk in order.
+pub(crate) async fn query_chunks_async<C, T, R, E, F>(
+    client: &C,
+    items: &[T],
+    chunk_size: usize,
+    max_concurrent: usize,
+    endpoint: F,
+) -> Result<Vec<R>, ApiError<C::Error>>
+where
+    C: AsyncClient + Sync,
+    R: DeserializeOwned + Send + 'static,
+    E: Endpoint + Sync,
+    F: FnMut(&[T]) -> E,
+{
+    let endpoints = items.chunks(chunk_size).map(endpoint).collect::<Vec<_>>();
+
+    stream::iter(&endpoints)
+        .map(|endpoint| AsyncQuery::<R, C>::query_async(endpoint, client))
+        .buffered(max_concurrent.max(1))
+        .try_collect()
+        .await
+}
+
+macro_rules! impl_query_all {
+    ($($endpoint:ident => $chunk_size:literal),* $(,)?) => {
+        $(
+            impl $endpoint {
+                #[doc = concat!(
+                    "Asynchronously check any number of IDs, sending them in chunks of ",
+                    stringify!($chunk_size),
+                    " with at most `max_concurrent` requests in flight.\n\n",
+                    "See [`batched_contains_async`] for details.\n\n",
+                    "# Errors\n",
+                    "Returns the error of the first chunk which failed.",
+                )]
+                pub async fn query_all<C>(
+                    &self,
+                    client: &C,
+                    max_concurrent: usize,
+                ) -> Result<Vec<bool>, ApiError<C::Error>>
+                where
+                    C: AsyncClient + Sync,
+                {
+                    batched_contains_async(client, &self.ids, $chunk_size, max_concurrent, |chunk| {
+                        Self::from(chunk)
+                    })
+                    .await
+                }
+            }
+        )*
+    };
+}
+
+impl_query_all![
+    CheckUserSavedAlbums => 20,
+    CheckUserSavedTracks => 50,
+];
+
+#[cfg(feature = "audiobooks")]
+impl_query_all![CheckUserSavedAudiobooks => 50];
+
+#[cfg(feature = "shows")]
+impl_query_all![
+    CheckUserSavedEpisodes => 50,
+    CheckUserSavedShows => 50,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{RestClient, tracks::SaveTracksForCurrentUser},
+        test::client::{ExpectedUrl, MultiTestClient},
+    };
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Method, Response, StatusCode, request::Builder as RequestBuilder};
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use url::Url;
+
+    const IDS: [&str; 5] = ["a", "b", "c", "d", "e"];
+
+    fn save(ids: &[&str]) -> SaveTracksForCurrentUser {
+        SaveTracksForCurrentUser::from(ids.iter().copied())
+    }
+
+    fn chunk(ids: &'static str, status: StatusCode) -> (ExpectedUrl, serde_json::Value) {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("me/tracks")
+            .add_query_params(&[("ids", ids)])
+            .status(status)
+            .build();
+        let body = if status.is_success() {
+            json!({})
+        } else {
+            json!({ "error": { "status": status.as_u16(), "message": "failed" } })
+        };
+        (endpoint, body)
+    }
+
+    #[test]
+    fn test_batched_all_chunks_succeed() {
+        let client = MultiTestClient::new_json([
+            chunk("a,b", StatusCode::OK),
+            chunk("c,d", StatusCode::OK),
+            chunk("e", StatusCode::OK),
+        ]);
+
+        let outcome = batched(&client, &IDS, 2, save);
+
+        assert!(outcome.is_complete());
+        assert_eq!(outcome.into_result().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_batched_second_chunk_fails() {
+        // The third chunk is not registered, so sending it would panic.
+        let client = MultiTestClient::new_json([
+            chunk("a,b", StatusCode::OK),
+            chunk("c,d", StatusCode::INTERNAL_SERVER_ERROR),
+        ]);
+
+        let outcome = batched(&client, &IDS, 2, save);
+
+        assert_eq!(outcome.succeeded, 2);
+        let (index, err) = outcome.failed_at.unwrap();
+        assert_eq!(index, 1);
+        assert!(matches!(
+            err,
+            ApiError::SpotifyObjectWithStatus {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                ..
+            }
+        ));
+    }
+
+    /// Wraps a client to measure how many requests are in flight at once.
+    struct ConcurrencyProbe {
+        inner: MultiTestClient,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    impl RestClient for ConcurrencyProbe {
+        type Error = <MultiTestClient as RestClient>::Error;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            self.inner.rest_endpoint(endpoint)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for ConcurrencyProbe {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            let rsp = self.inner.rest_async(request, body).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            rsp
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_all_preserves_order_and_bounds_concurrency() {
+        let ids = (0..200).map(|i| format!("id{i:03}")).collect::<Vec<_>>();
+        let saved = |id: &String| id.ends_with('7');
+
+        let responses = ids.chunks(50).map(|chunk| {
+            let mut endpoint = ExpectedUrl::builder()
+                .endpoint("me/tracks/contains")
+                .build();
+            endpoint.query.push(("ids".into(), chunk.join(",").into()));
+            (endpoint, chunk.iter().map(saved).collect::<Vec<_>>())
+        });
+        let client = ConcurrencyProbe {
+            inner: MultiTestClient::new_json(responses),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+
+        let result = CheckUserSavedTracks::from(&ids)
+            .query_all(&client, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(result, ids.iter().map(saved).collect::<Vec<_>>());
+        assert_eq!(client.max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_query_all_fails_on_any_chunk() {
+        let client = MultiTestClient::new_json([
+            (
+                ExpectedUrl::builder()
+                    .endpoint("me/albums/contains")
+                    .add_query_params(&[("ids", "a,b")])
+                    .build(),
+                serde_json::to_value([true, false]).unwrap(),
+            ),
+            (
+                ExpectedUrl::builder()
+                    .endpoint("me/albums/contains")
+                    .add_query_params(&[("ids", "c")])
+                    .status(StatusCode::BAD_GATEWAY)
+                    .build(),
+                json!({ "error": { "status": 502, "message": "failed" } }),
+            ),
+        ]);
+
+        let result = batched_contains_async(&client, &["a", "b", "c"], 2, 4, |chunk| {
+            CheckUserSavedAlbums::from(chunk.iter().copied())
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ApiError::SpotifyObjectWithStatus {
+                status: StatusCode::BAD_GATEWAY,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batched_async_second_chunk_fails() {
+        let client = MultiTestClient::new_json([
+            chunk("a,b", StatusCode::OK),
+            chunk("c,d", StatusCode::INTERNAL_SERVER_ERROR),
+        ]);
+
+        let outcome = batched_async(&client, &IDS, 2, save).await;
+
+        assert_eq!(outcome.succeeded, 2);
+        assert_eq!(outcome.failed_at.map(|(index, _)| index), Some(1));
+    }
+}
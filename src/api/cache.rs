@@ -0,0 +1,386 @@
+use crate::{
+    api::{ApiError, AsyncClient, Client, RestClient},
+    auth::scopes::Scope,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{HeaderMap, Method, Response, StatusCode, request::Builder as RequestBuilder};
+use parking_lot::Mutex;
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+use url::Url;
+
+/// The catalog resources whose single-item lookups are cached by [`CachingClient`].
+const CACHED_RESOURCES: &[&str] = &["tracks", "albums", "artists"];
+
+/// A successful response stored in a [`Cache`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The body of the response.
+    pub body: Bytes,
+}
+
+impl CachedResponse {
+    fn from_response(rsp: &Response<Bytes>) -> Self {
+        Self {
+            status: rsp.status(),
+            headers: rsp.headers().clone(),
+            body: rsp.body().clone(),
+        }
+    }
+
+    fn into_response(self) -> Response<Bytes> {
+        let mut rsp = Response::new(self.body);
+        *rsp.status_mut() = self.status;
+        *rsp.headers_mut() = self.headers;
+        rsp
+    }
+}
+
+/// A store for catalog responses used by [`CachingClient`].
+///
+/// Implementations are responsible for expiring their entries.
+pub trait Cache: Send + Sync {
+    /// Returns the response stored under `key`, if it is present and has not expired.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Stores a response under `key`.
+    fn insert(&self, key: String, response: CachedResponse);
+}
+
+struct LruEntry {
+    response: CachedResponse,
+    inserted_at: Instant,
+    last_used: u64,
+}
+
+struct LruState {
+    entries: HashMap<String, LruEntry>,
+    tick: u64,
+}
+
+/// An in-memory, least-recently-used [`Cache`] whose entries expire after a fixed time.
+pub struct LruCache {
+    capacity: usize,
+    ttl: Duration,
+    state: Mutex<LruState>,
+}
+
+impl LruCache {
+    /// Create a cache holding at most `capacity` responses, each for at most `ttl`.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            state: Mutex::new(LruState {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// The number of responses currently stored, including any which have expired.
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for LruCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut state = self.state.lock();
+        state.tick += 1;
+        let tick = state.tick;
+
+        let entry = state.entries.get_mut(key)?;
+        if entry.inserted_at.elapsed() >= self.ttl {
+            state.entries.remove(key);
+            return None;
+        }
+
+        entry.last_used = tick;
+        Some(entry.response.clone())
+    }
+
+    fn insert(&self, key: String, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock();
+        state.tick += 1;
+        let tick = state.tick;
+
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.entries.insert(
+            key,
+            LruEntry {
+                response,
+                inserted_at: Instant::now(),
+                last_used: tick,
+            },
+        );
+    }
+}
+
+/// A client wrapper which caches immutable catalog lookups.
+///
+/// Single track, album and artist lookups (`GetTrack`, `GetAlbum` and `GetArtist`) are
+/// answered from the cache when possible. They are keyed by their full URL, so the ID
+/// and any `market` parameter are both part of the key. Every other request, including
+/// library, player and mutating calls, is always sent to the wrapped client.
+///
+/// This type is only available with the `cache` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::{CachingClient, LruCache, Query as _, tracks::GetTrack};
+/// use spotify_web_api::model::Track;
+/// use std::time::Duration;
+///
+/// # fn example(client: impl spotify_web_api::api::Client) {
+/// let client = CachingClient::new(client, LruCache::new(1024, Duration::from_secs(3600)));
+///
+/// // Only the first lookup is sent to Spotify.
+/// let first: Track = GetTrack::from("11dFghVXANMlKmJXsNCbNl").query(&client).unwrap();
+/// let second: Track = GetTrack::from("11dFghVXANMlKmJXsNCbNl").query(&client).unwrap();
+/// # }
+/// ```
+pub struct CachingClient<C, K = LruCache> {
+    inner: C,
+    cache: K,
+}
+
+impl<C, K> CachingClient<C, K> {
+    /// Wrap a client, caching catalog lookups in `cache`.
+    pub fn new(inner: C, cache: K) -> Self {
+        Self { inner, cache }
+    }
+
+    /// A reference to the cache.
+    pub fn cache(&self) -> &K {
+        &self.cache
+    }
+
+    /// A reference to the wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwrap the client, discarding the cache.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, K> CachingClient<C, K>
+where
+    C: RestClient,
+    K: Cache,
+{
+    /// The cache key for a request, or `None` if the request should not be cached.
+    fn cache_key(&self, request: &RequestBuilder) -> Option<String> {
+        if request.method_ref() != Some(&Method::GET) {
+            return None;
+        }
+
+        let url = Url::parse(&request.uri_ref()?.to_string()).ok()?;
+        let base = self.inner.rest_endpoint("").ok()?;
+        let path = url.path().strip_prefix(base.path())?;
+
+        match path.split('/').collect::<Vec<_>>().as_slice() {
+            [resource, id] if !id.is_empty() && CACHED_RESOURCES.contains(resource) => {
+                Some(url.into())
+            }
+            _ => None,
+        }
+    }
+
+    fn store(&self, key: Option<String>, rsp: &Result<Response<Bytes>, ApiError<C::Error>>) {
+        if let (Some(key), Ok(rsp)) = (key, rsp)
+            && rsp.status().is_success()
+        {
+            self.cache.insert(key, CachedResponse::from_response(rsp));
+        }
+    }
+}
+
+impl<C, K> RestClient for CachingClient<C, K>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.inner.rest_endpoint(endpoint)
+    }
+
+    fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        self.inner.granted_scopes()
+    }
+}
+
+impl<C, K> Client for CachingClient<C, K>
+where
+    C: Client,
+    K: Cache,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = self.cache_key(&request);
+        if let Some(cached) = key.as_deref().and_then(|key| self.cache.get(key)) {
+            return Ok(cached.into_response());
+        }
+
+        let rsp = self.inner.rest(request, body);
+        self.store(key, &rsp);
+        rsp
+    }
+}
+
+#[async_trait]
+impl<C, K> AsyncClient for CachingClient<C, K>
+where
+    C: AsyncClient + Sync,
+    K: Cache,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = self.cache_key(&request);
+        if let Some(cached) = key.as_deref().and_then(|key| self.cache.get(key)) {
+            return Ok(cached.into_response());
+        }
+
+        let rsp = self.inner.rest_async(request, body).await;
+        self.store(key, &rsp);
+        rsp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, AsyncQuery as _, Query as _, RecordingClient, tracks::GetTrack},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    fn caching_client(endpoint: ExpectedUrl) -> CachingClient<RecordingClient<SingleTestClient>> {
+        CachingClient::new(
+            RecordingClient::new(SingleTestClient::new_raw(endpoint, "")),
+            LruCache::new(16, Duration::from_secs(60)),
+        )
+    }
+
+    #[test]
+    fn test_catalog_lookup_is_cached() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tracks/11dFghVXANMlKmJXsNCbNl")
+            .build();
+        let client = caching_client(endpoint);
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&client)
+            .unwrap();
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(client.inner().calls().len(), 1);
+        assert_eq!(client.cache().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_catalog_lookup_is_cached_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tracks/11dFghVXANMlKmJXsNCbNl")
+            .build();
+        let client = caching_client(endpoint);
+
+        for _ in 0..2 {
+            api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+                .query_async(&client)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(client.inner().calls().len(), 1);
+    }
+
+    #[cfg(feature = "player")]
+    #[test]
+    fn test_other_requests_bypass_cache() {
+        use crate::api::player::SeekToPosition;
+
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("me/player/seek")
+            .add_query_params(&[("position_ms", "25000")])
+            .build();
+        let client = caching_client(endpoint);
+
+        api::ignore(SeekToPosition::from(25000))
+            .query(&client)
+            .unwrap();
+        api::ignore(SeekToPosition::from(25000))
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(client.inner().calls().len(), 2);
+        assert!(client.cache().is_empty());
+    }
+
+    #[test]
+    fn test_lru_cache_expiry_and_eviction() {
+        let response = CachedResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: Bytes::from_static(b"{}"),
+        };
+
+        let cache = LruCache::new(2, Duration::from_secs(60));
+        cache.insert("a".into(), response.clone());
+        cache.insert("b".into(), response.clone());
+        assert!(cache.get("a").is_some());
+
+        // "b" is now the least recently used entry.
+        cache.insert("c".into(), response.clone());
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+
+        let cache = LruCache::new(2, Duration::ZERO);
+        cache.insert("a".into(), response);
+        assert!(cache.get("a").is_none());
+        assert!(cache.is_empty());
+    }
+}
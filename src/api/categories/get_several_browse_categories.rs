@@ -10,17 +10,32 @@ pub struct GetSeveralBrowseCategories {
     ///
     /// Example: `sv_SE`
     pub locale: Option<String>,
+
+    /// The maximum number of items to return. Default: 20. Minimum: 1. Maximum: 50.
+    pub limit: Option<u8>,
+
+    /// The index of the first item to return. Default: 0 (the first item). Use with `limit` to get the next set of items.
+    pub offset: Option<u32>,
 }
 
 impl GetSeveralBrowseCategories {
     pub fn with_locale(locale: impl Into<String>) -> Self {
         Self {
             locale: Some(locale.into()),
+            ..Default::default()
         }
     }
-}
 
-impl Pageable for GetSeveralBrowseCategories {}
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
 
 impl Endpoint for GetSeveralBrowseCategories {
     fn method(&self) -> Method {
@@ -34,6 +49,8 @@ impl Endpoint for GetSeveralBrowseCategories {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("locale", self.locale.as_ref());
+        params.push_opt("limit", self.limit);
+        params.push_opt("offset", self.offset);
         params
     }
 }
@@ -70,4 +87,20 @@ mod tests {
             .query(&client)
             .unwrap();
     }
+
+    #[test]
+    fn test_get_several_browse_categories_endpoint_with_limit_and_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("browse/categories")
+            .add_query_params(&[("locale", "sv_SE"), ("limit", "10"), ("offset", "5")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetSeveralBrowseCategories::with_locale("sv_SE")
+            .limit(10)
+            .offset(5);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -75,4 +75,24 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_several_chapters_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("chapters")
+            .add_query_params(&[
+                ("ids", "0IsXVP0JmcB2adSE338GkK,3ZXb8FKZGU0EHALYX6uCzU"),
+                ("market", "GB"),
+            ])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetSeveralChapters {
+            market: Some("GB".parse().unwrap()),
+            ..GetSeveralChapters::from(["0IsXVP0JmcB2adSE338GkK", "3ZXb8FKZGU0EHALYX6uCzU"])
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -1,8 +1,9 @@
 use super::error::ApiError;
+use crate::auth::scopes::Scope;
 use async_trait::async_trait;
 use bytes::Bytes;
 use http::{Response, request::Builder as RequestBuilder};
-use std::error::Error;
+use std::{collections::HashSet, error::Error};
 use url::Url;
 
 /// A trait representing a client which can communicate with a Spotify instance via REST.
@@ -14,6 +15,15 @@ pub trait RestClient {
     ///
     /// This method adds the hostname for the client's target instance.
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>>;
+
+    /// The scopes which have been granted to the client's access token, if they are known.
+    ///
+    /// Requests for endpoints which require a scope that is not in this set fail with
+    /// [`ApiError::InsufficientScope`] before they are sent. Clients which return `None` leave
+    /// it to Spotify to reject such requests.
+    fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        None
+    }
 }
 
 /// A trait representing a client which can communicate with a Spotify instance.
@@ -0,0 +1,108 @@
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, query};
+use async_trait::async_trait;
+use http::Request;
+
+/// A query modifier that builds the request for an endpoint without sending it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DryRun<E> {
+    endpoint: E,
+}
+
+/// Build the request for an endpoint without sending it.
+///
+/// The returned request contains the method, the final URL including query parameters,
+/// the headers and the body exactly as they would be sent, except for the authorization
+/// header which is added by the client. The client is only used to resolve the base URL.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::{dry_run, Query, albums::GetAlbum};
+///
+/// # fn example(client: &impl spotify_web_api::api::Client) {
+/// let request = dry_run(GetAlbum::from("album_id")).query(client).unwrap();
+/// println!("{} {}", request.method(), request.uri());
+/// # }
+/// ```
+pub fn dry_run<E>(endpoint: E) -> DryRun<E> {
+    DryRun { endpoint }
+}
+
+impl<E, C> Query<Request<Vec<u8>>, C> for DryRun<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Request<Vec<u8>>, ApiError<C::Error>> {
+        let (req, data) = query::build_request(&self.endpoint, client)?;
+        Ok(req.body(data)?)
+    }
+}
+
+#[async_trait]
+impl<E, C> AsyncQuery<Request<Vec<u8>>, C> for DryRun<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Request<Vec<u8>>, ApiError<C::Error>> {
+        let (req, data) = query::build_request(&self.endpoint, client)?;
+        Ok(req.body(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            self, AsyncQuery as _, Query as _, albums::GetSeveralAlbums, playlists::CreatePlaylist,
+        },
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+    use http::{Method, header};
+
+    fn client() -> SingleTestClient {
+        // The client panics if a request is actually sent.
+        SingleTestClient::new_raw(ExpectedUrl::builder().endpoint("never").build(), "")
+    }
+
+    #[test]
+    fn test_dry_run_create_playlist() {
+        let endpoint = CreatePlaylist {
+            id: "smedjan".to_owned(),
+            name: "New Playlist".to_owned(),
+            description: Some("New playlist description".to_owned()),
+            public: Some(false),
+            collaborative: None,
+        };
+
+        let request = api::dry_run(endpoint).query(&client()).unwrap();
+
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(
+            request.uri(),
+            "https://api.spotify.com/v1/users/smedjan/playlists"
+        );
+        assert_eq!(request.headers()[header::CONTENT_TYPE], "application/json");
+        assert!(!request.headers().contains_key(header::AUTHORIZATION));
+        assert_eq!(
+            request.body(),
+            br#"{"description":"New playlist description","name":"New Playlist","public":false}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_get_several_albums() {
+        let endpoint = GetSeveralAlbums::from(["382ObEPsp2rxGrnsizN5TX", "1A2GTWGtFfWp7KSQTwWOyo"]);
+
+        let request = api::dry_run(endpoint).query_async(&client()).await.unwrap();
+
+        assert_eq!(request.method(), Method::GET);
+        assert_eq!(
+            request.uri(),
+            "https://api.spotify.com/v1/albums?ids=382ObEPsp2rxGrnsizN5TX%2C1A2GTWGtFfWp7KSQTwWOyo"
+        );
+        assert!(request.headers().is_empty());
+        assert!(request.body().is_empty());
+    }
+}
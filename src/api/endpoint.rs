@@ -4,11 +4,9 @@ use super::{
     params::QueryParams,
     query::{self, AsyncQuery, Query},
 };
+use crate::auth::scopes::Scope;
 use async_trait::async_trait;
-use http::{
-    Method, Request,
-    header::{self, LOCATION},
-};
+use http::{Method, header::LOCATION};
 use serde::de::DeserializeOwned;
 use std::borrow::Cow;
 use url::Url;
@@ -57,6 +55,14 @@ pub trait Endpoint {
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         Ok(None)
     }
+
+    /// The scopes the access token must have been granted to use the endpoint.
+    ///
+    /// Clients which know the scopes of their token refuse to send the request when one of
+    /// these is missing, see [`RestClient::granted_scopes`].
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[]
+    }
 }
 
 impl<E> Endpoint for &E
@@ -82,6 +88,10 @@ where
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         (*self).body()
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        (*self).required_scopes()
+    }
 }
 
 impl<E, T, C> Query<T, C> for E
@@ -91,36 +101,20 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
-
-        self.parameters().add_to_url(&mut url);
-
-        let (mime, data) = self.body()?.map_or((None, Vec::new()), |(mime, data)| {
-            (Some(mime), data.clone())
-        });
-
-        let mut req = Request::builder()
-            .method(self.method())
-            .uri(query::url_to_http_uri(&url));
-
-        if let Some(mime) = mime {
-            req = req.header(header::CONTENT_TYPE, mime);
-        }
-
-        if matches!(self.method(), Method::POST | Method::PUT) {
-            req = req.header(header::CONTENT_LENGTH, data.len().to_string());
-        }
-
+        let (req, data) = query::build_request(self, client)?;
         let rsp = client.rest(req, data)?;
         let status = rsp.status();
 
-        let v = serde_json::from_slice(rsp.body())
-            .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
+        if status == http::StatusCode::MOVED_PERMANENTLY {
+            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
+        }
+
+        let body = query::decode_body(&rsp)?;
+        let v = serde_json::from_slice(&body)
+            .map_err(|_e| ApiError::unparsable_response(&rsp, &body))?;
 
         if !status.is_success() {
             return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
-            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
 
         serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
@@ -135,38 +129,66 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
-
-        self.parameters().add_to_url(&mut url);
+        let (req, data) = query::build_request(self, client)?;
+        let rsp = client.rest_async(req, data).await?;
+        let status = rsp.status();
 
-        let (mime, data) = self.body()?.map_or((None, Vec::new()), |(mime, data)| {
-            (Some(mime), data.clone())
-        });
+        if status == http::StatusCode::MOVED_PERMANENTLY {
+            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
+        }
 
-        let mut req = Request::builder()
-            .method(self.method())
-            .uri(query::url_to_http_uri(&url));
+        let body = query::decode_body(&rsp)?;
+        let v = serde_json::from_slice(&body)
+            .map_err(|_e| ApiError::unparsable_response(&rsp, &body))?;
 
-        if let Some(mime) = mime {
-            req = req.header(header::CONTENT_TYPE, mime);
+        if !status.is_success() {
+            return Err(ApiError::from_spotify_with_status(status, v));
         }
 
-        if matches!(self.method(), Method::POST | Method::PUT) {
-            req = req.header(header::CONTENT_LENGTH, data.len().to_string());
-        }
+        serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
+    }
+}
 
-        let rsp = client.rest_async(req, data).await?;
-        let status = rsp.status();
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{ApiError, AsyncQuery as _, Query as _, tracks::GetTrack},
+        model::Track,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
 
-        let v = serde_json::from_slice(rsp.body())
-            .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
+    const HTML: &str = "<html><body>502 Bad Gateway</body></html>";
 
-        if !status.is_success() {
-            return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
-            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
+    fn html_client() -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tracks/5IoBP6aTHHBStOrbLFc5uQ")
+            .response_header("content-type", "text/html; charset=utf-8")
+            .build();
+
+        SingleTestClient::new_raw(endpoint, HTML)
+    }
+
+    #[test]
+    fn html_response() {
+        let res: Result<Track, _> = GetTrack::from("5IoBP6aTHHBStOrbLFc5uQ").query(&html_client());
+        let err = res.unwrap_err();
+
+        if let ApiError::UnexpectedContentType { content_type } = err {
+            assert_eq!(content_type, "text/html; charset=utf-8");
+        } else {
+            panic!("unexpected error: {err}");
         }
+    }
 
-        serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
+    #[tokio::test]
+    async fn html_response_async() {
+        let res: Result<Track, _> = GetTrack::from("5IoBP6aTHHBStOrbLFc5uQ")
+            .query_async(&html_client())
+            .await;
+
+        assert!(matches!(
+            res.unwrap_err(),
+            ApiError::UnexpectedContentType { .. }
+        ));
     }
 }
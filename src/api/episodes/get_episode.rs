@@ -61,4 +61,21 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_episode_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("episodes/512ojhOuo1ktJprKbVcKyQ")
+            .add_query_params(&[("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetEpisode {
+            market: Some("ES".parse().unwrap()),
+            ..GetEpisode::from("512ojhOuo1ktJprKbVcKyQ")
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
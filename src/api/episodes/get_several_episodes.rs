@@ -1,4 +1,4 @@
-use crate::api::prelude::*;
+use crate::{api::prelude::*, model::EpisodeId};
 
 /// Get Spotify catalog information for several episodes based on their Spotify IDs.
 #[derive(Debug, Clone)]
@@ -29,6 +29,13 @@ where
     }
 }
 
+impl GetSeveralEpisodes {
+    /// Creates the endpoint from validated episode IDs.
+    pub fn from_ids(ids: impl IntoIterator<Item = EpisodeId>) -> Self {
+        Self::from(ids.into_iter().map(|id| id.id().to_owned()))
+    }
+}
+
 impl Endpoint for GetSeveralEpisodes {
     fn method(&self) -> Method {
         Method::GET
@@ -68,4 +75,21 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_several_episodes_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("episodes")
+            .add_query_params(&[("ids", "77o6BIVlYM3msb4MMIL1jH"), ("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetSeveralEpisodes {
+            market: Some("ES".parse().unwrap()),
+            ..GetSeveralEpisodes::from(["77o6BIVlYM3msb4MMIL1jH"])
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
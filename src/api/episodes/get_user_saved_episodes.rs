@@ -15,10 +15,13 @@ pub struct GetUserSavedEpisodes {
     pub market: Option<Market>,
 }
 
-impl From<Market> for GetUserSavedEpisodes {
-    fn from(market: Market) -> Self {
+impl<T> From<T> for GetUserSavedEpisodes
+where
+    T: Into<Market>,
+{
+    fn from(market: T) -> Self {
         Self {
-            market: Some(market),
+            market: Some(market.into()),
         }
     }
 }
@@ -1,5 +1,5 @@
 use super::endpoint::UrlBase;
-use crate::auth::AuthError;
+use crate::auth::{AuthError, scopes::Scope};
 use std::error::Error;
 use thiserror::Error;
 
@@ -14,6 +14,14 @@ pub enum BodyError {
     /// Body data could not be serialized to JSON from form parameters.
     #[error("failed to JSON encode form parameters: {0}")]
     JsonEncoded(#[from] serde_json::Error),
+
+    /// An image payload is larger than Spotify accepts.
+    #[error("image payload is {size} bytes, but at most {max} bytes are accepted")]
+    ImageTooLarge { size: usize, max: usize },
+
+    /// An image payload is not base64 encoded JPEG data.
+    #[error("image payload is not base64 encoded JPEG data")]
+    InvalidImage,
 }
 
 /// Errors which may occur when using API endpoints.
@@ -39,11 +47,20 @@ where
     #[error("failed to create form data: {0}")]
     Body(#[from] BodyError),
 
+    /// The HTTP request could not be built.
+    #[error("failed to build request: {0}")]
+    Request(#[from] http::Error),
+
     /// JSON deserialization from Spotify failed.
     #[error("could not parse JSON response: {0}")]
     Json(#[from] serde_json::Error),
 
     /// The resource has been moved permanently.
+    ///
+    /// Spotify answers with a `301` when a resource has been relocated, for example when a
+    /// track or album has been relinked to another ID for the requested market. Redirects
+    /// are not followed automatically; use [`ApiError::redirect_location`] to retry against
+    /// the new location.
     #[error("moved permanently to: {}", location.as_ref().map_or("<UNKNOWN>", AsRef::as_ref))]
     MovedPermanently {
         /// The new location for the resource.
@@ -60,6 +77,15 @@ where
         data: Vec<u8>,
     },
 
+    /// Spotify returned a successful response which is not JSON.
+    ///
+    /// This usually means an HTML error page was served by a proxy in front of Spotify.
+    #[error("unexpected content type in successful response: {content_type}")]
+    UnexpectedContentType {
+        /// The `Content-Type` of the response.
+        content_type: String,
+    },
+
     /// Failed to parse an expected data type.
     #[error("could not parse {typename} data: {source}")]
     DataType {
@@ -74,6 +100,21 @@ where
     #[error("unsupported URL base: {0:?}")]
     UnsupportedUrlBase(UrlBase),
 
+    /// The access token has not been granted a scope which the endpoint requires.
+    ///
+    /// Spotify answers with a `403 Forbidden` in this case. Clients which know the scopes of
+    /// their token also return it before sending a request for an endpoint whose
+    /// [required scopes](crate::api::Endpoint::required_scopes) have not all been granted.
+    /// The user has to authorize the application again, requesting the missing scope.
+    #[error("insufficient scope: {message}")]
+    InsufficientScope {
+        /// The missing scope, if Spotify named one in its message.
+        needed: Option<Scope>,
+
+        /// The error message from Spotify.
+        message: String,
+    },
+
     /// Spotify returned an error message with an HTTP error.
     #[error("spotify server error ({status}): {msg}")]
     SpotifyWithStatus {
@@ -125,8 +166,12 @@ where
             Self::UrlParse(source) => ApiError::UrlParse(source),
             Self::Auth(source) => ApiError::Auth(source),
             Self::Body(source) => ApiError::Body(source),
+            Self::Request(source) => ApiError::Request(source),
             Self::Json(source) => ApiError::Json(source),
             Self::MovedPermanently { location } => ApiError::MovedPermanently { location },
+            Self::InsufficientScope { needed, message } => {
+                ApiError::InsufficientScope { needed, message }
+            }
             Self::SpotifyWithStatus { status, msg } => ApiError::SpotifyWithStatus { status, msg },
             Self::SpotifyService { status, data } => ApiError::SpotifyService { status, data },
             Self::SpotifyObjectWithStatus { status, obj } => {
@@ -135,11 +180,23 @@ where
             Self::SpotifyUnrecognizedWithStatus { status, obj } => {
                 ApiError::SpotifyUnrecognizedWithStatus { status, obj }
             }
+            Self::UnexpectedContentType { content_type } => {
+                ApiError::UnexpectedContentType { content_type }
+            }
             Self::DataType { source, typename } => ApiError::DataType { source, typename },
             Self::UnsupportedUrlBase(url_base) => ApiError::UnsupportedUrlBase(url_base),
         }
     }
 
+    /// The new location of the resource, if this is a [`ApiError::MovedPermanently`] error
+    /// which included one.
+    pub fn redirect_location(&self) -> Option<&str> {
+        match self {
+            Self::MovedPermanently { location } => location.as_deref(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn moved_permanently(raw_location: Option<&http::HeaderValue>) -> Self {
         let location = raw_location.map(|v| String::from_utf8_lossy(v.as_bytes()).into());
         Self::MovedPermanently { location }
@@ -152,10 +209,35 @@ where
         }
     }
 
+    /// The error for a response body which could not be parsed as JSON.
+    pub(crate) fn unparsable_response(
+        rsp: &http::Response<bytes::Bytes>,
+        body: &bytes::Bytes,
+    ) -> Self {
+        let status = rsp.status();
+        let content_type = rsp
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned());
+
+        match content_type {
+            Some(content_type) if status.is_success() && !content_type.contains("json") => {
+                Self::UnexpectedContentType { content_type }
+            }
+            _ => Self::server_error(status, body),
+        }
+    }
+
     pub(crate) fn from_spotify_with_status(
         status: http::StatusCode,
         value: serde_json::Value,
     ) -> Self {
+        if status == http::StatusCode::FORBIDDEN
+            && let Some(err) = Self::insufficient_scope(&value)
+        {
+            return err;
+        }
+
         let error_value = value
             .pointer("/message")
             .or_else(|| value.pointer("/error"));
@@ -177,6 +259,35 @@ where
         }
     }
 
+    /// The error for a `403 Forbidden` response whose message is about a missing scope.
+    fn insufficient_scope(value: &serde_json::Value) -> Option<Self> {
+        let message = value
+            .pointer("/error/message")
+            .or_else(|| value.pointer("/message"))
+            .or_else(|| value.pointer("/error"))
+            .and_then(serde_json::Value::as_str)?;
+
+        if !message.to_ascii_lowercase().contains("scope") {
+            return None;
+        }
+
+        let needed = message
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .find_map(|word| Scope::try_from(word).ok());
+
+        Some(Self::InsufficientScope {
+            needed,
+            message: message.to_owned(),
+        })
+    }
+
+    pub(crate) fn missing_scope(scope: Scope) -> Self {
+        Self::InsufficientScope {
+            needed: Some(scope),
+            message: format!("the access token has not been granted the {scope} scope"),
+        }
+    }
+
     pub(crate) fn data_type<T>(source: serde_json::Error) -> Self {
         Self::DataType {
             source,
@@ -184,3 +295,64 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RestError;
+
+    #[test]
+    fn redirect_location() {
+        let location = http::HeaderValue::from_static("https://api.spotify.com/v1/tracks/abc");
+        let err = ApiError::<RestError>::moved_permanently(Some(&location));
+        assert_eq!(
+            err.redirect_location(),
+            Some("https://api.spotify.com/v1/tracks/abc")
+        );
+
+        let err = ApiError::<RestError>::moved_permanently(None);
+        assert_eq!(err.redirect_location(), None);
+    }
+
+    #[test]
+    fn insufficient_scope() {
+        let err = ApiError::<RestError>::from_spotify_with_status(
+            http::StatusCode::FORBIDDEN,
+            serde_json::json!({ "error": { "status": 403, "message": "Insufficient client scope" } }),
+        );
+        assert!(matches!(
+            &err,
+            ApiError::InsufficientScope { needed: None, message }
+                if message == "Insufficient client scope"
+        ));
+
+        let err = ApiError::<RestError>::from_spotify_with_status(
+            http::StatusCode::FORBIDDEN,
+            serde_json::json!({
+                "error": {
+                    "status": 403,
+                    "message": "Missing required scope: user-read-playback-state"
+                }
+            }),
+        );
+        assert!(matches!(
+            err,
+            ApiError::InsufficientScope {
+                needed: Some(Scope::UserReadPlaybackState),
+                ..
+            }
+        ));
+
+        let err = ApiError::<RestError>::from_spotify_with_status(
+            http::StatusCode::FORBIDDEN,
+            serde_json::json!({ "error": { "status": 403, "message": "Player command failed: Restriction violated" } }),
+        );
+        assert!(matches!(err, ApiError::SpotifyObjectWithStatus { .. }));
+
+        let err = ApiError::<RestError>::from_spotify_with_status(
+            http::StatusCode::UNAUTHORIZED,
+            serde_json::json!({ "error": { "status": 401, "message": "Invalid scope" } }),
+        );
+        assert!(matches!(err, ApiError::SpotifyObjectWithStatus { .. }));
+    }
+}
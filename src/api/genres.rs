@@ -1,3 +1,5 @@
+mod genre;
 mod get_available_genre_seeds;
 
+pub use genre::*;
 pub use get_available_genre_seeds::*;
@@ -0,0 +1,225 @@
+use super::GetAvailableGenreSeeds;
+use crate::{
+    api::{ApiError, AsyncClient, AsyncQuery as _, Client, Query as _},
+    model::Genres,
+};
+use parking_lot::RwLock;
+use std::{error::Error, fmt, sync::Arc};
+use thiserror::Error;
+
+/// Errors which may occur when validating a [`Genre`].
+///
+/// This enum is marked as `#[non_exhaustive]`, meaning new variants may be added in future versions.
+/// When matching against it, include a wildcard arm (`_`) to account for any future variants.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum GenreError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The genre seed is not one of the genres available for recommendations.
+    #[error("unknown genre seed: {genre}")]
+    Unknown {
+        /// The rejected genre seed.
+        genre: String,
+    },
+
+    /// The available genre seeds could not be fetched.
+    #[error("failed to fetch the available genre seeds: {0}")]
+    Api(#[from] ApiError<E>),
+}
+
+/// A client-side cache of the available genre seeds, used to validate a [`Genre`].
+///
+/// The seeds are fetched on first use. Clones share the same cache.
+#[derive(Debug, Clone, Default)]
+pub struct GenreSeedCache(Arc<RwLock<Option<Arc<[String]>>>>);
+
+impl GenreSeedCache {
+    /// The cached genre seeds, if they have been fetched.
+    pub fn get(&self) -> Option<Arc<[String]>> {
+        self.0.read().clone()
+    }
+
+    /// Replace the cached genre seeds.
+    pub fn set(&self, genres: impl Into<Arc<[String]>>) {
+        *self.0.write() = Some(genres.into());
+    }
+
+    /// Forget the cached genre seeds, so that they are fetched again on next use.
+    pub fn clear(&self) {
+        *self.0.write() = None;
+    }
+
+    /// Validate a genre seed, fetching the available seeds with `client` if they are not
+    /// cached yet.
+    ///
+    /// # Errors
+    /// Returns [`GenreError::Unknown`] if the genre is not an available seed, or
+    /// [`GenreError::Api`] if the available seeds could not be fetched.
+    pub fn genre<C>(
+        &self,
+        client: &C,
+        name: impl Into<String>,
+    ) -> Result<Genre, GenreError<C::Error>>
+    where
+        C: Client,
+    {
+        let seeds = if let Some(seeds) = self.get() {
+            seeds
+        } else {
+            let Genres { genres } = GetAvailableGenreSeeds.query(client)?;
+            self.store(genres)
+        };
+
+        Genre::validate(name.into(), &seeds)
+    }
+
+    /// Asynchronously validate a genre seed, fetching the available seeds with `client` if
+    /// they are not cached yet.
+    ///
+    /// # Errors
+    /// See [`GenreSeedCache::genre`].
+    pub async fn genre_async<C>(
+        &self,
+        client: &C,
+        name: impl Into<String>,
+    ) -> Result<Genre, GenreError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        let seeds = if let Some(seeds) = self.get() {
+            seeds
+        } else {
+            let Genres { genres } = GetAvailableGenreSeeds.query_async(client).await?;
+            self.store(genres)
+        };
+
+        Genre::validate(name.into(), &seeds)
+    }
+
+    fn store(&self, genres: Vec<String>) -> Arc<[String]> {
+        let seeds: Arc<[String]> = genres.into();
+        self.set(seeds.clone());
+        seeds
+    }
+}
+
+/// A genre seed which is known to be accepted by Spotify.
+///
+/// Unknown genre seeds are rejected by Spotify with a `400`, so a `Genre` can only be
+/// created by checking its name against the list returned by [`GetAvailableGenreSeeds`],
+/// either with [`Genres::genre`] or through a [`GenreSeedCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Genre(String);
+
+impl Genre {
+    /// The name of the genre seed.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn validate<E>(name: String, seeds: &[String]) -> Result<Self, GenreError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        if seeds.contains(&name) {
+            Ok(Self(name))
+        } else {
+            Err(GenreError::Unknown { genre: name })
+        }
+    }
+}
+
+impl Genres {
+    /// Returns the genre seed called `name`, if it is one of these genres.
+    pub fn genre(&self, name: &str) -> Option<Genre> {
+        self.genres
+            .iter()
+            .any(|genre| genre == name)
+            .then(|| Genre(name.to_owned()))
+    }
+}
+
+impl AsRef<str> for Genre {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Genre {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Genre> for String {
+    fn from(genre: Genre) -> Self {
+        genre.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::RecordingClient,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    fn client() -> RecordingClient<SingleTestClient> {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("recommendations/available-genre-seeds")
+            .build();
+        RecordingClient::new(SingleTestClient::new_raw(
+            endpoint,
+            r#"{"genres": ["alternative", "rock", "samba"]}"#,
+        ))
+    }
+
+    #[test]
+    fn test_valid_genre() {
+        let client = client();
+        let cache = GenreSeedCache::default();
+
+        let genre = cache.genre(&client, "rock").unwrap();
+        assert_eq!(genre.as_str(), "rock");
+
+        // The seed list is only fetched once.
+        cache.genre(&client, "samba").unwrap();
+        assert_eq!(client.calls().len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_genre() {
+        let err = GenreSeedCache::default()
+            .genre(&client(), "yacht-rock")
+            .unwrap_err();
+
+        if let GenreError::Unknown { genre } = err {
+            assert_eq!(genre, "yacht-rock");
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_genre_async() {
+        let client = client();
+        let cache = GenreSeedCache::default();
+
+        cache.genre_async(&client, "alternative").await.unwrap();
+        assert!(cache.genre_async(&client, "polka").await.is_err());
+        assert_eq!(client.calls().len(), 1);
+    }
+
+    #[test]
+    fn test_genre_from_genres() {
+        let genres = Genres {
+            genres: vec!["alternative".to_owned(), "samba".to_owned()],
+        };
+
+        assert_eq!(genres.genre("samba").unwrap().as_str(), "samba");
+        assert_eq!(genres.genre("polka"), None);
+    }
+}
@@ -36,6 +36,8 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        query::check_scopes(&self.endpoint, client)?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -65,12 +67,15 @@ where
         let rsp = client.rest(req, data)?;
         let status = rsp.status();
 
+        if status == http::StatusCode::MOVED_PERMANENTLY {
+            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
+        }
+
         if !status.is_success() {
-            let v = serde_json::from_slice(rsp.body())
-                .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
+            let body = query::decode_body(&rsp)?;
+            let v = serde_json::from_slice(&body)
+                .map_err(|_e| ApiError::server_error(status, &body))?;
             return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
-            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
 
         Ok(())
@@ -84,6 +89,8 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        query::check_scopes(&self.endpoint, client)?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -113,12 +120,15 @@ where
         let rsp = client.rest_async(req, data).await?;
         let status = rsp.status();
 
+        if status == http::StatusCode::MOVED_PERMANENTLY {
+            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
+        }
+
         if !status.is_success() {
-            let v = serde_json::from_slice(rsp.body())
-                .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
+            let body = query::decode_body(&rsp)?;
+            let v = serde_json::from_slice(&body)
+                .map_err(|_e| ApiError::server_error(status, &body))?;
             return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
-            return Err(ApiError::moved_permanently(rsp.headers().get(LOCATION)));
         }
 
         Ok(())
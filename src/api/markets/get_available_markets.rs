@@ -35,8 +35,8 @@ mod tests {
         let endpoint = ExpectedUrl::builder().endpoint("markets").build();
         let client = SingleTestClient::new_raw(endpoint, r#"{"markets": ["CA", "BR", "IT"]}"#);
         let response: Markets = GetAvailableMarkets.query(&client).unwrap();
-        for genre in ["CA", "BR", "IT"] {
-            assert!(response.markets.contains(&genre.to_owned()));
+        for market in ["CA", "BR", "IT"] {
+            assert!(response.contains(market));
         }
     }
 }
@@ -1,12 +1,31 @@
 mod all_at_once;
+mod cursor;
 mod lazy;
 mod pagination;
+mod single;
 
 pub use all_at_once::*;
+pub use cursor::*;
 pub use lazy::*;
 pub use pagination::*;
+pub use single::*;
 
 /// A trait to indicate that an endpoint is pageable.
 pub trait Pageable {}
 
 impl<E> Pageable for &E where E: Pageable {}
+
+/// A trait to indicate that an endpoint uses cursor-based pagination.
+///
+/// Cursor-paginated endpoints can be iterated with a [`LazilyCursorPagedIter`].
+pub trait CursorPageable {
+    /// The page type returned by the endpoint.
+    type Page: CursorPage;
+}
+
+impl<E> CursorPageable for &E
+where
+    E: CursorPageable,
+{
+    type Page = E::Page;
+}
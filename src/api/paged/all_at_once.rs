@@ -4,10 +4,9 @@ use crate::{
     model::Page,
 };
 use async_trait::async_trait;
+use futures_util::{StreamExt as _, TryStreamExt as _, stream};
 use http::{Method, Request, header};
-use parking_lot::Mutex;
 use serde::de::DeserializeOwned;
-use std::sync::Arc;
 use url::Url;
 
 /// A query modifier that paginates an endpoint.
@@ -15,6 +14,7 @@ use url::Url;
 pub struct Paged<E> {
     pub(crate) endpoint: E,
     pub(crate) pagination: Pagination,
+    pub(crate) max_concurrent: usize,
 }
 
 /// Collect data from a paged endpoint.
@@ -25,6 +25,7 @@ pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
     Paged {
         endpoint,
         pagination,
+        max_concurrent: 1,
     }
 }
 
@@ -56,6 +57,15 @@ pub fn paged_with_limit<E>(endpoint: E, limit: usize) -> Paged<E> {
     paged(endpoint, Pagination::Limit(limit.min(MAX_LIMIT)))
 }
 
+/// Collect up to `n` items from a paged endpoint.
+///
+/// Unlike [`paged_with_limit`], `n` may be greater than the maximum limit of 50, in which case
+/// several pages are fetched. Paging stops as soon as `n` items have been collected, and the
+/// last page only requests the items which are still needed.
+pub fn paged_take<E>(endpoint: E, n: usize) -> Paged<E> {
+    paged(endpoint, Pagination::Take(n))
+}
+
 /// Collect a limited amount of data from a paged endpoint starting at an offset.
 ///
 /// If the limit is greater than the maximum limit of 50, the maximum limit will be used.
@@ -88,7 +98,13 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        if self.pagination.remaining(0) == Some(0) {
+            return Ok(Vec::new());
+        }
+
         let url = {
+            query::check_scopes(&self.endpoint, client)?;
+
             let mut url = self
                 .endpoint
                 .url_base()
@@ -97,82 +113,200 @@ where
             url
         };
 
-        let results = Arc::new(Mutex::new(Vec::new()));
         let body = self.endpoint.body()?;
-        let mut next_url = None;
+        let mut results = Vec::new();
 
         let offset = match self.pagination {
             Pagination::Page { offset, .. } => offset,
             _ => 0,
         };
 
-        loop {
-            let page_url = next_url.take().unwrap_or_else(|| {
-                let mut page_url = url.clone();
-                {
-                    let mut pairs = page_url.query_pairs_mut();
-                    pairs.append_pair("offset", &offset.to_string());
-                    pairs.append_pair("limit", &self.pagination.limit().to_string());
-                }
-                page_url
-            });
+        let mut page_url = Some(page_url(&url, offset, self.pagination.limit()));
+        let mut first_page = true;
 
-            let (mime, data) = body.as_ref().map_or((None, Vec::new()), |(mime, data)| {
-                (Some(mime), data.clone())
-            });
+        while let Some(current_url) = page_url.take() {
+            let page: Page<T> = self.fetch_page(client, &current_url, body.as_ref()).await?;
 
-            let mut req = Request::builder()
-                .method(self.endpoint.method())
-                .uri(query::url_to_http_uri(&page_url));
+            let next_url = page.next.as_deref().map(Url::parse).transpose()?;
+            let (page_offset, total) = (page.offset, page.total);
 
-            if let Some(mime) = mime {
-                req = req.header(header::CONTENT_TYPE, *mime);
+            let mut items = page.items;
+            if let Some(remaining) = self.pagination.remaining(results.len()) {
+                items.truncate(remaining);
             }
 
-            if matches!(self.endpoint.method(), Method::POST | Method::PUT) {
-                req = req.header(header::CONTENT_LENGTH, data.len().to_string());
+            let page_len = items.len();
+            results.extend(items);
+
+            if self.pagination.is_last_page(page_len, results.len()) {
+                break;
             }
 
-            let rsp = client.rest_async(req, data).await?;
-            let status = rsp.status();
+            let Some(next_url) = next_url else {
+                break;
+            };
+
+            // Once the total is known, the remaining offsets can be fetched concurrently.
+            if first_page
+                && self.max_concurrent > 1
+                && let Some(urls) = self.remaining_page_urls(
+                    &url,
+                    &next_url,
+                    page_offset + page_len,
+                    total,
+                    results.len(),
+                )
+            {
+                let body = body.as_ref();
+                let pages: Vec<Page<T>> = stream::iter(
+                    urls.into_iter()
+                        .map(|url| async move { self.fetch_page(client, &url, body).await }),
+                )
+                .buffered(self.max_concurrent)
+                .try_collect()
+                .await?;
+
+                for page in pages {
+                    let mut items = page.items;
+                    if let Some(remaining) = self.pagination.remaining(results.len()) {
+                        items.truncate(remaining);
+                    }
+                    results.extend(items);
+                }
+                break;
+            }
 
-            let v = serde_json::from_slice(rsp.body())
-                .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
+            first_page = false;
+            page_url = Some(self.pagination.next_page_url(next_url, results.len()));
+        }
 
-            if !status.is_success() {
-                return Err(ApiError::from_spotify_with_status(status, v));
-            } else if status == http::StatusCode::MOVED_PERMANENTLY {
-                return Err(ApiError::moved_permanently(
-                    rsp.headers().get(header::LOCATION),
-                ));
-            }
+        Ok(results)
+    }
+}
 
-            let page: Page<T> =
-                serde_json::from_value(v).map_err(ApiError::data_type::<Page<T>>)?;
+impl<E> Paged<E>
+where
+    E: Endpoint + Sync,
+{
+    async fn fetch_page<T, C>(
+        &self,
+        client: &C,
+        url: &Url,
+        body: Option<&(&'static str, Vec<u8>)>,
+    ) -> Result<Page<T>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: AsyncClient + Sync,
+    {
+        let (mime, data) = body.map_or((None, Vec::new()), |(mime, data)| {
+            (Some(*mime), data.clone())
+        });
+
+        let mut req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+
+        if let Some(mime) = mime {
+            req = req.header(header::CONTENT_TYPE, mime);
+        }
 
-            let page_len = page.items.len();
-            next_url = page.next.as_ref().map(|url| Url::parse(url)).transpose()?;
+        if matches!(self.endpoint.method(), Method::POST | Method::PUT) {
+            req = req.header(header::CONTENT_LENGTH, data.len().to_string());
+        }
 
-            let mut locked_results = results.lock();
-            locked_results.extend(page.items);
+        let rsp = client.rest_async(req, data).await?;
+        let status = rsp.status();
 
-            if self.pagination.is_last_page(page_len, locked_results.len()) || next_url.is_none() {
-                break;
-            }
+        if status == http::StatusCode::MOVED_PERMANENTLY {
+            return Err(ApiError::moved_permanently(
+                rsp.headers().get(header::LOCATION),
+            ));
+        }
+
+        let body = query::decode_body(&rsp)?;
+        let v = serde_json::from_slice(&body)
+            .map_err(|_e| ApiError::unparsable_response(&rsp, &body))?;
+
+        if !status.is_success() {
+            return Err(ApiError::from_spotify_with_status(status, v));
+        }
+
+        serde_json::from_value(v).map_err(ApiError::data_type::<Page<T>>)
+    }
+}
+
+impl<E> Paged<E> {
+    /// Fetch the pages after the first one with at most `max_concurrent` requests in flight,
+    /// when all items are collected asynchronously.
+    ///
+    /// Pages are fetched one after another by default, following the `next` link of each
+    /// page. With a `max_concurrent` greater than one, the offsets of the remaining pages are
+    /// computed from the `total` reported by the first page instead, and the items are still
+    /// returned in order. Responses whose `next` link is not offset-based are always
+    /// followed one after another. A `max_concurrent` of zero is treated as one.
+    pub fn max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.max_concurrent = max_concurrent.max(1);
+        self
+    }
+
+    /// The URLs of the pages after `offset`, if they can be computed from the total.
+    fn remaining_page_urls(
+        &self,
+        url: &Url,
+        next_url: &Url,
+        mut offset: usize,
+        total: usize,
+        num_results: usize,
+    ) -> Option<Vec<Url>> {
+        if !next_url.query_pairs().any(|(key, _)| key == "offset") {
+            return None;
         }
 
-        let mut locked_results = results.lock();
+        let end = match self.pagination {
+            Pagination::All => total,
+            Pagination::Take(_) => self
+                .pagination
+                .remaining(num_results)
+                .map_or(total, |remaining| {
+                    total.min(offset.saturating_add(remaining))
+                }),
+            Pagination::Limit(_) | Pagination::Page { .. } => return None,
+        };
+
+        let limit = self.pagination.limit();
+        let mut urls = Vec::new();
+        while offset < end {
+            urls.push(page_url(url, offset, limit.min(end - offset)));
+            offset += limit;
+        }
 
-        Ok(std::mem::take(&mut locked_results))
+        Some(urls)
     }
 }
 
+fn page_url(url: &Url, offset: usize, limit: usize) -> Url {
+    let mut page_url = url.clone();
+    page_url
+        .query_pairs_mut()
+        .append_pair("offset", &offset.to_string())
+        .append_pair("limit", &limit.to_string());
+    page_url
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::test::client::{ExpectedUrl, PagedTestClient};
-    use http::Method;
+    use crate::{
+        api::RestClient,
+        test::client::{ExpectedUrl, PagedTestClient},
+    };
+    use bytes::Bytes;
+    use http::{Method, Response, request::Builder as RequestBuilder};
     use serde::{Deserialize, Serialize};
-    use std::borrow::Cow;
+    use std::{
+        borrow::Cow,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
 
     use super::*;
 
@@ -262,6 +396,38 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn pagination_take_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build();
+
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=254).map(|value| DummyResult { value }));
+
+        let res: Vec<DummyResult> = paged_take(Dummy, 75).query_async(&client).await.unwrap();
+
+        assert_eq!(res.len(), 75);
+
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+
+        let limits = client
+            .requests()
+            .iter()
+            .map(|url| {
+                url.query_pairs()
+                    .find(|(key, _)| key == "limit")
+                    .unwrap()
+                    .1
+                    .into_owned()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(limits, ["50", "25"]);
+    }
+
     #[tokio::test]
     async fn pagination_all_async() {
         let endpoint = ExpectedUrl::builder()
@@ -283,4 +449,99 @@ mod tests {
             assert_eq!(value.value, i as u8);
         }
     }
+
+    /// Answers the pages with the lowest offsets last, and records how many requests were in
+    /// flight at once.
+    struct ReversedClient {
+        inner: PagedTestClient<DummyResult>,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    impl RestClient for ReversedClient {
+        type Error = <PagedTestClient<DummyResult> as RestClient>::Error;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            self.inner.rest_endpoint(endpoint)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for ReversedClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let url = Url::parse(&request.uri_ref().unwrap().to_string()).unwrap();
+            let offset: u64 = url
+                .query_pairs()
+                .find(|(key, _)| key == "offset")
+                .unwrap()
+                .1
+                .parse()
+                .unwrap();
+
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(300 - offset)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            self.inner.rest_async(request, body).await
+        }
+    }
+
+    fn reversed_client(len: u8) -> ReversedClient {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build();
+
+        ReversedClient {
+            inner: PagedTestClient::new_raw(endpoint, (0..len).map(|value| DummyResult { value })),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn pagination_all_concurrent_async() {
+        let client = reversed_client(230);
+
+        let res: Vec<DummyResult> = paged_all(Dummy)
+            .max_concurrent(3)
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 230);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+
+        assert_eq!(client.inner.requests().len(), 5);
+        assert_eq!(client.max_in_flight.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn pagination_take_concurrent_async() {
+        let client = reversed_client(230);
+
+        let res: Vec<DummyResult> = paged_take(Dummy, 120)
+            .max_concurrent(4)
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 120);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+
+        let mut pages = client.inner.page_params();
+        // Requests are recorded as they complete, which is not the order they were sent in.
+        pages.sort_unstable();
+        assert_eq!(pages, [(0, 50), (50, 50), (100, 20)]);
+        assert_eq!(client.max_in_flight.load(Ordering::SeqCst), 2);
+    }
 }
@@ -0,0 +1,407 @@
+use super::CursorPageable;
+#[cfg(feature = "player")]
+use crate::model::{PlayHistory, RecentlyPlayedTracks};
+use crate::{
+    api::{ApiError, AsyncClient, Client, Endpoint, RestClient, query},
+    model::{Artist, FollowedArtists},
+};
+use bytes::Bytes;
+use futures_util::Stream;
+use http::{Request, Response, header, request::Builder as RequestBuilder};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+/// A page of results from an endpoint which uses cursor-based pagination.
+pub trait CursorPage: DeserializeOwned {
+    /// The type of the items in the page.
+    type Item;
+
+    /// The URL of the next page of items, if there is one.
+    fn next(&self) -> Option<&str>;
+
+    /// The cursor to use as key to find the next page of items.
+    fn after(&self) -> Option<&str>;
+
+    /// Consumes the page, returning its items.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl CursorPage for FollowedArtists {
+    type Item = Artist;
+
+    fn next(&self) -> Option<&str> {
+        self.artists.next.as_deref()
+    }
+
+    fn after(&self) -> Option<&str> {
+        self.artists.cursors.after.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Artist> {
+        self.artists.items
+    }
+}
+
+#[cfg(feature = "player")]
+impl CursorPage for RecentlyPlayedTracks {
+    type Item = PlayHistory;
+
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn after(&self) -> Option<&str> {
+        self.cursors.as_ref()?.after.as_deref()
+    }
+
+    fn into_items(self) -> Vec<PlayHistory> {
+        self.items
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CursorState {
+    First,
+    Next(Url),
+    Done,
+}
+
+/// An iterator which yields items from a cursor-paginated result.
+///
+/// Each page is fetched lazily by following the `next` URL of the previous one, which carries
+/// the cursor of the last item returned. Iteration stops once a page without a `next` URL has
+/// been consumed.
+pub struct LazilyCursorPagedIter<'a, E, C>
+where
+    E: CursorPageable,
+{
+    endpoint: E,
+    client: &'a C,
+    state: CursorState,
+    current_page: Vec<<E::Page as CursorPage>::Item>,
+}
+
+impl<'a, E, C> LazilyCursorPagedIter<'a, E, C>
+where
+    E: Endpoint + CursorPageable,
+{
+    /// Create an iterator over the items of every page of `endpoint`.
+    pub fn new(endpoint: E, client: &'a C) -> Self {
+        Self {
+            endpoint,
+            client,
+            state: CursorState::First,
+            current_page: Vec::new(),
+        }
+    }
+
+    fn page_url<R>(&self, client: &R) -> Result<Option<Url>, ApiError<R::Error>>
+    where
+        R: RestClient,
+    {
+        let url = match &self.state {
+            CursorState::Done => return Ok(None),
+            CursorState::Next(url) => url.clone(),
+            CursorState::First => {
+                query::check_scopes(&self.endpoint, client)?;
+
+                let mut url = self
+                    .endpoint
+                    .url_base()
+                    .endpoint_for(client, &self.endpoint.endpoint())?;
+                self.endpoint.parameters().add_to_url(&mut url);
+                url
+            }
+        };
+
+        Ok(Some(url))
+    }
+
+    fn build_request(&self, url: &Url) -> RequestBuilder {
+        Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url))
+    }
+
+    fn process_response<R>(&mut self, rsp: &Response<Bytes>) -> Result<(), ApiError<R::Error>>
+    where
+        R: RestClient,
+    {
+        let status = rsp.status();
+
+        if status == http::StatusCode::MOVED_PERMANENTLY {
+            return Err(ApiError::moved_permanently(
+                rsp.headers().get(header::LOCATION),
+            ));
+        }
+
+        let body = query::decode_body(rsp)?;
+        let v = serde_json::from_slice(&body)
+            .map_err(|_e| ApiError::unparsable_response(rsp, &body))?;
+
+        if !status.is_success() {
+            return Err(ApiError::from_spotify_with_status(status, v));
+        }
+
+        let page = serde_json::from_value::<E::Page>(v).map_err(ApiError::data_type::<E::Page>)?;
+
+        self.state = match page.next() {
+            Some(next) => CursorState::Next(Url::parse(next)?),
+            None => CursorState::Done,
+        };
+
+        self.current_page = page.into_items();
+        self.current_page.reverse();
+
+        Ok(())
+    }
+}
+
+impl<E, C> Iterator for LazilyCursorPagedIter<'_, E, C>
+where
+    E: Endpoint + CursorPageable,
+    C: Client,
+{
+    type Item = Result<<E::Page as CursorPage>::Item, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current_page.is_empty() {
+            let url = match self.page_url(self.client) {
+                Ok(Some(url)) => url,
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+
+            let result = self
+                .client
+                .rest(self.build_request(&url), Vec::new())
+                .and_then(|rsp| self.process_response::<C>(&rsp));
+
+            if let Err(err) = result {
+                return Some(Err(err));
+            }
+        }
+
+        self.current_page.pop().map(Ok)
+    }
+}
+
+impl<'a, E, C> LazilyCursorPagedIter<'a, E, C>
+where
+    E: Endpoint + CursorPageable + 'a,
+    C: AsyncClient + Sync,
+{
+    async fn next_async(
+        &mut self,
+    ) -> Option<Result<<E::Page as CursorPage>::Item, ApiError<C::Error>>> {
+        while self.current_page.is_empty() {
+            let url = match self.page_url(self.client) {
+                Ok(Some(url)) => url,
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+
+            let result = match self
+                .client
+                .rest_async(self.build_request(&url), Vec::new())
+                .await
+            {
+                Ok(rsp) => self.process_response::<C>(&rsp),
+                Err(err) => Err(err),
+            };
+
+            if let Err(err) = result {
+                return Some(Err(err));
+            }
+        }
+
+        self.current_page.pop().map(Ok)
+    }
+
+    /// Converts a "normal iterator" into an async iterator
+    pub fn into_async(
+        self,
+    ) -> impl Stream<Item = Result<<E::Page as CursorPage>::Item, ApiError<C::Error>>> + 'a {
+        futures_util::stream::unfold(self, |mut iter| async move {
+            iter.next_async().await.map(|item| (item, iter))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::users::GetFollowedArtists,
+        test::{
+            client::{ExpectedUrl, MultiTestClient},
+            fixtures,
+        },
+    };
+    use futures_util::StreamExt as _;
+    use http::StatusCode;
+    use serde_json::{Value, json};
+
+    fn artist(id: &str) -> Value {
+        let mut artist = fixtures::artist(id);
+        artist["name"] = id.into();
+        artist
+    }
+
+    fn followed_artists(ids: &[&str], after: Option<&str>) -> Value {
+        json!({
+            "artists": {
+                "href": "https://api.spotify.com/v1/me/following?type=artist",
+                "limit": ids.len(),
+                "next": after.map(|after| {
+                    format!("https://api.spotify.com/v1/me/following?type=artist&after={after}")
+                }),
+                "cursors": { "after": after },
+                "total": 3,
+                "items": ids.iter().map(|id| artist(id)).collect::<Vec<_>>()
+            }
+        })
+    }
+
+    fn followed_artists_client() -> MultiTestClient {
+        MultiTestClient::new_json([
+            (
+                ExpectedUrl::builder()
+                    .endpoint("me/following")
+                    .add_query_params(&[("type", "artist")])
+                    .build(),
+                followed_artists(&["1", "2"], Some("2")),
+            ),
+            (
+                ExpectedUrl::builder()
+                    .endpoint("me/following")
+                    .add_query_params(&[("type", "artist"), ("after", "2")])
+                    .build(),
+                followed_artists(&["3"], None),
+            ),
+        ])
+    }
+
+    #[test]
+    fn followed_artists_pages() {
+        let client = followed_artists_client();
+
+        let ids = LazilyCursorPagedIter::new(GetFollowedArtists::default(), &client)
+            .map(|artist| artist.map(|artist| artist.id))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(ids, ["1", "2", "3"]);
+        assert_eq!(client.requests().len(), 2);
+    }
+
+    #[test]
+    fn followed_artists_are_fetched_lazily() {
+        let client = followed_artists_client();
+        let endpoint = GetFollowedArtists::default();
+
+        let first = LazilyCursorPagedIter::new(&endpoint, &client)
+            .take(2)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(client.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn followed_artists_pages_async() {
+        let client = followed_artists_client();
+
+        let ids = LazilyCursorPagedIter::new(GetFollowedArtists::default(), &client)
+            .into_async()
+            .map(|artist| artist.map(|artist| artist.id))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(ids, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn error_stops_at_page() {
+        let client = MultiTestClient::new_json([(
+            ExpectedUrl::builder()
+                .endpoint("me/following")
+                .add_query_params(&[("type", "artist")])
+                .status(StatusCode::UNAUTHORIZED)
+                .build(),
+            json!({ "error": { "status": 401, "message": "The access token expired" } }),
+        )]);
+
+        let err = LazilyCursorPagedIter::new(GetFollowedArtists::default(), &client)
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::SpotifyObjectWithStatus { .. }));
+    }
+
+    #[cfg(feature = "player")]
+    #[test]
+    fn recently_played_pages() {
+        use crate::{api::player::GetRecentlyPlayedTracks, model::QueryRange};
+
+        fn play_history(id: &str) -> Value {
+            json!({
+                "track": fixtures::track(id),
+                "played_at": "2024-12-11T00:00:00.000Z",
+                "context": {
+                    "type": "playlist",
+                    "href": null,
+                    "external_urls": { "spotify": "string" },
+                    "uri": "string"
+                }
+            })
+        }
+
+        fn recently_played(ids: &[&str], before: Option<&str>) -> Value {
+            json!({
+                "href": "https://api.spotify.com/v1/me/player/recently-played?limit=2",
+                "limit": 2,
+                "next": before.map(|before| {
+                    format!("https://api.spotify.com/v1/me/player/recently-played?before={before}&limit=2")
+                }),
+                "cursors": { "after": "1733877079", "before": before },
+                "items": ids.iter().map(|id| play_history(id)).collect::<Vec<_>>()
+            })
+        }
+
+        let client = MultiTestClient::new_json([
+            (
+                ExpectedUrl::builder()
+                    .endpoint("me/player/recently-played")
+                    .add_query_params(&[("limit", "2"), ("before", "1733877079")])
+                    .build(),
+                recently_played(&["1", "2"], Some("1733870000")),
+            ),
+            (
+                ExpectedUrl::builder()
+                    .endpoint("me/player/recently-played")
+                    .add_query_params(&[("limit", "2"), ("before", "1733870000")])
+                    .build(),
+                recently_played(&["3"], None),
+            ),
+        ]);
+
+        let endpoint = GetRecentlyPlayedTracks {
+            limit: Some(2),
+            timeframe: Some(QueryRange::Before(1_733_877_079)),
+        };
+
+        let ids = LazilyCursorPagedIter::new(endpoint, &client)
+            .map(|item| item.map(|item| item.track.id))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(ids, ["1", "2", "3"]);
+        assert_eq!(client.requests().len(), 2);
+    }
+}
@@ -10,6 +10,7 @@ use futures_util::Stream;
 use http::{Method, Request, Response, header, request::Builder as RequestBuilder};
 use parking_lot::RwLock;
 use serde::de::DeserializeOwned;
+use std::ops::ControlFlow;
 use url::Url;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -53,10 +54,16 @@ where
             _ => 0,
         };
 
+        let next_page = if paged.pagination.remaining(0) == Some(0) {
+            PageCursor::Done
+        } else {
+            PageCursor::First
+        };
+
         let page_state = PageState {
             offset,
             total: 0,
-            next_page: PageCursor::First,
+            next_page,
         };
 
         Self {
@@ -77,7 +84,9 @@ impl<E> LazilyPagedState<E> {
         {
             PageCursor::Done
         } else {
-            next_url.map_or(PageCursor::Done, PageCursor::Next)
+            next_url.map_or(PageCursor::Done, |url| {
+                PageCursor::Next(self.paged.pagination.next_page_url(url, page_state.total))
+            })
         };
     }
 }
@@ -101,6 +110,8 @@ where
         let url = if let Some(next_url) = next_page.next_url() {
             next_url.clone()
         } else {
+            query::check_scopes(&self.paged.endpoint, client)?;
+
             let mut url = self
                 .paged
                 .endpoint
@@ -152,18 +163,30 @@ where
     {
         let status = rsp.status();
 
-        let v = serde_json::from_slice(rsp.body())
-            .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-
-        if !status.is_success() {
-            return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
+        if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(header::LOCATION),
             ));
         }
 
-        let page = serde_json::from_value::<Page<T>>(v).map_err(ApiError::data_type::<Page<T>>)?;
+        let body = query::decode_body(rsp)?;
+        let v = serde_json::from_slice(&body)
+            .map_err(|_e| ApiError::unparsable_response(rsp, &body))?;
+
+        if !status.is_success() {
+            return Err(ApiError::from_spotify_with_status(status, v));
+        }
+
+        let mut page =
+            serde_json::from_value::<Page<T>>(v).map_err(ApiError::data_type::<Page<T>>)?;
+
+        if let Some(remaining) = self
+            .paged
+            .pagination
+            .remaining(self.page_state.read().total)
+        {
+            page.items.truncate(remaining);
+        }
 
         let next_url = page.next.as_ref().map(|url| Url::parse(url)).transpose()?;
 
@@ -173,20 +196,52 @@ where
     }
 }
 
-impl<E, T, C> Query<Vec<T>, C> for LazilyPagedState<E>
+impl<E> LazilyPagedState<E>
 where
     E: Endpoint + Pageable,
-    T: DeserializeOwned,
-    C: Client,
 {
-    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+    /// Fetch the next page, or `None` if every page has been fetched.
+    fn fetch_page<C, T>(&self, client: &C) -> Result<Option<Page<T>>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: Client,
+    {
         let Some(url) = self.page_url(client)? else {
-            return Ok(Vec::new());
+            return Ok(None);
         };
         let (req, data) = self.build_request::<C>(&url)?;
         let rsp = client.rest(req, data)?;
-        let page = self.process_response::<C, _>(&rsp)?;
-        Ok(page.items)
+        self.process_response::<C, _>(&rsp).map(Some)
+    }
+
+    /// Asynchronously fetch the next page, or `None` if every page has been fetched.
+    async fn fetch_page_async<C, T>(
+        &self,
+        client: &C,
+    ) -> Result<Option<Page<T>>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: AsyncClient + Sync,
+    {
+        let Some(url) = self.page_url(client)? else {
+            return Ok(None);
+        };
+        let (req, data) = self.build_request::<C>(&url)?;
+        let rsp = client.rest_async(req, data).await?;
+        self.process_response::<C, _>(&rsp).map(Some)
+    }
+}
+
+impl<E, T, C> Query<Vec<T>, C> for LazilyPagedState<E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        Ok(self
+            .fetch_page(client)?
+            .map_or_else(Vec::new, |page| page.items))
     }
 }
 
@@ -198,13 +253,10 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
-        let Some(url) = self.page_url(client)? else {
-            return Ok(Vec::new());
-        };
-        let (req, data) = self.build_request::<C>(&url)?;
-        let rsp = client.rest_async(req, data).await?;
-        let page = self.process_response::<C, _>(&rsp)?;
-        Ok(page.items)
+        Ok(self
+            .fetch_page_async(client)
+            .await?
+            .map_or_else(Vec::new, |page| page.items))
     }
 }
 
@@ -287,11 +339,7 @@ where
     /// Create an iterator over the results of paginated results for with a client.
     #[allow(clippy::iter_not_returning_iterator)]
     pub fn iter<'a, C, T>(&'a self, client: &'a C) -> LazilyPagedIter<'a, &'a E, C, T> {
-        let borrowed = Paged::<&E> {
-            endpoint: &self.endpoint,
-            pagination: self.pagination,
-        };
-        LazilyPagedIter::new(borrowed, client)
+        LazilyPagedIter::new(self.borrowed(), client)
     }
 
     /// Create an iterator over the results of paginated results for with a client.
@@ -300,6 +348,72 @@ where
     }
 }
 
+impl<E> Paged<E>
+where
+    E: Endpoint + Pageable,
+{
+    /// Fetch the pages one at a time, passing each to `f` as soon as it arrives.
+    ///
+    /// This is useful for reporting progress during long syncs, since each [`Page`] carries
+    /// its `offset` and the `total` number of items. Returning [`ControlFlow::Break`] from
+    /// `f` stops fetching further pages.
+    ///
+    /// # Errors
+    /// Returns the first error encountered while fetching a page.
+    pub fn for_each_page<C, T, F>(&self, client: &C, mut f: F) -> Result<(), ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: Client,
+        F: FnMut(Page<T>) -> ControlFlow<()>,
+    {
+        let state = LazilyPagedState::new(self.borrowed());
+
+        while let Some(page) = state.fetch_page(client)? {
+            if f(page).is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously fetch the pages one at a time, passing each to `f` as soon as it arrives.
+    ///
+    /// See [`Paged::for_each_page`].
+    ///
+    /// # Errors
+    /// Returns the first error encountered while fetching a page.
+    pub async fn for_each_page_async<C, T, F>(
+        &self,
+        client: &C,
+        mut f: F,
+    ) -> Result<(), ApiError<C::Error>>
+    where
+        E: Sync,
+        T: DeserializeOwned,
+        C: AsyncClient + Sync,
+        F: FnMut(Page<T>) -> ControlFlow<()>,
+    {
+        let state = LazilyPagedState::new(self.borrowed());
+
+        while let Some(page) = state.fetch_page_async(client).await? {
+            if f(page).is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn borrowed(&self) -> Paged<&E> {
+        Paged {
+            endpoint: &self.endpoint,
+            pagination: self.pagination,
+            max_concurrent: self.max_concurrent,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -411,6 +525,135 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pagination_take() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build();
+
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=254).map(|value| DummyResult { value }));
+
+        let res: Vec<DummyResult> = api::paged_take(Dummy, 75)
+            .iter(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(res.len(), 75);
+
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+
+        assert_eq!(client.page_params(), [(0, 50), (50, 25)]);
+
+        let res: Vec<DummyResult> = api::paged_take(Dummy, 0).query(&client).unwrap();
+        assert!(res.is_empty());
+        assert_eq!(client.requests().len(), 2);
+    }
+
+    #[test]
+    fn for_each_page() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build();
+
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=120).map(|value| DummyResult { value }));
+        let paged = api::paged(Dummy, Pagination::All);
+
+        let mut pages = 0;
+        paged
+            .for_each_page(&client, |page: Page<DummyResult>| {
+                assert_eq!(page.offset, pages * 50);
+                assert_eq!(page.total, 121);
+                pages += 1;
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(pages, 3);
+
+        let mut items = Vec::new();
+        paged
+            .for_each_page(&client, |page: Page<DummyResult>| {
+                items.extend(page.items);
+                if items.len() >= 100 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .unwrap();
+        assert_eq!(items.len(), 100);
+    }
+
+    #[tokio::test]
+    async fn for_each_page_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build();
+
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=120).map(|value| DummyResult { value }));
+
+        let mut pages = 0;
+        api::paged(Dummy, Pagination::All)
+            .for_each_page_async(&client, |_: Page<DummyResult>| {
+                pages += 1;
+                if pages == 2 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .await
+            .unwrap();
+        assert_eq!(pages, 2);
+    }
+
+    #[test]
+    fn gzip_encoded_response() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write as _;
+
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("offset", "0"), ("limit", "50")])
+            .response_header("content-encoding", "gzip")
+            .build();
+
+        let page = json!({
+            "href": "https://api.spotify.com/v1/paged_dummy?offset=0&limit=50",
+            "limit": 50,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 3,
+            "items": [{ "value": 0 }, { "value": 1 }, { "value": 2 }],
+        });
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&serde_json::to_vec(&page).unwrap())
+            .unwrap();
+
+        let client = SingleTestClient::new_raw(endpoint, encoder.finish().unwrap());
+
+        let res: Vec<DummyResult> = api::paged(Dummy, Pagination::All)
+            .iter(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(res.len(), 3);
+
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+    }
+
     #[test]
     fn non_json_response() {
         let endpoint = ExpectedUrl::builder()
@@ -432,6 +675,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn html_response() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("offset", "0"), ("limit", "50")])
+            .response_header("content-type", "text/html")
+            .build();
+
+        let client =
+            SingleTestClient::new_raw(endpoint, "<html><body>Service Unavailable</body></html>");
+
+        let res: Result<Vec<DummyResult>, _> =
+            api::paged(Dummy, Pagination::All).iter(&client).collect();
+
+        let err = res.unwrap_err();
+
+        if let ApiError::UnexpectedContentType { content_type } = err {
+            assert_eq!(content_type, "text/html");
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+
     #[test]
     fn error_bad_json() {
         let endpoint = ExpectedUrl::builder()
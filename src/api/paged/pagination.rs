@@ -1,7 +1,13 @@
+use url::Url;
+
 pub(crate) const MAX_LIMIT: usize = 50;
 
 /// Pagination options for Spotify.
+///
+/// This enum is marked as `#[non_exhaustive]`, meaning new variants may be added in future versions.
+/// When matching against it, include a wildcard arm (`_`) to account for any future variants.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Pagination {
     #[default]
     All,
@@ -10,13 +16,17 @@ pub enum Pagination {
         limit: usize,
         offset: usize,
     },
+    /// Fetch pages until the given number of items has been collected.
+    Take(usize),
 }
 
 impl Pagination {
     pub(crate) fn limit(self) -> usize {
         match self {
             Self::All => MAX_LIMIT,
-            Self::Limit(limit) | Self::Page { limit, .. } => limit.min(MAX_LIMIT),
+            Self::Limit(limit) | Self::Page { limit, .. } | Self::Take(limit) => {
+                limit.min(MAX_LIMIT)
+            }
         }
     }
 
@@ -25,8 +35,32 @@ impl Pagination {
             Self::All => last_page_size < MAX_LIMIT,
             Self::Limit(limit) => limit <= num_results || limit > MAX_LIMIT,
             Self::Page { limit, offset } => last_page_size < limit || offset + limit >= num_results,
+            Self::Take(limit) => limit <= num_results || last_page_size == 0,
+        }
+    }
+
+    /// The number of items still to be collected, if the pagination is capped.
+    pub(crate) fn remaining(self, num_results: usize) -> Option<usize> {
+        match self {
+            Self::Take(limit) => Some(limit.saturating_sub(num_results)),
+            _ => None,
         }
     }
+
+    /// Adjusts the `next` URL returned by Spotify so that no more items than needed are fetched.
+    pub(crate) fn next_page_url(self, url: Url, num_results: usize) -> Url {
+        let Some(remaining) = self.remaining(num_results) else {
+            return url;
+        };
+
+        let mut next_url = url.clone();
+        next_url
+            .query_pairs_mut()
+            .clear()
+            .extend_pairs(url.query_pairs().filter(|(key, _)| key != "limit"))
+            .append_pair("limit", &remaining.min(MAX_LIMIT).to_string());
+        next_url
+    }
 }
 
 #[cfg(test)]
@@ -43,5 +77,18 @@ mod tests {
         assert_eq!(Pagination::All.limit(), MAX_LIMIT);
         assert_eq!(Pagination::Limit(MAX_LIMIT).limit(), MAX_LIMIT,);
         assert_eq!(Pagination::Limit(1).limit(), 1);
+        assert_eq!(Pagination::Take(75).limit(), MAX_LIMIT);
+    }
+
+    #[test]
+    fn take_next_page_url() {
+        let url = Url::parse("https://api.spotify.com/v1/me/tracks?offset=50&limit=50&market=US")
+            .unwrap();
+
+        assert_eq!(
+            Pagination::Take(75).next_page_url(url.clone(), 50).as_str(),
+            "https://api.spotify.com/v1/me/tracks?offset=50&market=US&limit=25"
+        );
+        assert_eq!(Pagination::All.next_page_url(url.clone(), 50), url);
     }
 }
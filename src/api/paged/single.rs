@@ -0,0 +1,242 @@
+use super::{MAX_LIMIT, Pageable};
+use crate::{
+    api::{
+        ApiError, AsyncClient, AsyncQuery, BodyError, Client, Endpoint, Query, QueryParams, UrlBase,
+    },
+    auth::scopes::Scope,
+    model::Page,
+};
+use async_trait::async_trait;
+use http::Method;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+
+/// A query modifier that fetches a single page of a paged endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SinglePage<E> {
+    endpoint: E,
+    limit: usize,
+    offset: usize,
+}
+
+/// Fetch a single page of a paged endpoint, keeping the paging metadata.
+///
+/// Unlike the `paged_*` modifiers, the `next` link is not followed and the returned [`Page`]
+/// keeps its `total`, `next` and `previous` fields, e.g. to implement a "load more" button.
+/// The first 50 items are requested unless [`SinglePage::limit`] or
+/// [`SinglePage::offset`] are set.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::{single_page, Query, playlists::GetCurrentUserPlaylists};
+/// use spotify_web_api::model::{Page, SimplifiedPlaylist};
+///
+/// # fn example(client: &impl spotify_web_api::api::Client) {
+/// let page: Page<SimplifiedPlaylist> = single_page(GetCurrentUserPlaylists::default())
+///     .limit(20)
+///     .query(client)
+///     .unwrap();
+///
+/// let has_more = page.next.is_some();
+/// # }
+/// ```
+pub fn single_page<E>(endpoint: E) -> SinglePage<E> {
+    SinglePage {
+        endpoint,
+        limit: MAX_LIMIT,
+        offset: 0,
+    }
+}
+
+impl<E> SinglePage<E> {
+    /// The maximum number of items to return.
+    ///
+    /// If the limit is greater than the maximum limit of 50, the maximum limit will be used.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit.min(MAX_LIMIT);
+        self
+    }
+
+    /// The index of the first item to return.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn page_endpoint(&self) -> PageEndpoint<'_, E> {
+        PageEndpoint {
+            endpoint: &self.endpoint,
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+/// The wrapped endpoint with the `offset` and `limit` of the page appended to its parameters.
+struct PageEndpoint<'a, E> {
+    endpoint: &'a E,
+    limit: usize,
+    offset: usize,
+}
+
+impl<E> Endpoint for PageEndpoint<'_, E>
+where
+    E: Endpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.endpoint()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.endpoint.url_base()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = self.endpoint.parameters();
+        params
+            .push("offset", &self.offset.to_string())
+            .push("limit", &self.limit.to_string());
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.endpoint.body()
+    }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        self.endpoint.required_scopes()
+    }
+}
+
+impl<E, T, C> Query<Page<T>, C> for SinglePage<E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Page<T>, ApiError<C::Error>> {
+        self.page_endpoint().query(client)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<Page<T>, C> for SinglePage<E>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Page<T>, ApiError<C::Error>> {
+        self.page_endpoint().query_async(client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::client::{ExpectedUrl, PagedTestClient};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "paged_dummy".into()
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    #[derive(Debug, Clone, Deserialize, Serialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    fn client() -> PagedTestClient<DummyResult> {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build();
+
+        PagedTestClient::new_raw(endpoint, (0..=255).map(|value| DummyResult { value }))
+    }
+
+    #[test]
+    fn single_page_defaults() {
+        let client = client();
+
+        let page: Page<DummyResult> = single_page(Dummy).query(&client).unwrap();
+
+        assert_eq!(page.items.len(), MAX_LIMIT);
+        assert_eq!(page.items[0].value, 0);
+        assert_eq!(page.offset, 0);
+        assert_eq!(page.total, 256);
+        assert!(page.next.is_some());
+        assert_eq!(client.requests().len(), 1);
+    }
+
+    #[test]
+    fn single_page_limit_and_offset() {
+        let client = client();
+
+        let page: Page<DummyResult> = single_page(Dummy)
+            .limit(20)
+            .offset(100)
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(page.items.len(), 20);
+        assert_eq!(page.items[0].value, 100);
+        assert_eq!(page.limit, 20);
+        assert_eq!(page.offset, 100);
+        assert_eq!(page.total, 256);
+        assert!(page.next.is_some());
+        assert!(page.previous.is_some());
+        assert_eq!(client.requests().len(), 1);
+    }
+
+    #[test]
+    fn single_page_last_page() {
+        let client = client();
+
+        let page: Page<DummyResult> = single_page(Dummy).offset(250).query(&client).unwrap();
+
+        assert_eq!(page.items.len(), 6);
+        assert_eq!(page.total, 256);
+        assert!(page.next.is_none());
+    }
+
+    #[test]
+    fn single_page_limit_is_capped() {
+        assert_eq!(single_page(Dummy).limit(100).limit, MAX_LIMIT);
+    }
+
+    #[tokio::test]
+    async fn single_page_async() {
+        let client = client();
+
+        let page: Page<DummyResult> = single_page(Dummy)
+            .limit(10)
+            .offset(50)
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 10);
+        assert_eq!(page.items[0].value, 50);
+        assert_eq!(page.total, 256);
+        assert!(page.next.is_some());
+        assert_eq!(client.requests().len(), 1);
+    }
+}
@@ -71,6 +71,12 @@ impl ParamValue<'static> for i64 {
     }
 }
 
+impl ParamValue<'static> for f32 {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_string().into()
+    }
+}
+
 impl ParamValue<'static> for f64 {
     fn as_value(&self) -> Cow<'static, str> {
         self.to_string().into()
@@ -84,6 +90,12 @@ impl ParamValue<'static> for DateTime<Utc> {
     }
 }
 
+impl<'a> ParamValue<'a> for &'a crate::model::Market {
+    fn as_value(&self) -> Cow<'a, str> {
+        self.as_str().into()
+    }
+}
+
 impl<'a> ParamValue<'a> for &'a crate::model::AlbumType {
     fn as_value(&self) -> Cow<'a, str> {
         self.to_string().into()
@@ -114,12 +126,22 @@ impl<'a> ParamValue<'a> for &'a crate::model::IncludeExternalType {
     }
 }
 
+#[cfg(feature = "player")]
 impl ParamValue<'static> for crate::model::RepeatState {
     fn as_value(&self) -> Cow<'static, str> {
         self.to_string().into()
     }
 }
 
+#[cfg(feature = "player")]
+impl ParamValue<'static> for crate::model::QueryRange {
+    fn as_value(&self) -> Cow<'static, str> {
+        match self {
+            Self::Before(time) | Self::After(time) => time.to_string().into(),
+        }
+    }
+}
+
 impl ParamValue<'static> for crate::model::PlaylistItem {
     fn as_value(&self) -> Cow<'static, str> {
         self.to_string().into()
@@ -208,6 +230,37 @@ impl JsonParams {
     }
 }
 
+/// Where an endpoint sends its list of IDs.
+///
+/// Spotify accepts the IDs of library endpoints either as a comma-separated `ids` query
+/// parameter, or as a JSON body of the form `{ "ids": [...] }`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IdsPlacement {
+    /// Send the IDs as the `ids` query parameter.
+    #[default]
+    Query,
+
+    /// Send the IDs in a JSON body.
+    Body,
+}
+
+impl IdsPlacement {
+    pub(crate) fn parameters(self, ids: &[String]) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        if self == Self::Query {
+            params.push("ids", &ids.join(","));
+        }
+        params
+    }
+
+    pub(crate) fn body(self, ids: &[String]) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        match self {
+            Self::Query => Ok(None),
+            Self::Body => JsonParams::into_body(&serde_json::json!({ "ids": ids })),
+        }
+    }
+}
+
 /// A structure for query parameters.
 #[derive(Debug, Default, Clone)]
 pub struct QueryParams<'a> {
@@ -292,6 +345,21 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "player")]
+    #[test]
+    fn test_query_range_as_value() {
+        use crate::model::QueryRange;
+
+        let items = &[
+            (QueryRange::Before(1733877079), "1733877079"),
+            (QueryRange::After(1484811043508), "1484811043508"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_value(), *s);
+        }
+    }
+
     #[test]
     fn json_params_clean() {
         let dirty = json!({
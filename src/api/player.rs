@@ -13,6 +13,7 @@ mod skip_to_previous;
 mod start_playback;
 mod toggle_playback_shuffle;
 mod transfer_playback;
+mod volume;
 
 pub use add_item_to_playback_queue::*;
 pub use get_available_devices::*;
@@ -29,3 +30,4 @@ pub use skip_to_previous::*;
 pub use start_playback::*;
 pub use toggle_playback_shuffle::*;
 pub use transfer_playback::*;
+pub use volume::*;
@@ -1,7 +1,7 @@
 use crate::api::prelude::*;
 
 /// Get the object currently being played on the user's Spotify account.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct GetCurrentlyPlayingTrack {
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -11,6 +11,21 @@ pub struct GetCurrentlyPlayingTrack {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// Whether the client supports episodes as well as tracks.
+    ///
+    /// Defaults to `true` when the `shows` feature is enabled. If `false`, Spotify returns no
+    /// item while an episode is playing.
+    pub include_episodes: bool,
+}
+
+impl Default for GetCurrentlyPlayingTrack {
+    fn default() -> Self {
+        Self {
+            market: None,
+            include_episodes: cfg!(feature = "shows"),
+        }
+    }
 }
 
 impl Endpoint for GetCurrentlyPlayingTrack {
@@ -25,8 +40,15 @@ impl Endpoint for GetCurrentlyPlayingTrack {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        if self.include_episodes {
+            params.push("additional_types", &"episode");
+        }
         params
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserReadCurrentlyPlaying]
+    }
 }
 
 #[cfg(test)]
@@ -39,13 +61,30 @@ mod tests {
 
     #[test]
     fn test_get_currently_playing_track_endpoint() {
+        let mut endpoint = ExpectedUrl::builder();
+        endpoint.endpoint("me/player/currently-playing");
+        #[cfg(feature = "shows")]
+        endpoint.add_query_params(&[("additional_types", "episode")]);
+
+        let client = SingleTestClient::new_raw(endpoint.build(), "");
+
+        let endpoint = GetCurrentlyPlayingTrack::default();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_currently_playing_track_endpoint_without_episodes() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("me/player/currently-playing")
             .build();
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetCurrentlyPlayingTrack::default();
+        let endpoint = GetCurrentlyPlayingTrack {
+            include_episodes: false,
+            ..Default::default()
+        };
 
         api::ignore(endpoint).query(&client).unwrap();
     }
@@ -1,7 +1,7 @@
 use crate::api::prelude::*;
 
 /// Get information about the user’s current playback state, including track or episode, progress, and active device.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct GetPlaybackState {
     /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
     /// If a country code is specified, only content that is available in that market will be returned.
@@ -11,6 +11,21 @@ pub struct GetPlaybackState {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// Whether the client supports episodes as well as tracks.
+    ///
+    /// Defaults to `true` when the `shows` feature is enabled. If `false`, Spotify returns no
+    /// item while an episode is playing.
+    pub include_episodes: bool,
+}
+
+impl Default for GetPlaybackState {
+    fn default() -> Self {
+        Self {
+            market: None,
+            include_episodes: cfg!(feature = "shows"),
+        }
+    }
 }
 
 impl Endpoint for GetPlaybackState {
@@ -25,8 +40,15 @@ impl Endpoint for GetPlaybackState {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        if self.include_episodes {
+            params.push("additional_types", &"episode");
+        }
         params
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserReadPlaybackState]
+    }
 }
 
 #[cfg(test)]
@@ -39,11 +61,28 @@ mod tests {
 
     #[test]
     fn test_get_playback_state_endpoint() {
+        let mut endpoint = ExpectedUrl::builder();
+        endpoint.endpoint("me/player");
+        #[cfg(feature = "shows")]
+        endpoint.add_query_params(&[("additional_types", "episode")]);
+
+        let client = SingleTestClient::new_raw(endpoint.build(), "");
+
+        let endpoint = GetPlaybackState::default();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playback_state_endpoint_without_episodes() {
         let endpoint = ExpectedUrl::builder().endpoint("me/player").build();
 
         let client = SingleTestClient::new_raw(endpoint, "");
 
-        let endpoint = GetPlaybackState::default();
+        let endpoint = GetPlaybackState {
+            include_episodes: false,
+            ..Default::default()
+        };
 
         api::ignore(endpoint).query(&client).unwrap();
     }
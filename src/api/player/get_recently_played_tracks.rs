@@ -1,19 +1,19 @@
 use crate::{
     api::{Endpoint, prelude::*},
-    model::QueryRange,
+    model::{QueryRange, RecentlyPlayedTracks},
 };
 
 /// Get tracks from the current user's recently played tracks.
 /// # Note:
 /// Currently doesn't support podcast episodes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct GetRecentlyPlayedTracks {
     /// The maximum number of items to return. Default: 20. Minimum: 1. Maximum: 50.
     pub limit: Option<u8>,
 
-    /// The Unix timestamp in milliseconds. Returns all items after (but not including) this cursor position.
-    /// If after is specified, before must not be specified.
-    pub timeframe: QueryRange,
+    /// The Unix timestamp in milliseconds to return the items before or after (but not including).
+    /// If omitted, the most recently played items are returned.
+    pub timeframe: Option<QueryRange>,
 }
 
 impl Endpoint for GetRecentlyPlayedTracks {
@@ -31,28 +31,36 @@ impl Endpoint for GetRecentlyPlayedTracks {
         let mut params = QueryParams::default();
         params.push("limit", &limit);
 
-        match self.timeframe {
-            QueryRange::Before(time) => {
-                params.push("before", &time);
-            }
-            QueryRange::After(time) => {
-                params.push("after", &time);
-            }
+        if let Some(timeframe) = &self.timeframe {
+            let key = match timeframe {
+                QueryRange::Before(_) => "before",
+                QueryRange::After(_) => "after",
+            };
+
+            params.push(key, timeframe);
         }
 
         params
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserReadRecentlyPlayed]
+    }
 }
 
 impl From<QueryRange> for GetRecentlyPlayedTracks {
     fn from(timeframe: QueryRange) -> Self {
         Self {
             limit: None,
-            timeframe,
+            timeframe: Some(timeframe),
         }
     }
 }
 
+impl CursorPageable for GetRecentlyPlayedTracks {
+    type Page = RecentlyPlayedTracks;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,4 +83,36 @@ mod tests {
         .query(&client)
         .unwrap();
     }
+
+    #[test]
+    fn test_get_recently_played_tracks_endpoint_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/player/recently-played")
+            .add_query_params(&[("limit", "50"), ("after", "1733877079")])
+            .build();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetRecentlyPlayedTracks {
+            limit: Some(50),
+            timeframe: Some(QueryRange::After(1_733_877_079)),
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_recently_played_tracks_endpoint_latest() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/player/recently-played")
+            .add_query_params(&[("limit", "1")])
+            .build();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetRecentlyPlayedTracks {
+            limit: Some(0),
+            ..GetRecentlyPlayedTracks::default()
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -12,6 +12,10 @@ impl Endpoint for GetUserQueue {
     fn endpoint(&self) -> Cow<'static, str> {
         "me/player/queue".into()
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserReadPlaybackState]
+    }
 }
 
 #[cfg(test)]
@@ -28,4 +32,33 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
         api::ignore(GetUserQueue).query(&client).unwrap();
     }
+
+    #[cfg(feature = "shows")]
+    #[test]
+    fn test_get_user_queue_with_track_and_episode() {
+        use crate::{
+            model::{Queue, TrackItem},
+            test::fixtures,
+        };
+        use serde_json::json;
+
+        let track = fixtures::track("11dFghVXANMlKmJXsNCbNl");
+
+        let episode = fixtures::episode("5Xt5DXGzch68nYYamXrNxZ");
+
+        let endpoint = ExpectedUrl::builder().endpoint("me/player/queue").build();
+        let client = SingleTestClient::new_raw(
+            endpoint,
+            json!({ "currently_playing": track, "queue": [track, episode] }).to_string(),
+        );
+
+        let queue: Queue = GetUserQueue.query(&client).unwrap();
+
+        assert!(matches!(queue.currently_playing, Some(TrackItem::Track(_))));
+        assert!(matches!(
+            queue.queue.as_slice(),
+            [TrackItem::Track(track), TrackItem::Episode(episode)]
+                if track.id == "11dFghVXANMlKmJXsNCbNl" && episode.id == "5Xt5DXGzch68nYYamXrNxZ"
+        ));
+    }
 }
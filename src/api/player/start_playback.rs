@@ -95,12 +95,7 @@ impl Endpoint for StartPlayback {
         }
 
         if let Some(offset) = self.offset.as_ref() {
-            body["offset"] = match offset {
-                Offset::Position(pos) => {
-                    serde_json::json!({ "position": pos })
-                }
-                Offset::Uri(context) => serde_json::json!({ "uri": context.uri() }),
-            }
+            body["offset"] = serde_json::to_value(offset)?;
         }
 
         if let Some(position_ms) = self.position_ms {
@@ -120,7 +115,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        model::AlbumId,
+        model::{AlbumId, PlaylistId},
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -161,4 +156,43 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_start_playback_endpoint_with_uri_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("me/player/play")
+            .body_str(r#"{"context_uri":"spotify:playlist:3cEYpjA9oz9GiPac4AsH4n","offset":{"uri":"spotify:track:1301WleyT98MSxVHPZCA6M"}}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StartPlayback::default()
+            .context_uri(ContextType::Playlist(
+                PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap(),
+            ))
+            .offset(TrackId::from_id("1301WleyT98MSxVHPZCA6M").unwrap());
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_start_playback_endpoint_with_uris() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("me/player/play")
+            .body_str(r#"{"offset":{"position":1},"uris":["spotify:track:4iV5W9uYEdYUVa79Axb7Rh","spotify:track:1301WleyT98MSxVHPZCA6M"]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StartPlayback::default()
+            .uri(TrackId::from_id("4iV5W9uYEdYUVa79Axb7Rh").unwrap())
+            .uri(TrackId::from_id("1301WleyT98MSxVHPZCA6M").unwrap())
+            .offset(1);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -0,0 +1,361 @@
+use super::{GetAvailableDevices, SetPlaybackVolume};
+use crate::{
+    api::{ApiError, AsyncClient, AsyncQuery as _, Client, Query as _, ignore},
+    model::{Device, Devices},
+};
+use std::error::Error;
+use thiserror::Error;
+
+/// Errors which may occur when changing the volume of a device with the volume helpers.
+///
+/// This enum is marked as `#[non_exhaustive]`, meaning new variants may be added in future versions.
+/// When matching against it, include a wildcard arm (`_`) to account for any future variants.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum VolumeError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The playback device could not be found among the user's available devices.
+    #[error("playback device not found: {}", device_id.as_deref().unwrap_or("<ACTIVE>"))]
+    DeviceNotFound {
+        /// The ID of the device, or `None` if the active device was requested.
+        device_id: Option<String>,
+    },
+
+    /// The playback device does not support setting the volume.
+    #[error("device does not support setting the volume: {device}")]
+    VolumeNotSupported {
+        /// The name of the device.
+        device: String,
+    },
+
+    /// The playback device does not report its current volume, so it could not be restored
+    /// after muting.
+    #[error("device does not report its volume: {device}")]
+    VolumeUnknown {
+        /// The name of the device.
+        device: String,
+    },
+
+    /// A request to Spotify failed.
+    #[error("{0}")]
+    Api(#[from] ApiError<E>),
+}
+
+/// The volume of a device before it was muted with [`mute`].
+///
+/// Pass it to [`unmute`] to restore the volume.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MutedDevice {
+    /// The id of the muted device, or `None` if the device does not report one and the
+    /// active device was muted.
+    pub device_id: Option<String>,
+
+    /// The volume of the device before it was muted.
+    pub volume_percent: u8,
+}
+
+/// Set the volume of a device, after checking that the device supports it.
+///
+/// Spotify silently ignores volume changes for devices which do not support them, so the
+/// available devices are fetched first. If `device_id` is `None`, the active device is used.
+///
+/// # Errors
+/// Returns [`VolumeError::DeviceNotFound`] if the device is not available,
+/// [`VolumeError::VolumeNotSupported`] if it does not support setting the volume, or the first
+/// error encountered while making the requests.
+pub fn set_volume_checked<C>(
+    client: &C,
+    device_id: Option<&str>,
+    volume_percent: u8,
+) -> Result<(), VolumeError<C::Error>>
+where
+    C: Client,
+{
+    let devices: Devices = GetAvailableDevices.query(client)?;
+    find_volume_device(devices, device_id)?;
+    ignore(set_volume(device_id, volume_percent)).query(client)?;
+    Ok(())
+}
+
+/// Asynchronously set the volume of a device, after checking that the device supports it.
+///
+/// See [`set_volume_checked`].
+///
+/// # Errors
+/// Returns [`VolumeError::DeviceNotFound`] if the device is not available,
+/// [`VolumeError::VolumeNotSupported`] if it does not support setting the volume, or the first
+/// error encountered while making the requests.
+pub async fn set_volume_checked_async<C>(
+    client: &C,
+    device_id: Option<&str>,
+    volume_percent: u8,
+) -> Result<(), VolumeError<C::Error>>
+where
+    C: AsyncClient + Sync,
+{
+    let devices: Devices = GetAvailableDevices.query_async(client).await?;
+    find_volume_device(devices, device_id)?;
+    ignore(set_volume(device_id, volume_percent))
+        .query_async(client)
+        .await?;
+    Ok(())
+}
+
+/// Mute a device, returning its previous volume.
+///
+/// If `device_id` is `None`, the active device is muted. Its ID is kept in the returned
+/// [`MutedDevice`], so that [`unmute`] restores the volume of the same device even if another
+/// one has become active in the meantime.
+///
+/// # Errors
+/// Returns the same errors as [`set_volume_checked`], or [`VolumeError::VolumeUnknown`] if the
+/// device does not report its current volume, as it could then not be restored.
+pub fn mute<C>(client: &C, device_id: Option<&str>) -> Result<MutedDevice, VolumeError<C::Error>>
+where
+    C: Client,
+{
+    let devices: Devices = GetAvailableDevices.query(client)?;
+    let muted = muted_device(devices, device_id)?;
+    ignore(set_volume(muted.device_id.as_deref(), 0)).query(client)?;
+    Ok(muted)
+}
+
+/// Asynchronously mute a device, returning its previous volume.
+///
+/// See [`mute`].
+///
+/// # Errors
+/// Returns the same errors as [`mute`].
+pub async fn mute_async<C>(
+    client: &C,
+    device_id: Option<&str>,
+) -> Result<MutedDevice, VolumeError<C::Error>>
+where
+    C: AsyncClient + Sync,
+{
+    let devices: Devices = GetAvailableDevices.query_async(client).await?;
+    let muted = muted_device(devices, device_id)?;
+    ignore(set_volume(muted.device_id.as_deref(), 0))
+        .query_async(client)
+        .await?;
+    Ok(muted)
+}
+
+/// Restore the volume of a device muted with [`mute`].
+///
+/// # Errors
+/// Returns the first error encountered while making the request.
+pub fn unmute<C>(client: &C, muted: &MutedDevice) -> Result<(), ApiError<C::Error>>
+where
+    C: Client,
+{
+    ignore(set_volume(muted.device_id.as_deref(), muted.volume_percent)).query(client)
+}
+
+/// Asynchronously restore the volume of a device muted with [`mute_async`].
+///
+/// # Errors
+/// Returns the first error encountered while making the request.
+pub async fn unmute_async<C>(client: &C, muted: &MutedDevice) -> Result<(), ApiError<C::Error>>
+where
+    C: AsyncClient + Sync,
+{
+    ignore(set_volume(muted.device_id.as_deref(), muted.volume_percent))
+        .query_async(client)
+        .await
+}
+
+fn set_volume(device_id: Option<&str>, volume_percent: u8) -> SetPlaybackVolume {
+    SetPlaybackVolume {
+        device_id: device_id.map(ToOwned::to_owned),
+        volume_percent,
+    }
+}
+
+fn find_volume_device<E>(
+    devices: Devices,
+    device_id: Option<&str>,
+) -> Result<Device, VolumeError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    let device = devices
+        .devices
+        .into_iter()
+        .find(|device| match device_id {
+            Some(id) => device.id.as_deref() == Some(id),
+            None => device.is_active,
+        })
+        .ok_or_else(|| VolumeError::DeviceNotFound {
+            device_id: device_id.map(ToOwned::to_owned),
+        })?;
+
+    if device.supports_volume {
+        Ok(device)
+    } else {
+        Err(VolumeError::VolumeNotSupported {
+            device: device.name,
+        })
+    }
+}
+
+fn muted_device<E>(devices: Devices, device_id: Option<&str>) -> Result<MutedDevice, VolumeError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    let device = find_volume_device(devices, device_id)?;
+    let volume_percent = device.volume_percent.ok_or(VolumeError::VolumeUnknown {
+        device: device.name,
+    })?;
+
+    Ok(MutedDevice {
+        device_id: device.id,
+        volume_percent,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::client::{ExpectedUrl, MultiTestClient};
+    use http::Method;
+    use serde_json::{Value, json};
+
+    fn devices() -> (ExpectedUrl, Value) {
+        (
+            ExpectedUrl::builder().endpoint("me/player/devices").build(),
+            json!({
+                "devices": [
+                    {
+                        "id": "speaker",
+                        "is_active": true,
+                        "is_private_session": false,
+                        "is_restricted": false,
+                        "name": "Kitchen",
+                        "type": "Speaker",
+                        "volume_percent": 60,
+                        "supports_volume": true
+                    },
+                    {
+                        "id": "tv",
+                        "is_active": false,
+                        "is_private_session": false,
+                        "is_restricted": false,
+                        "name": "Living Room TV",
+                        "type": "TV",
+                        "volume_percent": null,
+                        "supports_volume": false
+                    }
+                ]
+            }),
+        )
+    }
+
+    fn volume(query: &[(&'static str, &'static str)]) -> (ExpectedUrl, Value) {
+        (
+            ExpectedUrl::builder()
+                .method(Method::PUT)
+                .endpoint("me/player/volume")
+                .add_query_params(query)
+                .build(),
+            Value::Null,
+        )
+    }
+
+    #[test]
+    fn set_volume_supported() {
+        let client = MultiTestClient::new_json([
+            devices(),
+            volume(&[("device_id", "speaker"), ("volume_percent", "30")]),
+        ]);
+
+        set_volume_checked(&client, Some("speaker"), 30).unwrap();
+    }
+
+    #[test]
+    fn set_volume_not_supported() {
+        let client = MultiTestClient::new_json([devices()]);
+
+        let err = set_volume_checked(&client, Some("tv"), 30).unwrap_err();
+        assert!(
+            matches!(err, VolumeError::VolumeNotSupported { device } if device == "Living Room TV")
+        );
+
+        let err = set_volume_checked(&client, Some("phone"), 30).unwrap_err();
+        assert!(
+            matches!(err, VolumeError::DeviceNotFound { device_id } if device_id.as_deref() == Some("phone"))
+        );
+    }
+
+    #[tokio::test]
+    async fn mute_and_unmute() {
+        let client = MultiTestClient::new_json([
+            devices(),
+            volume(&[("device_id", "speaker"), ("volume_percent", "0")]),
+            volume(&[("device_id", "speaker"), ("volume_percent", "60")]),
+        ]);
+
+        let muted = mute_async(&client, Some("speaker")).await.unwrap();
+        assert_eq!(
+            muted,
+            MutedDevice {
+                device_id: Some("speaker".to_owned()),
+                volume_percent: 60,
+            }
+        );
+
+        unmute_async(&client, &muted).await.unwrap();
+    }
+
+    #[test]
+    fn unmute_active_device_after_switching() {
+        let client = MultiTestClient::new_json([
+            devices(),
+            volume(&[("device_id", "speaker"), ("volume_percent", "0")]),
+        ]);
+
+        let muted = mute(&client, None).unwrap();
+        assert_eq!(muted.device_id.as_deref(), Some("speaker"));
+
+        // Playback moves to the TV while the speaker is muted.
+        let (url, mut switched) = devices();
+        switched["devices"][0]["is_active"] = false.into();
+        switched["devices"][1]["is_active"] = true.into();
+        let client = MultiTestClient::new_json([
+            (url, switched),
+            volume(&[("device_id", "speaker"), ("volume_percent", "60")]),
+        ]);
+
+        let devices: Devices = GetAvailableDevices.query(&client).unwrap();
+        assert!(devices.devices[1].is_active);
+
+        // The speaker is unmuted, not whichever device is active by now.
+        unmute(&client, &muted).unwrap();
+    }
+
+    #[test]
+    fn mute_unknown_volume() {
+        let client = MultiTestClient::new_json([(
+            ExpectedUrl::builder().endpoint("me/player/devices").build(),
+            json!({
+                "devices": [
+                    {
+                        "id": "web",
+                        "is_active": true,
+                        "is_private_session": false,
+                        "is_restricted": false,
+                        "name": "Web Player",
+                        "type": "Computer",
+                        "volume_percent": null,
+                        "supports_volume": true
+                    }
+                ]
+            }),
+        )]);
+
+        let err = mute(&client, None).unwrap_err();
+        assert!(matches!(err, VolumeError::VolumeUnknown { device } if device == "Web Player"));
+    }
+}
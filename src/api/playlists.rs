@@ -1,5 +1,6 @@
 mod add_items_to_playlist;
 mod change_playlist_details;
+mod collect_playlist_track_ids;
 mod create_playlist;
 mod get_current_user_playlists;
 mod get_playlist;
@@ -8,9 +9,11 @@ mod get_playlist_items;
 mod get_user_playlists;
 mod remove_playlist_items;
 mod update_playlist_items;
+mod upload_playlist_cover;
 
 pub use add_items_to_playlist::*;
 pub use change_playlist_details::*;
+pub use collect_playlist_track_ids::*;
 pub use create_playlist::*;
 pub use get_current_user_playlists::*;
 pub use get_playlist::*;
@@ -19,3 +22,4 @@ pub use get_playlist_items::*;
 pub use get_user_playlists::*;
 pub use remove_playlist_items::*;
 pub use update_playlist_items::*;
+pub use upload_playlist_cover::*;
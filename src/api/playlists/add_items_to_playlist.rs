@@ -1,4 +1,5 @@
 use crate::{api::prelude::*, model::PlaylistItem};
+use serde_json::json;
 
 /// Add one or more items to a user's playlist.
 #[derive(Debug, Clone)]
@@ -9,7 +10,7 @@ pub struct AddItemsToPlaylist {
     /// The position to insert the items, a zero-based index.
     /// For example, to insert the items in the first position: position=0; to insert the items in the third position: position=2.
     /// If omitted, the items will be appended to the playlist.
-    /// Items are added in the order they are listed in the query string or request body.
+    /// Items are added in the order they are listed.
     pub position: Option<u32>,
 
     /// A list of [Spotify URIs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) to set, can be track or episode URIs.
@@ -25,18 +26,20 @@ impl Endpoint for AddItemsToPlaylist {
         format!("playlists/{}/tracks", self.id).into()
     }
 
-    fn parameters(&self) -> QueryParams<'_> {
-        let mut params = QueryParams::default();
-        let uris: String = self
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let uris = self
             .uris
             .iter()
-            .map(|u| u.to_string())
-            .collect::<Vec<_>>()
-            .join(",");
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
 
-        params.push("uris", &uris);
-        params.push_opt("position", self.position);
-        params
+        let mut body = json!({ "uris": uris });
+
+        if let Some(position) = self.position {
+            body["position"] = json!(position);
+        }
+
+        JsonParams::into_body(&body)
     }
 }
 
@@ -45,7 +48,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        model::TrackId,
+        model::{EpisodeId, TrackId},
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -53,8 +56,9 @@ mod tests {
     fn test_add_items_to_playlist_endpoint() {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
+            .content_type("application/json")
             .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
-            .add_query_params(&[("uris", "spotify:track:60zbztYPxtTQLLcPVjnEZG")])
+            .body_str(r#"{"uris":["spotify:track:60zbztYPxtTQLLcPVjnEZG"]}"#)
             .build();
 
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -69,4 +73,27 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_add_items_to_playlist_endpoint_with_position() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .content_type("application/json")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .body_str(r#"{"position":2,"uris":["spotify:track:60zbztYPxtTQLLcPVjnEZG","spotify:episode:512ojhOuo1ktJprKbVcKyQ"]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddItemsToPlaylist {
+            id: "3cEYpjA9oz9GiPac4AsH4n".to_owned(),
+            position: Some(2),
+            uris: vec![
+                TrackId::from_id("60zbztYPxtTQLLcPVjnEZG").unwrap().into(),
+                EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap().into(),
+            ],
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -0,0 +1,147 @@
+use super::GetPlaylistItems;
+use crate::{
+    api::{ApiError, AsyncClient, AsyncQuery as _, Client, Query as _, paged_all},
+    model::{PlaylistTrack, TrackId, TrackItem},
+};
+
+/// Fetch the IDs of every track in a playlist.
+///
+/// All pages of the playlist are fetched. Episodes and local files are skipped, as they
+/// cannot be used with track endpoints, and so are any tracks whose ID is not a valid
+/// [`TrackId`]; the number of invalid IDs is logged as a warning.
+///
+/// This is useful as a first step for bulk operations, such as adding every track of one
+/// playlist to another or checking which of them are saved.
+///
+/// # Errors
+/// Returns the first error encountered while fetching the playlist items.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::playlists::collect_playlist_track_ids;
+///
+/// # fn example(client: &impl spotify_web_api::api::Client) {
+/// let ids = collect_playlist_track_ids(client, "3cEYpjA9oz9GiPac4AsH4n").unwrap();
+/// # }
+/// ```
+pub fn collect_playlist_track_ids<C>(
+    client: &C,
+    playlist_id: impl Into<String>,
+) -> Result<Vec<TrackId>, ApiError<C::Error>>
+where
+    C: Client,
+{
+    let items: Vec<PlaylistTrack> = paged_all(GetPlaylistItems::from(playlist_id)).query(client)?;
+    Ok(track_ids(items))
+}
+
+/// Asynchronously fetch the IDs of every track in a playlist.
+///
+/// See [`collect_playlist_track_ids`].
+///
+/// # Errors
+/// Returns the first error encountered while fetching the playlist items.
+pub async fn collect_playlist_track_ids_async<C>(
+    client: &C,
+    playlist_id: impl Into<String>,
+) -> Result<Vec<TrackId>, ApiError<C::Error>>
+where
+    C: AsyncClient + Sync,
+{
+    let items: Vec<PlaylistTrack> = paged_all(GetPlaylistItems::from(playlist_id))
+        .query_async(client)
+        .await?;
+    Ok(track_ids(items))
+}
+
+fn track_ids(items: Vec<PlaylistTrack>) -> Vec<TrackId> {
+    let mut invalid = 0;
+
+    let ids = items
+        .into_iter()
+        .filter(|item| !item.is_local)
+        .filter_map(|item| match item.track {
+            TrackItem::Track(track) if !track.is_local => Some(track.id),
+            TrackItem::Track(_) => None,
+            #[cfg(feature = "shows")]
+            TrackItem::Episode(_) => None,
+        })
+        .filter_map(|id| TrackId::from_id(id).inspect_err(|_| invalid += 1).ok())
+        .collect();
+
+    if invalid > 0 {
+        log::warn!("skipped {invalid} playlist tracks with an invalid ID");
+    }
+
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        client::{ExpectedUrl, PagedTestClient},
+        fixtures,
+    };
+    use serde_json::{Value, json};
+
+    fn item(item: &Value, is_local: bool) -> Value {
+        json!({
+            "added_at": null,
+            "added_by": null,
+            "is_local": is_local,
+            "primary_color": null,
+            "video_thumbnail": null,
+            "track": item,
+        })
+    }
+
+    fn track(id: &str, is_local: bool) -> Value {
+        let mut track = fixtures::track(id);
+        track["is_local"] = is_local.into();
+        item(&track, is_local)
+    }
+
+    #[cfg(feature = "shows")]
+    fn episode(id: &str) -> Value {
+        item(&fixtures::episode(id), false)
+    }
+
+    fn client() -> PagedTestClient<Value> {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .add_query_params(&[("additional_types", "track,episode")])
+            .paginated(true)
+            .build();
+
+        PagedTestClient::new_raw(
+            endpoint,
+            [
+                track("11dFghVXANMlKmJXsNCbNl", false),
+                track("4iV5W9uYEdYUVa79Axb7Rh", true),
+                #[cfg(feature = "shows")]
+                episode("5Xt5DXGzch68nYYamXrNxZ"),
+                track("not-a-track-id", false),
+                track("6rqhFgbbKwnb9MLmUQDhG6", false),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_collect_playlist_track_ids() {
+        let ids = collect_playlist_track_ids(&client(), "3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        let ids = ids.iter().map(TrackId::id).collect::<Vec<_>>();
+        assert_eq!(ids, ["11dFghVXANMlKmJXsNCbNl", "6rqhFgbbKwnb9MLmUQDhG6"]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_playlist_track_ids_async() {
+        let ids = collect_playlist_track_ids_async(&client(), "3cEYpjA9oz9GiPac4AsH4n")
+            .await
+            .unwrap();
+
+        assert_eq!(ids.len(), 2);
+    }
+}
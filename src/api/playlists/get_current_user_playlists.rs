@@ -21,8 +21,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_current_user_playlists_endpoint() {
@@ -30,4 +31,33 @@ mod tests {
         let client = SingleTestClient::new_raw(endpoint, "");
         api::ignore(GetCurrentUserPlaylists).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_current_user_playlists_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/playlists")
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..101).map(|i| json!({ "id": i })));
+
+        let playlists: Vec<Value> = api::paged_all(GetCurrentUserPlaylists)
+            .query(&client)
+            .unwrap();
+        assert_eq!(playlists.len(), 101);
+
+        let offsets = client
+            .requests()
+            .iter()
+            .map(|url| url.query().unwrap_or_default().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            offsets,
+            [
+                "offset=0&limit=50",
+                "offset=50&limit=50",
+                "offset=100&limit=50"
+            ]
+        );
+    }
 }
@@ -14,6 +14,17 @@ pub struct GetPlaylist {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// Filters for the query: a comma-separated list of the fields to return.
+    /// If omitted, all fields are returned. For example, to get just the playlist's description
+    /// and URI: `fields=description,uri`.
+    pub fields: Option<String>,
+
+    /// Whether the client supports episodes as well as tracks.
+    ///
+    /// If `false`, Spotify omits the episodes of playlists which contain podcast episodes.
+    /// Defaults to `true`.
+    pub include_episodes: bool,
 }
 
 impl<T: Into<String>> From<T> for GetPlaylist {
@@ -21,6 +32,8 @@ impl<T: Into<String>> From<T> for GetPlaylist {
         Self {
             id: id.into(),
             market: None,
+            fields: None,
+            include_episodes: true,
         }
     }
 }
@@ -37,6 +50,10 @@ impl Endpoint for GetPlaylist {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        params.push_opt("fields", self.fields.as_ref());
+        if self.include_episodes {
+            params.push("additional_types", &"track,episode");
+        }
         params
     }
 }
@@ -53,6 +70,7 @@ mod tests {
     fn test_get_playlist_endpoint() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n")
+            .add_query_params(&[("additional_types", "track,episode")])
             .build();
 
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -61,4 +79,40 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_playlist_endpoint_with_fields() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n")
+            .add_query_params(&[("fields", "snapshot_id")])
+            .add_query_params(&[("additional_types", "track,episode")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaylist {
+            fields: Some("snapshot_id".to_owned()),
+            ..GetPlaylist::from("3cEYpjA9oz9GiPac4AsH4n")
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_playlist_endpoint_without_episodes() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n")
+            .add_query_params(&[("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaylist {
+            market: Some("ES".parse().unwrap()),
+            include_episodes: false,
+            ..GetPlaylist::from("3cEYpjA9oz9GiPac4AsH4n")
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -14,6 +14,19 @@ pub struct GetPlaylistItems {
     /// If neither market or user country are provided, the content is considered unavailable for the client.
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
+
+    /// Filters for the query: a comma-separated list of the fields to return, passed to Spotify
+    /// verbatim. If omitted, all fields are returned.
+    ///
+    /// When paging, the paging fields (`href`, `limit`, `next`, `offset`, `previous` and `total`)
+    /// must be kept, for example `fields=href,limit,next,offset,previous,total,items(track(name))`.
+    pub fields: Option<String>,
+
+    /// Whether the client supports episodes as well as tracks.
+    ///
+    /// If `false`, Spotify omits the episodes of playlists which contain podcast episodes.
+    /// Defaults to `true`.
+    pub include_episodes: bool,
 }
 
 impl Pageable for GetPlaylistItems {}
@@ -23,6 +36,8 @@ impl<T: Into<String>> From<T> for GetPlaylistItems {
         Self {
             id: id.into(),
             market: None,
+            fields: None,
+            include_episodes: true,
         }
     }
 }
@@ -39,6 +54,10 @@ impl Endpoint for GetPlaylistItems {
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("market", self.market.as_ref());
+        params.push_opt("fields", self.fields.as_ref());
+        if self.include_episodes {
+            params.push("additional_types", &"track,episode");
+        }
         params
     }
 }
@@ -48,13 +67,15 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_playlist_items_endpoint() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .add_query_params(&[("additional_types", "track,episode")])
             .build();
 
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -63,4 +84,87 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_playlist_items_endpoint_without_episodes() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetPlaylistItems {
+            include_episodes: false,
+            ..GetPlaylistItems::from("3cEYpjA9oz9GiPac4AsH4n")
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[cfg(feature = "shows")]
+    #[test]
+    fn test_get_playlist_items_with_episode() {
+        use crate::{
+            model::{Page, PlaylistTrack, TrackItem},
+            test::fixtures,
+        };
+
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .add_query_params(&[("additional_types", "track,episode")])
+            .build();
+
+        let page = json!({
+            "href": "string",
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1,
+            "items": [{
+                "added_at": null,
+                "added_by": null,
+                "is_local": false,
+                "primary_color": null,
+                "video_thumbnail": null,
+                "track": fixtures::episode("512ojhOuo1ktJprKbVcKyQ"),
+            }]
+        });
+        let client = SingleTestClient::new_raw(endpoint, page.to_string());
+
+        let page: Page<PlaylistTrack> = GetPlaylistItems::from("3cEYpjA9oz9GiPac4AsH4n")
+            .query(&client)
+            .unwrap();
+
+        assert!(matches!(page.items[0].track, TrackItem::Episode(_)));
+    }
+
+    #[test]
+    fn test_get_playlist_items_endpoint_with_fields_paged() {
+        let fields = "href,limit,next,offset,previous,total,items(track(name,href))";
+
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .add_query_params(&[("fields", fields), ("additional_types", "track,episode")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..70).map(|i| json!({ "track": { "name": i.to_string() } })),
+        );
+
+        let endpoint = GetPlaylistItems {
+            fields: Some(fields.to_owned()),
+            ..GetPlaylistItems::from("3cEYpjA9oz9GiPac4AsH4n")
+        };
+
+        let items: Vec<Value> = api::paged_all(endpoint).query(&client).unwrap();
+        assert_eq!(items.len(), 70);
+        assert_eq!(client.requests().len(), 2);
+        assert!(client.requests().iter().all(|url| {
+            url.query_pairs()
+                .any(|(key, value)| key == "fields" && value == fields)
+        }));
+    }
 }
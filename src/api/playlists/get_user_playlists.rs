@@ -36,8 +36,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_user_playlists_endpoint() {
@@ -51,4 +52,22 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_user_playlists_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/smedjan/playlists")
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..40).map(|i| json!({ "id": i })));
+
+        let playlists: Vec<Value> =
+            api::paged_with_limit_and_offset(GetUserPlaylists::new("smedjan"), 15, 30)
+                .query(&client)
+                .unwrap();
+        assert_eq!(playlists.len(), 10);
+        assert_eq!(playlists[0], json!({ "id": 30 }));
+        assert_eq!(client.requests()[0].query(), Some("offset=30&limit=15"));
+    }
 }
@@ -15,7 +15,7 @@ pub struct RemovePlaylistItems {
 
     /// The playlist's snapshot ID against which you want to make the changes.
     /// The API will validate that the specified items exist and in the specified positions and make the changes, even if more recent changes have been made to the playlist.
-    pub snapshot_id: String,
+    pub snapshot_id: Option<String>,
 }
 
 impl Endpoint for RemovePlaylistItems {
@@ -31,17 +31,14 @@ impl Endpoint for RemovePlaylistItems {
         let tracks = self
             .tracks
             .iter()
-            .map(|item| {
-                let mut obj = json!({});
-                obj["uri"] = item.to_string().clone().into();
-                obj
-            })
+            .map(|item| json!({ "uri": item.to_string() }))
             .collect::<Vec<_>>();
 
-        let body = json!({
-            "snapshot_id": self.snapshot_id,
-            "tracks": tracks,
-        });
+        let mut body = json!({ "tracks": tracks });
+
+        if let Some(snapshot_id) = self.snapshot_id.as_ref() {
+            body["snapshot_id"] = json!(snapshot_id);
+        }
 
         JsonParams::into_body(&body)
     }
@@ -52,7 +49,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        model::TrackId,
+        model::{EpisodeId, TrackId},
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -72,10 +69,30 @@ mod tests {
 
         let endpoint = RemovePlaylistItems {
             id: "3cEYpjA9oz9GiPac4AsH4n".to_owned(),
-            snapshot_id: "abc".to_owned(),
+            snapshot_id: Some("abc".to_owned()),
             tracks: vec![track_1.into(), track_2.into()],
         };
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_remove_playlist_items_endpoint_without_snapshot_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .content_type("application/json")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .body_str(r#"{"tracks":[{"uri":"spotify:episode:512ojhOuo1ktJprKbVcKyQ"}]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemovePlaylistItems {
+            id: "3cEYpjA9oz9GiPac4AsH4n".to_owned(),
+            snapshot_id: None,
+            tracks: vec![EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap().into()],
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
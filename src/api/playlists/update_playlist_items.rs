@@ -6,7 +6,7 @@ use serde_json::json;
 
 /// Either reorder or replace items in a playlist depending on the request's parameters.
 /// To reorder items, include `range_start`, `insert_before`, `range_length` and `snapshot_id` in the request's body.
-/// To replace items, include uris as either a query parameter or in the request's body.
+/// To replace items, set `uris`, in which case the range fields are not sent.
 /// Replacing items in a playlist will overwrite its existing items.
 /// This operation can be used for replacing or clearing items in a playlist.
 #[derive(Debug, Clone)]
@@ -42,6 +42,11 @@ impl UpdatePlaylistItems {
         }
     }
 
+    /// Replace every item of the playlist with `uris`.
+    pub fn replace(id: impl Into<String>, uris: Vec<PlaylistItem>) -> Self {
+        Self::new(id, 0, 0).uris(uris)
+    }
+
     pub fn uris(mut self, uris: Vec<PlaylistItem>) -> Self {
         self.uris = Some(uris);
         self
@@ -72,26 +77,16 @@ impl Endpoint for UpdatePlaylistItems {
         format!("playlists/{}/tracks", self.id).into()
     }
 
-    fn parameters(&self) -> QueryParams<'_> {
-        let mut params = QueryParams::default();
-        let uris: Option<String> = self.uris.as_ref().map(|uris| {
-            uris.iter()
-                .map(|uri| uri.to_string())
-                .collect::<Vec<_>>()
-                .join(",")
-        });
-        params.push_opt("uris", uris);
-        params
-    }
-
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
-        let range_length = self.range_length.unwrap_or(1);
-
-        let mut body = json!({
-            "range_start": self.range_start,
-            "insert_before": self.insert_before,
-            "range_length": range_length,
-        });
+        let mut body = if let Some(uris) = self.uris.as_ref() {
+            json!({ "uris": uris.iter().map(ToString::to_string).collect::<Vec<_>>() })
+        } else {
+            json!({
+                "range_start": self.range_start,
+                "insert_before": self.insert_before,
+                "range_length": self.range_length.unwrap_or(1),
+            })
+        };
 
         if let Some(snapshot_id) = self.snapshot_id.as_ref() {
             body["snapshot_id"] = json!(snapshot_id);
@@ -106,6 +101,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
+        model::{EpisodeId, TrackId},
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -124,4 +120,61 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_update_playlist_items_endpoint_with_snapshot_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .body_str(r#"{"insert_before":0,"range_length":1,"range_start":5,"snapshot_id":"abc"}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdatePlaylistItems::new("3cEYpjA9oz9GiPac4AsH4n", 5, 0).snapshot_id("abc");
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_update_playlist_items_endpoint_replace() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .body_str(r#"{"uris":["spotify:track:4iV5W9uYEdYUVa79Axb7Rh","spotify:episode:512ojhOuo1ktJprKbVcKyQ"]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdatePlaylistItems::replace(
+            "3cEYpjA9oz9GiPac4AsH4n",
+            vec![
+                TrackId::from_id("4iV5W9uYEdYUVa79Axb7Rh").unwrap().into(),
+                EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap().into(),
+            ],
+        );
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_update_playlist_items_endpoint_clear() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/tracks")
+            .body_str(r#"{"uris":[]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        api::ignore(UpdatePlaylistItems::replace(
+            "3cEYpjA9oz9GiPac4AsH4n",
+            vec![],
+        ))
+        .query(&client)
+        .unwrap();
+    }
 }
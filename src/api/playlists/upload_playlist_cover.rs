@@ -0,0 +1,147 @@
+use crate::api::prelude::*;
+use base64::{Engine as _, engine::general_purpose};
+
+/// The largest base64 encoded image payload Spotify accepts, in bytes.
+pub const MAX_COVER_IMAGE_SIZE: usize = 256 * 1024;
+
+/// The bytes every JPEG image starts with.
+const JPEG_MAGIC: [u8; 3] = [0xFF, 0xD8, 0xFF];
+
+/// Replace the image used to represent a specific playlist.
+///
+/// Requires the `ugc-image-upload` and `playlist-modify-public` or `playlist-modify-private`
+/// scopes.
+#[derive(Debug, Clone)]
+pub struct UploadPlaylistCover {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
+    pub id: String,
+
+    /// Base64 encoded JPEG image data, at most [`MAX_COVER_IMAGE_SIZE`] bytes long.
+    pub jpeg_base64: String,
+}
+
+impl UploadPlaylistCover {
+    pub fn new(id: impl Into<String>, jpeg_base64: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            jpeg_base64: jpeg_base64.into(),
+        }
+    }
+
+    /// Base64 encode the raw bytes of a JPEG image for upload.
+    pub fn from_jpeg_bytes(id: impl Into<String>, jpeg: &[u8]) -> Self {
+        Self::new(id, general_purpose::STANDARD.encode(jpeg))
+    }
+}
+
+impl Endpoint for UploadPlaylistCover {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("playlists/{}/images", self.id).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let size = self.jpeg_base64.len();
+
+        // The encoded payload is always larger than the image, so this also bounds the image.
+        if size > MAX_COVER_IMAGE_SIZE {
+            return Err(BodyError::ImageTooLarge {
+                size,
+                max: MAX_COVER_IMAGE_SIZE,
+            });
+        }
+
+        let jpeg = general_purpose::STANDARD
+            .decode(&self.jpeg_base64)
+            .map_err(|_e| BodyError::InvalidImage)?;
+
+        if !jpeg.starts_with(&JPEG_MAGIC) {
+            return Err(BodyError::InvalidImage);
+        }
+
+        Ok(Some(("image/jpeg", self.jpeg_base64.clone().into_bytes())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, ApiError, Query as _},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    const JPEG: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, 0x4A, 0x46, 0x49, 0x46];
+
+    #[test]
+    fn test_upload_playlist_cover_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("image/jpeg")
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/images")
+            .body_str("/9j/4AAQSkZJRg==")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        api::ignore(UploadPlaylistCover::new(
+            "3cEYpjA9oz9GiPac4AsH4n",
+            "/9j/4AAQSkZJRg==",
+        ))
+        .query(&client)
+        .unwrap();
+    }
+
+    #[test]
+    fn from_jpeg_bytes() {
+        let endpoint = UploadPlaylistCover::from_jpeg_bytes("3cEYpjA9oz9GiPac4AsH4n", JPEG);
+
+        assert_eq!(endpoint.id, "3cEYpjA9oz9GiPac4AsH4n");
+        assert_eq!(endpoint.jpeg_base64, "/9j/4AAQSkZJRg==");
+    }
+
+    #[test]
+    fn too_large() {
+        let mut jpeg = JPEG.to_vec();
+        jpeg.resize(MAX_COVER_IMAGE_SIZE, 0);
+
+        let err = UploadPlaylistCover::from_jpeg_bytes("3cEYpjA9oz9GiPac4AsH4n", &jpeg)
+            .body()
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            BodyError::ImageTooLarge {
+                size: 349_528,
+                max: MAX_COVER_IMAGE_SIZE,
+            }
+        ));
+
+        let client = SingleTestClient::new_raw(ExpectedUrl::builder().build(), "");
+        let err = api::ignore(UploadPlaylistCover::from_jpeg_bytes(
+            "3cEYpjA9oz9GiPac4AsH4n",
+            &jpeg,
+        ))
+        .query(&client)
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ApiError::Body(BodyError::ImageTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_image() {
+        let not_base64 = UploadPlaylistCover::new("3cEYpjA9oz9GiPac4AsH4n", "not base64!");
+        assert!(matches!(
+            not_base64.body().unwrap_err(),
+            BodyError::InvalidImage
+        ));
+
+        let png = UploadPlaylistCover::from_jpeg_bytes("3cEYpjA9oz9GiPac4AsH4n", b"\x89PNG");
+        assert!(matches!(png.body().unwrap_err(), BodyError::InvalidImage));
+    }
+}
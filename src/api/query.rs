@@ -1,8 +1,13 @@
 use super::{
-    client::{AsyncClient, Client},
+    client::{AsyncClient, Client, RestClient},
+    endpoint::Endpoint,
     error::ApiError,
 };
 use async_trait::async_trait;
+use bytes::Bytes;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use http::{Method, Request, Response, header, request::Builder as RequestBuilder};
+use std::{error::Error, io::Read as _};
 
 pub fn url_to_http_uri(url: &url::Url) -> http::Uri {
     url.as_str()
@@ -10,6 +15,94 @@ pub fn url_to_http_uri(url: &url::Url) -> http::Uri {
         .expect("failed to parse a url::Url as an http::Uri")
 }
 
+/// Fails if the endpoint requires a scope which the client knows its token has not been granted.
+pub(crate) fn check_scopes<E, C>(endpoint: &E, client: &C) -> Result<(), ApiError<C::Error>>
+where
+    E: Endpoint,
+    C: RestClient,
+{
+    let Some(granted) = client.granted_scopes() else {
+        return Ok(());
+    };
+
+    match endpoint
+        .required_scopes()
+        .iter()
+        .find(|scope| !granted.contains(scope))
+    {
+        Some(scope) => Err(ApiError::missing_scope(*scope)),
+        None => Ok(()),
+    }
+}
+
+/// Builds the request for an endpoint, without any authorization, along with its body.
+pub fn build_request<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
+where
+    E: Endpoint,
+    C: RestClient,
+{
+    check_scopes(endpoint, client)?;
+
+    let mut url = endpoint
+        .url_base()
+        .endpoint_for(client, &endpoint.endpoint())?;
+
+    endpoint.parameters().add_to_url(&mut url);
+
+    let (mime, data) = endpoint
+        .body()?
+        .map_or((None, Vec::new()), |(mime, data)| (Some(mime), data));
+
+    let mut req = Request::builder()
+        .method(endpoint.method())
+        .uri(url_to_http_uri(&url));
+
+    if let Some(mime) = mime {
+        req = req.header(header::CONTENT_TYPE, mime);
+    }
+
+    if matches!(endpoint.method(), Method::POST | Method::PUT) {
+        req = req.header(header::CONTENT_LENGTH, data.len().to_string());
+    }
+
+    Ok((req, data))
+}
+
+/// Returns the body of a response, decompressing it according to its `Content-Encoding`.
+///
+/// The HTTP client usually decompresses responses itself, but a compressed body can still
+/// reach us when decompression is not enabled on the underlying client. Browsers always
+/// decompress responses, so the body is returned as is on wasm32.
+pub fn decode_body<E>(rsp: &Response<Bytes>) -> Result<Bytes, ApiError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    if cfg!(target_arch = "wasm32") {
+        return Ok(rsp.body().clone());
+    }
+
+    let encoding = rsp
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_ascii_lowercase());
+
+    let mut decoded = Vec::new();
+
+    let result = match encoding.as_deref() {
+        Some("gzip" | "x-gzip") => GzDecoder::new(rsp.body().as_ref()).read_to_end(&mut decoded),
+        Some("deflate") => ZlibDecoder::new(rsp.body().as_ref()).read_to_end(&mut decoded),
+        _ => return Ok(rsp.body().clone()),
+    };
+
+    result.map_err(|_e| ApiError::server_error(rsp.status(), rsp.body()))?;
+
+    Ok(decoded.into())
+}
+
 /// A trait which represents a query which may be made to a Spotify client.
 pub trait Query<T, C>
 where
@@ -28,3 +121,62 @@ where
     /// Perform the query asynchronously against the client.
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::client::TestClientError;
+    use flate2::{
+        Compression,
+        write::{GzEncoder, ZlibEncoder},
+    };
+    use http::StatusCode;
+    use std::io::Write as _;
+
+    fn response(encoding: Option<&str>, body: Vec<u8>) -> Response<Bytes> {
+        let mut rsp = Response::builder().status(StatusCode::OK);
+        if let Some(encoding) = encoding {
+            rsp = rsp.header(header::CONTENT_ENCODING, encoding);
+        }
+        rsp.body(body.into()).unwrap()
+    }
+
+    #[test]
+    fn decode_body_identity() {
+        let rsp = response(None, br#"{"value":1}"#.to_vec());
+        let body = decode_body::<TestClientError>(&rsp).unwrap();
+        assert_eq!(body.as_ref(), br#"{"value":1}"#);
+    }
+
+    #[test]
+    fn decode_body_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(br#"{"value":1}"#).unwrap();
+        let rsp = response(Some("gzip"), encoder.finish().unwrap());
+
+        let body = decode_body::<TestClientError>(&rsp).unwrap();
+        assert_eq!(body.as_ref(), br#"{"value":1}"#);
+    }
+
+    #[test]
+    fn decode_body_deflate() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(br#"{"value":1}"#).unwrap();
+        let rsp = response(Some("deflate"), encoder.finish().unwrap());
+
+        let body = decode_body::<TestClientError>(&rsp).unwrap();
+        assert_eq!(body.as_ref(), br#"{"value":1}"#);
+    }
+
+    #[test]
+    fn decode_body_corrupt() {
+        let rsp = response(Some("gzip"), b"not gzip".to_vec());
+        let err = decode_body::<TestClientError>(&rsp).unwrap_err();
+
+        if let ApiError::SpotifyService { status, .. } = err {
+            assert_eq!(status, StatusCode::OK);
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+}
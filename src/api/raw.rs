@@ -33,6 +33,8 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
+        query::check_scopes(&self.endpoint, client)?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -50,17 +52,21 @@ where
         };
         let rsp = client.rest(req, data)?;
         let status = rsp.status();
-        if !status.is_success() {
-            let v = serde_json::from_slice(rsp.body())
-                .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-            return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
+
+        if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(header::LOCATION),
             ));
         }
 
-        Ok(rsp.into_body().as_ref().into())
+        let body = query::decode_body(&rsp)?;
+        if !status.is_success() {
+            let v = serde_json::from_slice(&body)
+                .map_err(|_e| ApiError::server_error(status, &body))?;
+            return Err(ApiError::from_spotify_with_status(status, v));
+        }
+
+        Ok(body.as_ref().into())
     }
 }
 
@@ -71,6 +77,8 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
+        query::check_scopes(&self.endpoint, client)?;
+
         let mut url = self
             .endpoint
             .url_base()
@@ -100,17 +108,20 @@ where
         let rsp = client.rest_async(req, data).await?;
         let status = rsp.status();
 
-        if !status.is_success() {
-            let v = serde_json::from_slice(rsp.body())
-                .map_err(|_e| ApiError::server_error(status, rsp.body()))?;
-            return Err(ApiError::from_spotify_with_status(status, v));
-        } else if status == http::StatusCode::MOVED_PERMANENTLY {
+        if status == http::StatusCode::MOVED_PERMANENTLY {
             return Err(ApiError::moved_permanently(
                 rsp.headers().get(header::LOCATION),
             ));
         }
 
-        Ok(rsp.into_body().as_ref().into())
+        let body = query::decode_body(&rsp)?;
+        if !status.is_success() {
+            let v = serde_json::from_slice(&body)
+                .map_err(|_e| ApiError::server_error(status, &body))?;
+            return Err(ApiError::from_spotify_with_status(status, v));
+        }
+
+        Ok(body.as_ref().into())
     }
 }
 
@@ -154,6 +165,38 @@ mod tests {
         itertools::assert_equal(data, "not json".bytes());
     }
 
+    fn moved_client() -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::MOVED_PERMANENTLY)
+            .response_header("location", "https://api.spotify.com/v1/moved")
+            .build();
+        SingleTestClient::new_raw(endpoint, "")
+    }
+
+    #[test]
+    fn test_moved_permanently() {
+        let err = api::raw(Dummy).query(&moved_client()).unwrap_err();
+        if let ApiError::MovedPermanently { location } = err {
+            assert_eq!(location.unwrap(), "https://api.spotify.com/v1/moved");
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_moved_permanently_async() {
+        let err = api::raw(Dummy)
+            .query_async(&moved_client())
+            .await
+            .unwrap_err();
+        if let ApiError::MovedPermanently { location } = err {
+            assert_eq!(location.unwrap(), "https://api.spotify.com/v1/moved");
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+
     #[test]
     fn test_spotify_error_bad_json() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,3 @@
+mod get_recommendations;
+
+pub use get_recommendations::*;
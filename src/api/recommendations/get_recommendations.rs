@@ -0,0 +1,316 @@
+use crate::{
+    api::{genres::Genre, prelude::*},
+    model::{ArtistId, TrackId},
+};
+
+/// Get recommendations based on seed artists, tracks and genres.
+///
+/// Up to five seeds may be given in any combination of `seed_artists`, `seed_tracks` and
+/// `seed_genres`. The tunable attributes narrow down the recommended tracks: `min_*` and
+/// `max_*` are hard floors and ceilings, while `target_*` only prefers tracks close to the
+/// value. Only the attributes which are `Some` are sent.
+#[derive(Debug, Default, Clone)]
+pub struct GetRecommendations {
+    /// The artists to use as seeds.
+    pub seed_artists: Vec<ArtistId>,
+
+    /// The tracks to use as seeds.
+    pub seed_tracks: Vec<TrackId>,
+
+    /// The genres to use as seeds, validated against
+    /// [`GetAvailableGenreSeeds`](crate::api::genres::GetAvailableGenreSeeds).
+    pub seed_genres: Vec<Genre>,
+
+    /// The target size of the list of recommended tracks. Default: 20. Minimum: 1. Maximum: 100.
+    pub limit: Option<u8>,
+
+    /// An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2).
+    /// If a country code is specified, only content that is available in that market will be returned.
+    /// If a valid user access token is specified in the request header, the country associated with the user account will take priority over this parameter.
+    pub market: Option<Market>,
+
+    /// The minimum acousticness, a confidence measure from 0.0 to 1.0 of whether the track is acoustic.
+    pub min_acousticness: Option<f32>,
+
+    /// The maximum acousticness, a confidence measure from 0.0 to 1.0 of whether the track is acoustic.
+    pub max_acousticness: Option<f32>,
+
+    /// The target acousticness, a confidence measure from 0.0 to 1.0 of whether the track is acoustic.
+    pub target_acousticness: Option<f32>,
+
+    /// The minimum danceability, from 0.0 to 1.0.
+    pub min_danceability: Option<f32>,
+
+    /// The maximum danceability, from 0.0 to 1.0.
+    pub max_danceability: Option<f32>,
+
+    /// The target danceability, from 0.0 to 1.0.
+    pub target_danceability: Option<f32>,
+
+    /// The minimum duration, in milliseconds.
+    pub min_duration_ms: Option<u32>,
+
+    /// The maximum duration, in milliseconds.
+    pub max_duration_ms: Option<u32>,
+
+    /// The target duration, in milliseconds.
+    pub target_duration_ms: Option<u32>,
+
+    /// The minimum energy, a perceptual measure of intensity and activity from 0.0 to 1.0.
+    pub min_energy: Option<f32>,
+
+    /// The maximum energy, a perceptual measure of intensity and activity from 0.0 to 1.0.
+    pub max_energy: Option<f32>,
+
+    /// The target energy, a perceptual measure of intensity and activity from 0.0 to 1.0.
+    pub target_energy: Option<f32>,
+
+    /// The minimum instrumentalness, from 0.0 to 1.0.
+    pub min_instrumentalness: Option<f32>,
+
+    /// The maximum instrumentalness, from 0.0 to 1.0.
+    pub max_instrumentalness: Option<f32>,
+
+    /// The target instrumentalness, from 0.0 to 1.0.
+    pub target_instrumentalness: Option<f32>,
+
+    /// The minimum key, in [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class) from 0 to 11.
+    pub min_key: Option<u32>,
+
+    /// The maximum key, in [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class) from 0 to 11.
+    pub max_key: Option<u32>,
+
+    /// The target key, in [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class) from 0 to 11.
+    pub target_key: Option<u32>,
+
+    /// The minimum liveness, from 0.0 to 1.0.
+    pub min_liveness: Option<f32>,
+
+    /// The maximum liveness, from 0.0 to 1.0.
+    pub max_liveness: Option<f32>,
+
+    /// The target liveness, from 0.0 to 1.0.
+    pub target_liveness: Option<f32>,
+
+    /// The minimum loudness, in decibels.
+    pub min_loudness: Option<f32>,
+
+    /// The maximum loudness, in decibels.
+    pub max_loudness: Option<f32>,
+
+    /// The target loudness, in decibels.
+    pub target_loudness: Option<f32>,
+
+    /// The minimum mode, where major is 1 and minor is 0.
+    pub min_mode: Option<u32>,
+
+    /// The maximum mode, where major is 1 and minor is 0.
+    pub max_mode: Option<u32>,
+
+    /// The target mode, where major is 1 and minor is 0.
+    pub target_mode: Option<u32>,
+
+    /// The minimum popularity, from 0 to 100.
+    pub min_popularity: Option<u32>,
+
+    /// The maximum popularity, from 0 to 100.
+    pub max_popularity: Option<u32>,
+
+    /// The target popularity, from 0 to 100.
+    pub target_popularity: Option<u32>,
+
+    /// The minimum speechiness, from 0.0 to 1.0.
+    pub min_speechiness: Option<f32>,
+
+    /// The maximum speechiness, from 0.0 to 1.0.
+    pub max_speechiness: Option<f32>,
+
+    /// The target speechiness, from 0.0 to 1.0.
+    pub target_speechiness: Option<f32>,
+
+    /// The minimum tempo, in beats per minute.
+    pub min_tempo: Option<f32>,
+
+    /// The maximum tempo, in beats per minute.
+    pub max_tempo: Option<f32>,
+
+    /// The target tempo, in beats per minute.
+    pub target_tempo: Option<f32>,
+
+    /// The minimum time signature, from 3 to 7.
+    pub min_time_signature: Option<u32>,
+
+    /// The maximum time signature, from 3 to 7.
+    pub max_time_signature: Option<u32>,
+
+    /// The target time signature, from 3 to 7.
+    pub target_time_signature: Option<u32>,
+
+    /// The minimum valence, the musical positiveness from 0.0 to 1.0.
+    pub min_valence: Option<f32>,
+
+    /// The maximum valence, the musical positiveness from 0.0 to 1.0.
+    pub max_valence: Option<f32>,
+
+    /// The target valence, the musical positiveness from 0.0 to 1.0.
+    pub target_valence: Option<f32>,
+}
+
+impl Endpoint for GetRecommendations {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "recommendations".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt(
+                "seed_artists",
+                join_ids(self.seed_artists.iter().map(ArtistId::id)),
+            )
+            .push_opt(
+                "seed_tracks",
+                join_ids(self.seed_tracks.iter().map(TrackId::id)),
+            )
+            .push_opt(
+                "seed_genres",
+                join_ids(self.seed_genres.iter().map(Genre::as_str)),
+            )
+            .push_opt("limit", self.limit)
+            .push_opt("market", self.market.as_ref());
+
+        macro_rules! push_tunables {
+            ($($field:ident),* $(,)?) => {
+                $(params.push_opt(stringify!($field), self.$field);)*
+            };
+        }
+
+        push_tunables!(
+            min_acousticness,
+            max_acousticness,
+            target_acousticness,
+            min_danceability,
+            max_danceability,
+            target_danceability,
+            min_duration_ms,
+            max_duration_ms,
+            target_duration_ms,
+            min_energy,
+            max_energy,
+            target_energy,
+            min_instrumentalness,
+            max_instrumentalness,
+            target_instrumentalness,
+            min_key,
+            max_key,
+            target_key,
+            min_liveness,
+            max_liveness,
+            target_liveness,
+            min_loudness,
+            max_loudness,
+            target_loudness,
+            min_mode,
+            max_mode,
+            target_mode,
+            min_popularity,
+            max_popularity,
+            target_popularity,
+            min_speechiness,
+            max_speechiness,
+            target_speechiness,
+            min_tempo,
+            max_tempo,
+            target_tempo,
+            min_time_signature,
+            max_time_signature,
+            target_time_signature,
+            min_valence,
+            max_valence,
+            target_valence,
+        );
+
+        params
+    }
+}
+
+/// Comma-joins the seeds, or returns `None` if there are none.
+fn join_ids<'a>(ids: impl Iterator<Item = &'a str>) -> Option<String> {
+    let joined = ids.collect::<Vec<_>>().join(",");
+    (!joined.is_empty()).then_some(joined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        model::Genres,
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_recommendations_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("recommendations")
+            .add_query_params(&[
+                ("seed_artists", "4NHQUGzhtTLFvgF5SZesLK"),
+                (
+                    "seed_tracks",
+                    "0c6xIDDpzE81m2q797ordA,2takcwOaAZWiXQijPHIx7B",
+                ),
+                ("seed_genres", "classical,country"),
+                ("limit", "10"),
+                ("min_energy", "0.4"),
+                ("target_energy", "0.8"),
+                ("max_popularity", "50"),
+            ])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let genres = Genres {
+            genres: vec!["classical".to_owned(), "country".to_owned()],
+        };
+
+        let endpoint = GetRecommendations {
+            seed_artists: vec![ArtistId::from_id("4NHQUGzhtTLFvgF5SZesLK").unwrap()],
+            seed_tracks: vec![
+                TrackId::from_id("0c6xIDDpzE81m2q797ordA").unwrap(),
+                TrackId::from_id("2takcwOaAZWiXQijPHIx7B").unwrap(),
+            ],
+            seed_genres: vec![
+                genres.genre("classical").unwrap(),
+                genres.genre("country").unwrap(),
+            ],
+            limit: Some(10),
+            min_energy: Some(0.4),
+            target_energy: Some(0.8),
+            max_popularity: Some(50),
+            ..Default::default()
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_get_recommendations_endpoint_without_seeds() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("recommendations")
+            .add_query_params(&[("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetRecommendations {
+            market: Some("ES".parse().unwrap()),
+            ..Default::default()
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    api::{ApiError, AsyncClient, Client, RestClient},
+    auth::scopes::Scope,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Method, Response, StatusCode, request::Builder as RequestBuilder};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use url::Url;
+
+/// A single request made through a [`RecordingClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedCall {
+    /// The HTTP method of the request.
+    pub method: Method,
+
+    /// The full URL of the request, including query parameters.
+    pub url: String,
+
+    /// The body sent with the request.
+    pub body: Vec<u8>,
+
+    /// The HTTP status of the response, or `None` if the inner client returned an error.
+    pub status: Option<StatusCode>,
+}
+
+impl RecordedCall {
+    /// The body of the request as a (lossy) UTF-8 string.
+    pub fn body_str(&self) -> std::borrow::Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+}
+
+/// A client wrapper which records every request sent through it.
+///
+/// This is useful for verifying which Spotify calls higher-level code made without
+/// having to mock each one individually. The wrapped client is still responsible for
+/// actually answering the requests.
+///
+/// This type is only available with the `record` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::{Query as _, RecordingClient, ignore, player::PausePlayback};
+///
+/// # fn example(client: impl spotify_web_api::api::Client) {
+/// let client = RecordingClient::new(client);
+///
+/// ignore(PausePlayback::default()).query(&client).unwrap();
+///
+/// let calls = client.calls();
+/// assert_eq!(calls.len(), 1);
+/// assert!(calls[0].url.ends_with("me/player/pause"));
+/// # }
+/// ```
+pub struct RecordingClient<C> {
+    inner: C,
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl<C> RecordingClient<C> {
+    /// Wrap a client, recording all of its requests.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Returns a snapshot of the calls recorded so far, in the order they were made.
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.calls.lock().clone()
+    }
+
+    /// Forget all recorded calls.
+    pub fn clear(&self) {
+        self.calls.lock().clear();
+    }
+
+    /// A reference to the wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwrap the client, discarding the recorded calls.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    fn record<E>(
+        &self,
+        method: Method,
+        url: String,
+        body: Vec<u8>,
+        rsp: &Result<Response<Bytes>, E>,
+    ) {
+        let status = rsp.as_ref().ok().map(Response::status);
+        self.calls.lock().push(RecordedCall {
+            method,
+            url,
+            body,
+            status,
+        });
+    }
+}
+
+fn request_parts(request: &RequestBuilder) -> (Method, String) {
+    let method = request.method_ref().cloned().unwrap_or_default();
+    let url = request
+        .uri_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    (method, url)
+}
+
+impl<C> RestClient for RecordingClient<C>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.inner.rest_endpoint(endpoint)
+    }
+
+    fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        self.inner.granted_scopes()
+    }
+}
+
+impl<C> Client for RecordingClient<C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let (method, url) = request_parts(&request);
+        let rsp = self.inner.rest(request, body.clone());
+        self.record(method, url, body, &rsp);
+        rsp
+    }
+}
+
+#[async_trait]
+impl<C> AsyncClient for RecordingClient<C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let (method, url) = request_parts(&request);
+        let rsp = self.inner.rest_async(request, body.clone()).await;
+        self.record(method, url, body, &rsp);
+        rsp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, AsyncQuery as _, Query as _, tracks::GetTrack},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[cfg(feature = "player")]
+    fn seek_twice<C>(client: &C) -> Result<(), ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        use crate::api::player::SeekToPosition;
+
+        api::ignore(SeekToPosition::from(25000)).query(client)?;
+        api::ignore(SeekToPosition::from(25000)).query(client)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_recording_client_get() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tracks/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let client = RecordingClient::new(SingleTestClient::new_raw(endpoint, ""));
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&client)
+            .unwrap();
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method, Method::GET);
+        assert!(
+            calls[0]
+                .url
+                .starts_with("https://api.spotify.com/v1/tracks/11dFghVXANMlKmJXsNCbNl")
+        );
+        assert_eq!(calls[0].status, Some(StatusCode::OK));
+    }
+
+    #[cfg(feature = "player")]
+    #[test]
+    fn test_recording_client() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("me/player/seek")
+            .add_query_params(&[("position_ms", "25000")])
+            .build();
+
+        let client = RecordingClient::new(SingleTestClient::new_raw(endpoint, ""));
+
+        seek_twice(&client).unwrap();
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 2);
+
+        for call in &calls {
+            assert_eq!(call.method, Method::PUT);
+            assert_eq!(
+                call.url,
+                "https://api.spotify.com/v1/me/player/seek?position_ms=25000"
+            );
+            assert_eq!(call.body_str(), "");
+            assert_eq!(call.status, Some(StatusCode::OK));
+        }
+
+        client.clear();
+        assert!(client.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recording_client_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tracks/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let client = RecordingClient::new(SingleTestClient::new_raw(endpoint, ""));
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(client.calls().len(), 1);
+    }
+}
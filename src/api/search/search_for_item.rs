@@ -1,5 +1,5 @@
 use crate::{
-    api::{Endpoint, common::path_escaped, prelude::*},
+    api::{Endpoint, prelude::*},
     model::{IncludeExternalType, SearchType},
 };
 
@@ -22,6 +22,13 @@ pub struct SearchForItem {
     /// Users can view the country that is associated with their account in the [account settings](https://www.spotify.com/account/overview/).
     pub market: Option<Market>,
 
+    /// The maximum number of results to return in each item type. Default: 20. Minimum: 0. Maximum: 50.
+    pub limit: Option<u8>,
+
+    /// The index of the first result to return. Use with `limit` to get the next page of search results.
+    /// Default: 0. Minimum: 0. Maximum: 1000.
+    pub offset: Option<u32>,
+
     /// If `include_external=audio` is specified it signals that the client can play externally hosted audio content, and marks the content as playable in the response.
     /// By default externally hosted audio content is marked as unplayable in the response.
     pub include_external: Option<IncludeExternalType>,
@@ -36,6 +43,8 @@ impl SearchForItem {
             query: query.into(),
             search_types: search_types.into_iter().collect(),
             market: None,
+            limit: None,
+            offset: None,
             include_external: None,
         }
     }
@@ -45,6 +54,16 @@ impl SearchForItem {
         self
     }
 
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     pub fn include_external(mut self, include_external: IncludeExternalType) -> Self {
         self.include_external = Some(include_external);
         self
@@ -62,7 +81,7 @@ impl Endpoint for SearchForItem {
 
     fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
-        params.push("q", &format!("{}", path_escaped(&self.query)));
+        params.push("q", &self.query);
 
         let type_str = self
             .search_types
@@ -73,6 +92,8 @@ impl Endpoint for SearchForItem {
 
         params.push("type", &type_str);
         params.push_opt("market", self.market.as_ref());
+        params.push_opt("limit", self.limit);
+        params.push_opt("offset", self.offset);
         params.push_opt("include_external", self.include_external.as_ref());
 
         params
@@ -84,6 +105,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
+        model::SearchQuery,
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -91,7 +113,7 @@ mod tests {
     fn test_search_for_item_endpoint() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("search")
-            .add_query_params(&[("q", "remaster%20track:Doxy%20artist:Miles%20Davis")])
+            .add_query_params(&[("q", "remaster track:Doxy artist:Miles Davis")])
             .add_query_params(&[("type", "album")])
             .build();
 
@@ -109,7 +131,7 @@ mod tests {
     fn test_search_for_item_endpoint_with_include_external() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("search")
-            .add_query_params(&[("q", "remaster%20track:Doxy%20artist:Miles%20Davis")])
+            .add_query_params(&[("q", "remaster track:Doxy artist:Miles Davis")])
             .add_query_params(&[("type", "album")])
             .add_query_params(&[("include_external", "audio")])
             .build();
@@ -123,4 +145,50 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_search_for_item_endpoint_with_limit_and_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[
+                ("q", "weyes blood"),
+                ("type", "artist,album"),
+                ("market", "US"),
+                ("limit", "10"),
+                ("offset", "5"),
+            ])
+            .build();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SearchForItem::new("weyes blood", [SearchType::Artist, SearchType::Album])
+            .market("US".parse().unwrap())
+            .limit(10)
+            .offset(5);
+
+        let mut url = url::Url::parse("https://api.spotify.com/v1/search").unwrap();
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("q=weyes+blood&type=artist%2Calbum&market=US&limit=10&offset=5")
+        );
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_search_for_item_endpoint_with_search_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[
+                ("q", "album:Blue artist:\"Joni Mitchell\""),
+                ("type", "album"),
+            ])
+            .build();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let query = SearchQuery::default().album("Blue").artist("Joni Mitchell");
+        let endpoint = SearchForItem::new(query, [SearchType::Album]);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
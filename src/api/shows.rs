@@ -1,4 +1,5 @@
 mod check_user_saved_shows;
+mod get_full_shows;
 mod get_several_shows;
 mod get_show;
 mod get_show_episodes;
@@ -7,6 +8,7 @@ mod remove_user_saved_shows;
 mod save_shows_for_current_user;
 
 pub use check_user_saved_shows::*;
+pub use get_full_shows::*;
 pub use get_several_shows::*;
 pub use get_show::*;
 pub use get_show_episodes::*;
@@ -0,0 +1,130 @@
+use crate::{
+    api::{ApiError, AsyncClient, AsyncQuery as _, shows::GetShow},
+    model::Show,
+};
+use futures_util::future;
+
+/// Fetch the full [`Show`] for each of the given IDs concurrently.
+///
+/// [`GetSeveralShows`](super::GetSeveralShows) only returns
+/// [`SimplifiedShow`](crate::model::SimplifiedShow)s, which do not include any episodes.
+/// Use this when the episodes are needed; it issues one [`GetShow`] request per ID and
+/// returns the shows in the same order as the IDs.
+///
+/// # Errors
+/// Returns the first error encountered if any of the requests fail.
+///
+/// # Example
+///
+/// ```no_run
+/// use spotify_web_api::api::shows::get_full_shows;
+///
+/// # async fn example(client: &(impl spotify_web_api::api::AsyncClient + Sync)) {
+/// let shows = get_full_shows(client, ["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"])
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub async fn get_full_shows<C, I, T>(client: &C, ids: I) -> Result<Vec<Show>, ApiError<C::Error>>
+where
+    C: AsyncClient + Sync,
+    I: IntoIterator<Item = T>,
+    T: Into<String>,
+{
+    let endpoints: Vec<GetShow> = ids.into_iter().map(GetShow::from).collect();
+    future::try_join_all(
+        endpoints
+            .iter()
+            .map(|endpoint| endpoint.query_async(client)),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{Query as _, shows::GetSeveralShows},
+        model::Shows,
+        test::client::{ExpectedUrl, MultiTestClient},
+    };
+    use serde_json::{Value, json};
+
+    fn show(id: &str) -> Value {
+        json!({
+            "copyrights": [],
+            "description": "string",
+            "html_description": "string",
+            "explicit": false,
+            "external_urls": { "spotify": "string" },
+            "href": format!("https://api.spotify.com/v1/shows/{id}"),
+            "id": id,
+            "images": [],
+            "is_externally_hosted": false,
+            "languages": ["en"],
+            "media_type": "audio",
+            "name": "string",
+            "publisher": "string",
+            "type": "show",
+            "uri": format!("spotify:show:{id}"),
+            "total_episodes": 0,
+        })
+    }
+
+    fn full_show(id: &str) -> Value {
+        let mut show = show(id);
+        show["episodes"] = json!({
+            "href": format!("https://api.spotify.com/v1/shows/{id}/episodes"),
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 0,
+            "items": [],
+        });
+        show
+    }
+
+    #[tokio::test]
+    async fn test_batch_shows_are_simplified() {
+        let ids = ["5CfCWKI5pZ28U0uOzXkDHe", "5as3aKmN2k11yfDDDSrvaZ"];
+
+        let client = MultiTestClient::new_json([
+            (
+                ExpectedUrl::builder()
+                    .endpoint("shows")
+                    .add_query_params(&[("ids", "5CfCWKI5pZ28U0uOzXkDHe,5as3aKmN2k11yfDDDSrvaZ")])
+                    .build(),
+                json!({ "shows": [show(ids[0]), show(ids[1])] }),
+            ),
+            (
+                ExpectedUrl::builder()
+                    .endpoint("shows/5CfCWKI5pZ28U0uOzXkDHe")
+                    .build(),
+                full_show(ids[0]),
+            ),
+            (
+                ExpectedUrl::builder()
+                    .endpoint("shows/5as3aKmN2k11yfDDDSrvaZ")
+                    .build(),
+                full_show(ids[1]),
+            ),
+        ]);
+
+        // The batch endpoint only returns simplified shows, without episodes.
+        let batch: Shows = GetSeveralShows::from(ids).query(&client).unwrap();
+        assert_eq!(batch.shows.len(), 2);
+
+        // Fetching each show individually returns the full show, including episodes.
+        let full = get_full_shows(&client, ids).await.unwrap();
+        assert_eq!(full.len(), 2);
+        assert_eq!(full[0].id, ids[0]);
+        assert_eq!(full[1].id, ids[1]);
+        #[cfg(feature = "page_items")]
+        assert!(full.iter().all(|show| show.episodes.items.is_empty()));
+
+        for (simplified, show) in batch.shows.iter().zip(full) {
+            assert_eq!(simplified, &crate::model::SimplifiedShow::from(show));
+        }
+    }
+}
@@ -1,6 +1,11 @@
-use crate::api::prelude::*;
+use crate::{api::prelude::*, model::ShowId};
 
 /// Get Spotify catalog information for several shows based on their Spotify IDs.
+///
+/// The response is a [`Shows`](crate::model::Shows) containing
+/// [`SimplifiedShow`](crate::model::SimplifiedShow)s, which do not include any episodes.
+/// Use [`GetShow`](super::GetShow) or [`get_full_shows`](super::get_full_shows) when the
+/// episodes are needed.
 #[derive(Debug, Clone)]
 pub struct GetSeveralShows {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the shows.
@@ -29,6 +34,13 @@ where
     }
 }
 
+impl GetSeveralShows {
+    /// Creates the endpoint from validated show IDs.
+    pub fn from_ids(ids: impl IntoIterator<Item = ShowId>) -> Self {
+        Self::from(ids.into_iter().map(|id| id.id().to_owned()))
+    }
+}
+
 impl Endpoint for GetSeveralShows {
     fn method(&self) -> Method {
         Method::GET
@@ -67,4 +79,21 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_several_shows_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("shows")
+            .add_query_params(&[("ids", "5CfCWKI5pZ28U0uOzXkDHe"), ("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetSeveralShows {
+            market: Some("ES".parse().unwrap()),
+            ..GetSeveralShows::from(["5CfCWKI5pZ28U0uOzXkDHe"])
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -46,6 +46,7 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
+        model::{Show, shows::tests::SHOW},
         test::client::{ExpectedUrl, SingleTestClient},
     };
 
@@ -61,4 +62,24 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_show_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("shows/38bS44xjbVVZ3No3ByF1dJ")
+            .add_query_params(&[("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, SHOW);
+
+        let endpoint = GetShow {
+            market: Some("ES".parse().unwrap()),
+            ..GetShow::from("38bS44xjbVVZ3No3ByF1dJ")
+        };
+
+        let show: Show = endpoint.query(&client).unwrap();
+        assert_eq!(show.total_episodes, 0);
+        #[cfg(feature = "page_items")]
+        assert_eq!(show.episodes.items.len(), 1);
+    }
 }
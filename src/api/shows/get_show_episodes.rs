@@ -48,8 +48,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_show_episodes_endpoint() {
@@ -63,4 +64,28 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_show_episodes_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("shows/38bS44xjbVVZ3No3ByF1dJ/episodes")
+            .add_query_params(&[("market", "ES")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..30).map(|i| json!({ "id": i })));
+
+        let endpoint = GetShowEpisodes {
+            market: Some("ES".parse().unwrap()),
+            ..GetShowEpisodes::from("38bS44xjbVVZ3No3ByF1dJ")
+        };
+
+        let episodes: Vec<Value> = api::paged_with_limit_and_offset(endpoint, 10, 20)
+            .query(&client)
+            .unwrap();
+        assert_eq!(episodes.len(), 10);
+        assert_eq!(episodes[0], json!({ "id": 20 }));
+
+        assert_eq!(client.page_params(), [(20, 10)]);
+    }
 }
@@ -1,4 +1,10 @@
-use crate::api::prelude::*;
+use crate::{
+    api::{ApiError, AsyncClient, Client, Query as _, batch::query_chunks_async, prelude::*},
+    model::{Track, TrackId, Tracks},
+};
+
+/// The maximum number of IDs accepted by a single request.
+const MAX_IDS: usize = 50;
 
 /// Get Spotify catalog information for several tracks based on their Spotify IDs.
 #[derive(Debug, Clone)]
@@ -46,13 +52,79 @@ impl Endpoint for GetSeveralTracks {
     }
 }
 
+impl GetSeveralTracks {
+    /// Creates the endpoint from validated track IDs.
+    pub fn from_ids(ids: impl IntoIterator<Item = TrackId>) -> Self {
+        Self::from(ids.into_iter().map(|id| id.id().to_owned()))
+    }
+
+    /// Fetch any number of tracks, sending the IDs in chunks of 50.
+    ///
+    /// A single request accepts at most 50 IDs. This issues one request per chunk and returns
+    /// the tracks in the same order as `ids`, with `None` for the IDs which were not found.
+    ///
+    /// # Errors
+    /// Returns the error of the first chunk which failed.
+    pub fn query_chunked<C>(&self, client: &C) -> Result<Vec<Option<Track>>, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        let mut tracks = Vec::with_capacity(self.ids.len());
+        for endpoint in self.chunks() {
+            let chunk: Tracks = endpoint.query(client)?;
+            tracks.extend(chunk.tracks);
+        }
+        Ok(tracks)
+    }
+
+    /// Asynchronously fetch any number of tracks, sending the IDs in chunks of 50 with at most
+    /// `max_concurrent` requests in flight.
+    ///
+    /// See [`GetSeveralTracks::query_chunked`] for details. A `max_concurrent` of zero is
+    /// treated as one.
+    ///
+    /// # Errors
+    /// Returns the error of the first chunk which failed.
+    pub async fn query_chunked_async<C>(
+        &self,
+        client: &C,
+        max_concurrent: usize,
+    ) -> Result<Vec<Option<Track>>, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        let chunks: Vec<Tracks> =
+            query_chunks_async(client, &self.ids, MAX_IDS, max_concurrent, |ids| Self {
+                ids: ids.to_vec(),
+                market: self.market.clone(),
+            })
+            .await?;
+
+        Ok(chunks.into_iter().flat_map(|chunk| chunk.tracks).collect())
+    }
+
+    fn chunks(&self) -> Vec<Self> {
+        self.ids
+            .chunks(MAX_IDS)
+            .map(|ids| Self {
+                ids: ids.to_vec(),
+                market: self.market.clone(),
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        api,
+        test::{
+            client::{ExpectedUrl, MultiTestClient, SingleTestClient},
+            fixtures,
+        },
     };
+    use serde_json::json;
 
     #[test]
     fn test_get_several_tracks_endpoint() {
@@ -67,4 +139,65 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    /// 120 IDs, where every tenth ID is not found.
+    fn chunked_client() -> (Vec<String>, MultiTestClient) {
+        let ids = (0..120).map(|i| format!("id{i:03}")).collect::<Vec<_>>();
+
+        let responses = ids.chunks(50).map(|chunk| {
+            let mut endpoint = ExpectedUrl::builder()
+                .endpoint("tracks")
+                .add_query_params(&[("market", "ES")])
+                .build();
+            endpoint
+                .query
+                .insert(0, ("ids".into(), chunk.join(",").into()));
+            let tracks = chunk
+                .iter()
+                .map(|id| (!id.ends_with('0')).then(|| fixtures::track(id)))
+                .collect::<Vec<_>>();
+            (endpoint, json!({ "tracks": tracks }))
+        });
+
+        let client = MultiTestClient::new_json(responses);
+        (ids, client)
+    }
+
+    fn assert_chunked(ids: &[String], tracks: &[Option<Track>]) {
+        assert_eq!(tracks.len(), ids.len());
+        for (id, track) in ids.iter().zip(tracks) {
+            match track {
+                Some(track) => assert_eq!(&track.id, id),
+                None => assert!(id.ends_with('0')),
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_several_tracks_chunked() {
+        let (ids, client) = chunked_client();
+
+        let endpoint = GetSeveralTracks {
+            market: Some("ES".parse().unwrap()),
+            ..GetSeveralTracks::from(&ids)
+        };
+        let tracks = endpoint.query_chunked(&client).unwrap();
+
+        assert_chunked(&ids, &tracks);
+        assert_eq!(client.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_several_tracks_chunked_async() {
+        let (ids, client) = chunked_client();
+
+        let endpoint = GetSeveralTracks {
+            market: Some("ES".parse().unwrap()),
+            ..GetSeveralTracks::from(&ids)
+        };
+        let tracks = endpoint.query_chunked_async(&client, 2).await.unwrap();
+
+        assert_chunked(&ids, &tracks);
+        assert_eq!(client.requests().len(), 3);
+    }
 }
@@ -0,0 +1,70 @@
+use crate::{api::prelude::*, model::TrackId};
+
+/// Get audio features for multiple tracks based on their Spotify IDs.
+#[derive(Debug, Clone)]
+pub struct GetSeveralTracksAudioFeatures {
+    /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
+    /// Maximum: 100 IDs.
+    pub ids: Vec<String>,
+}
+
+impl<T, I> From<I> for GetSeveralTracksAudioFeatures
+where
+    I: IntoIterator<Item = T>,
+    T: Into<String>,
+{
+    fn from(ids: I) -> Self {
+        Self {
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl GetSeveralTracksAudioFeatures {
+    /// Creates the endpoint from validated track IDs.
+    pub fn from_ids(ids: impl IntoIterator<Item = TrackId>) -> Self {
+        Self::from(ids.into_iter().map(|id| id.id().to_owned()))
+    }
+}
+
+impl Endpoint for GetSeveralTracksAudioFeatures {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "audio-features".into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("ids", &self.ids.join(","));
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_several_tracks_audio_features_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-features")
+            .add_query_params(&[("ids", "7ouMYWpwJ422jRcDASZB7P,4VqPOruhp5EdPBeR92t6lQ")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetSeveralTracksAudioFeatures::from([
+            "7ouMYWpwJ422jRcDASZB7P",
+            "4VqPOruhp5EdPBeR92t6lQ",
+        ]);
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -1,4 +1,4 @@
-use crate::api::prelude::*;
+use crate::{api::prelude::*, model::TrackId};
 
 /// Get Spotify catalog information for a single track identified by its unique Spotify ID.
 #[derive(Debug, Clone)]
@@ -25,6 +25,12 @@ impl<T: Into<String>> From<T> for GetTrack {
     }
 }
 
+impl From<TrackId> for GetTrack {
+    fn from(id: TrackId) -> Self {
+        Self::from(id.id())
+    }
+}
+
 impl Endpoint for GetTrack {
     fn method(&self) -> Method {
         Method::GET
@@ -61,4 +67,22 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_track_endpoint_with_market() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tracks/5IoBP6aTHHBStOrbLFc5uQ")
+            .add_query_params(&[("market", "ES")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let id = TrackId::from_id("5IoBP6aTHHBStOrbLFc5uQ").unwrap();
+        let endpoint = GetTrack {
+            market: Some("ES".parse().unwrap()),
+            ..GetTrack::from(id)
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
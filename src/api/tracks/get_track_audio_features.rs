@@ -0,0 +1,46 @@
+use crate::api::prelude::*;
+
+/// Get audio feature information for a single track identified by its unique Spotify ID.
+#[derive(Debug, Clone)]
+pub struct GetTrackAudioFeatures {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the track.
+    pub id: String,
+}
+
+impl<T: Into<String>> From<T> for GetTrackAudioFeatures {
+    fn from(id: T) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+impl Endpoint for GetTrackAudioFeatures {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("audio-features/{}", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{self, Query as _},
+        test::client::{ExpectedUrl, SingleTestClient},
+    };
+
+    #[test]
+    fn test_get_track_audio_features_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audio-features/11dFghVXANMlKmJXsNCbNl")
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GetTrackAudioFeatures::from("11dFghVXANMlKmJXsNCbNl");
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
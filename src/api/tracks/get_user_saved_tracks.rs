@@ -17,10 +17,13 @@ pub struct GetUserSavedTracks {
 
 impl Pageable for GetUserSavedTracks {}
 
-impl From<Market> for GetUserSavedTracks {
-    fn from(market: Market) -> Self {
+impl<T> From<T> for GetUserSavedTracks
+where
+    T: Into<Market>,
+{
+    fn from(market: T) -> Self {
         Self {
-            market: Some(market),
+            market: Some(market.into()),
         }
     }
 }
@@ -39,6 +42,10 @@ impl Endpoint for GetUserSavedTracks {
         params.push_opt("market", self.market.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserLibraryRead]
+    }
 }
 
 #[cfg(test)]
@@ -46,8 +53,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_user_saved_track_endpoint() {
@@ -56,4 +64,39 @@ mod tests {
         let endpoint = GetUserSavedTracks::default();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_user_saved_tracks_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/tracks")
+            .add_query_params(&[("market", "ES")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..60).map(|i| json!({ "id": i })));
+
+        let tracks: Vec<Value> = api::paged_all(GetUserSavedTracks::from("ES"))
+            .query(&client)
+            .unwrap();
+        assert_eq!(tracks.len(), 60);
+        assert_eq!(client.page_params(), [(0, 50), (50, 50)]);
+    }
+
+    #[test]
+    fn test_get_user_saved_tracks_endpoint_with_limit_and_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/tracks")
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..60).map(|i| json!({ "id": i })));
+
+        let tracks: Vec<Value> =
+            api::paged_with_limit_and_offset(GetUserSavedTracks::default(), 20, 40)
+                .query(&client)
+                .unwrap();
+        assert_eq!(tracks.len(), 20);
+        assert_eq!(tracks[0], json!({ "id": 40 }));
+        assert_eq!(client.page_params(), [(40, 20)]);
+    }
 }
@@ -7,6 +7,17 @@ use crate::api::prelude::*;
 pub struct RemoveUserSavedTracks {
     /// A list of [Spotify IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the tracks.
     pub ids: Vec<String>,
+
+    /// Whether the IDs are sent as a query parameter or in a JSON body.
+    pub ids_placement: IdsPlacement,
+}
+
+impl RemoveUserSavedTracks {
+    /// Send the IDs in a JSON body rather than as a query parameter.
+    pub fn in_body(mut self) -> Self {
+        self.ids_placement = IdsPlacement::Body;
+        self
+    }
 }
 
 impl<T, I> From<I> for RemoveUserSavedTracks
@@ -17,6 +28,7 @@ where
     fn from(ids: I) -> Self {
         Self {
             ids: ids.into_iter().map(Into::into).collect(),
+            ids_placement: IdsPlacement::default(),
         }
     }
 }
@@ -31,9 +43,11 @@ impl Endpoint for RemoveUserSavedTracks {
     }
 
     fn parameters(&self) -> QueryParams<'_> {
-        let mut params = QueryParams::default();
-        params.push("ids", &self.ids.join(","));
-        params
+        self.ids_placement.parameters(&self.ids)
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.ids_placement.body(&self.ids)
     }
 }
 
@@ -61,4 +75,22 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_remove_user_saved_tracks_endpoint_in_body() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .content_type("application/json")
+            .endpoint("me/tracks")
+            .body_str(r#"{"ids":["39joRyXYyjSpI6nKZHyWmH","5mPY98zmeNSp8cmrRtdUW3"]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint =
+            RemoveUserSavedTracks::from(["39joRyXYyjSpI6nKZHyWmH", "5mPY98zmeNSp8cmrRtdUW3"])
+                .in_body();
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
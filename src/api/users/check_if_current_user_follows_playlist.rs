@@ -5,17 +5,35 @@ use crate::api::prelude::*;
 pub struct CheckIfCurrentUserFollowsPlaylist {
     /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the playlist.
     pub id: String,
+
+    /// The [Spotify user IDs](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids)
+    /// to check, in the order of the returned booleans.
+    ///
+    /// Spotify only accepts the current user's ID here, so this is usually left empty.
+    pub ids: Vec<String>,
 }
 
 impl CheckIfCurrentUserFollowsPlaylist {
     pub fn new(id: impl Into<String>) -> Self {
         Self::from(id)
     }
+
+    pub fn user_ids<T, I>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.ids = ids.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 impl<T: Into<String>> From<T> for CheckIfCurrentUserFollowsPlaylist {
     fn from(id: T) -> Self {
-        Self { id: id.into() }
+        Self {
+            id: id.into(),
+            ids: Vec::new(),
+        }
     }
 }
 
@@ -27,6 +45,14 @@ impl Endpoint for CheckIfCurrentUserFollowsPlaylist {
     fn endpoint(&self) -> Cow<'static, str> {
         format!("playlists/{}/followers/contains", self.id).into()
     }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        if !self.ids.is_empty() {
+            params.push("ids", &self.ids.join(","));
+        }
+        params
+    }
 }
 
 #[cfg(test)]
@@ -53,4 +79,21 @@ mod tests {
 
         assert_eq!(result, expected_response);
     }
+
+    #[test]
+    fn test_check_if_current_user_follows_playlist_endpoint_with_ids() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("playlists/3cEYpjA9oz9GiPac4AsH4n/followers/contains")
+            .add_query_params(&[("ids", "jmperezperez")])
+            .build();
+
+        let client = SingleTestClient::new_json(endpoint, &[true]);
+
+        let endpoint = CheckIfCurrentUserFollowsPlaylist::new("3cEYpjA9oz9GiPac4AsH4n")
+            .user_ids(["jmperezperez"]);
+
+        let result: Vec<bool> = endpoint.query(&client).unwrap();
+
+        assert_eq!(result, [true]);
+    }
 }
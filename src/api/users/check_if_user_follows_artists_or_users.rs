@@ -64,4 +64,23 @@ mod tests {
 
         assert_eq!(result, expected_response);
     }
+
+    #[test]
+    fn test_check_if_user_follows_users_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/following/contains")
+            .add_query_params(&[("type", "user"), ("ids", "jmperezperez,thelinmichael")])
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "[true,false]");
+
+        let endpoint = CheckIfUserFollowsArtistsOrUsers {
+            type_: FollowType::User,
+            ids: vec!["jmperezperez".to_owned(), "thelinmichael".to_owned()],
+        };
+
+        let result: Vec<bool> = endpoint.query(&client).unwrap();
+
+        assert_eq!(result, [true, false]);
+    }
 }
@@ -64,4 +64,24 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_follow_users_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .content_type("application/json")
+            .endpoint("me/following")
+            .add_query_params(&[("type", "user")])
+            .body_str(r#"{"ids":["jmperezperez"]}"#)
+            .build();
+
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FollowArtistsOrUsers {
+            type_: FollowType::User,
+            ids: vec!["jmperezperez".to_owned()],
+        };
+
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -12,6 +12,10 @@ impl Endpoint for GetCurrentUserProfile {
     fn endpoint(&self) -> Cow<'static, str> {
         "me".into()
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserReadPrivate]
+    }
 }
 
 #[cfg(test)]
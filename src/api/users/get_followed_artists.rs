@@ -1,4 +1,7 @@
-use crate::{api::prelude::*, model::FollowedArtistsType};
+use crate::{
+    api::prelude::*,
+    model::{FollowedArtists, FollowedArtistsType},
+};
 
 /// Get the current user's followed artists.
 #[derive(Debug, Clone)]
@@ -49,6 +52,14 @@ impl Endpoint for GetFollowedArtists {
         params.push_opt("after", self.after.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserFollowRead]
+    }
+}
+
+impl CursorPageable for GetFollowedArtists {
+    type Page = FollowedArtists;
 }
 
 #[cfg(test)]
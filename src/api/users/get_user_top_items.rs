@@ -46,6 +46,10 @@ impl Endpoint for GetUserTopItems {
         params.push_opt("time_range", self.time_range.as_ref());
         params
     }
+
+    fn required_scopes(&self) -> &'static [Scope] {
+        &[Scope::UserTopRead]
+    }
 }
 
 #[cfg(test)]
@@ -53,8 +57,9 @@ mod tests {
     use super::*;
     use crate::{
         api::{self, Query as _},
-        test::client::{ExpectedUrl, SingleTestClient},
+        test::client::{ExpectedUrl, PagedTestClient, SingleTestClient},
     };
+    use serde_json::{Value, json};
 
     #[test]
     fn test_get_user_top_items_endpoint() {
@@ -72,4 +77,30 @@ mod tests {
 
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn test_get_user_top_items_endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("me/top/artists")
+            .add_query_params(&[("time_range", "long_term")])
+            .paginated(true)
+            .build();
+
+        let client = PagedTestClient::new_raw(endpoint, (0..30).map(|i| json!({ "id": i })));
+
+        let endpoint = GetUserTopItems {
+            time_range: Some(TimeRange::LongTerm),
+            ..GetUserTopItems::from(TopItemType::Artists)
+        };
+
+        let artists: Vec<Value> = api::paged_with_limit_and_offset(endpoint, 10, 5)
+            .query(&client)
+            .unwrap();
+        assert_eq!(artists.len(), 10);
+        assert_eq!(artists[0], json!({ "id": 5 }));
+        assert_eq!(
+            client.requests()[0].query(),
+            Some("time_range=long_term&offset=5&limit=10")
+        );
+    }
 }
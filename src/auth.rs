@@ -4,13 +4,18 @@
 //!
 //! - **Authorization Code with PKCE**: For applications that need to access user data.
 //!   Use [`crate::SpotifyPKCE`] or [`crate::AsyncSpotifyPKCE`].
+//! - **Authorization Code**: For server-side applications that need to access user data and can
+//!   safely store the client secret. Use [`crate::SpotifyAuthCode`] or [`crate::AsyncSpotifyAuthCode`].
 //! - **Client Credentials**: For server-to-server authentication without user context.
 //!   Use [`crate::SpotifyClientCredentials`] or [`crate::AsyncSpotifyClientCredentials`].
 //!
 //! See the [Spotify Authorization Guide](https://developer.spotify.com/documentation/web-api/concepts/authorization)
 //! for more information on choosing the right authorization flow.
 
+mod auth_code;
 mod client_credentials;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod local_server;
 mod pkce;
 pub mod scopes;
 
@@ -19,14 +24,19 @@ use crate::{
     api::{ApiError, FormParams, query},
     model::Token,
 };
+pub(crate) use auth_code::AuthCode;
 use bytes::Bytes;
 pub(crate) use client_credentials::ClientCredentials;
 use http::{HeaderMap, HeaderValue, Request, Response as HttpResponse, header, request::Builder};
 pub(crate) use pkce::AuthCodePKCE;
+#[cfg(not(target_arch = "wasm32"))]
 use reqwest::blocking::Client;
 use thiserror::Error;
 use url::Url;
 
+/// The base URL of the Spotify Accounts service, used for authorization and token requests.
+pub(crate) const ACCOUNTS_URL: &str = "https://accounts.spotify.com/";
+
 /// A specialized `Result` type for authorization operations.
 pub type AuthResult<T> = Result<T, AuthError>;
 
@@ -82,7 +92,7 @@ pub enum AuthError {
     /// Ensure that the `user_authorization_url()` method is called to generate
     /// a proper authorization URL that includes the state parameter.
     #[error(
-        "AuthCodePKCE's state is None. Make sure to generate a user authorization URL by calling user_authorization_url()"
+        "the authorization state is None. Make sure to generate a user authorization URL by calling user_authorization_url()"
     )]
     NoState,
 
@@ -105,15 +115,53 @@ pub enum AuthError {
     /// token refreshing cannot proceed.
     #[error("refresh token is empty")]
     EmptyRefreshToken,
+
+    /// Indicates that the access token has expired and there is no refresh token to renew it.
+    ///
+    /// The user has to authorize the application again before further requests can be made.
+    #[error("access token has expired and cannot be refreshed")]
+    ExpiredAccessToken,
+
+    /// Indicates that the callback URL does not match the configured redirect URI.
+    ///
+    /// Spotify rejects the token exchange with `invalid_grant` when the redirect URI
+    /// differs from the one used in the authorization request, so this is checked
+    /// before any request is made.
+    ///
+    /// # Fields
+    /// - `expected`: The configured redirect URI.
+    /// - `got`: The origin and path of the callback URL that was received.
+    #[error("redirect uri mismatch: expected {expected} got {got}")]
+    RedirectUriMismatch { expected: String, got: String },
+
+    /// No authorization callback was received by the local server in time.
+    #[error("timed out waiting for the authorization callback")]
+    Timeout,
+
+    /// An I/O error occurred in the local server waiting for the authorization callback.
+    #[error("local server error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 pub(crate) mod private {
-    use super::AuthError;
+    use super::{AuthError, AuthResult};
     use crate::{RestError, api::ApiError, model::Token};
     use async_trait::async_trait;
+    #[cfg(not(target_arch = "wasm32"))]
     use reqwest::blocking::Client;
+    use url::Url;
+
+    pub trait Accounts {
+        fn accounts_url(&self) -> &Url;
+
+        fn set_accounts_url(&mut self, url: Url);
 
-    pub trait AuthFlow {
+        /// Adjusts a token set by the user before it is stored in the client.
+        fn accept_token(&mut self, token: &mut Token);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub trait AuthFlow: Accounts {
         fn refresh_token(
             &self,
             client: &Client,
@@ -123,10 +171,18 @@ pub(crate) mod private {
             let _ = refresh_token;
             Err(AuthError::EmptyRefreshToken.into())
         }
+
+        /// Requests a new access token without a refresh token, if the flow allows it.
+        ///
+        /// Returns `None` for flows which need the user to authorize the application again.
+        fn reacquire_token(&self, client: &Client) -> Option<Result<Token, ApiError<RestError>>> {
+            let _ = client;
+            None
+        }
     }
 
     #[async_trait]
-    pub trait AsyncAuthFlow {
+    pub trait AsyncAuthFlow: Accounts {
         async fn refresh_token_async(
             &self,
             client: &reqwest::Client,
@@ -136,31 +192,131 @@ pub(crate) mod private {
             let _ = refresh_token;
             Err(AuthError::EmptyRefreshToken.into())
         }
+
+        /// Requests a new access token without a refresh token, if the flow allows it.
+        ///
+        /// Returns `None` for flows which need the user to authorize the application again.
+        async fn reacquire_token_async(
+            &self,
+            client: &reqwest::Client,
+        ) -> Option<Result<Token, ApiError<RestError>>> {
+            let _ = client;
+            None
+        }
+    }
+
+    /// A flow in which the user authorizes the application in the browser and is redirected
+    /// back with an authorization code.
+    pub trait UserAuthorization: Accounts {
+        #[cfg(not(target_arch = "wasm32"))]
+        fn redirect_uri(&self) -> &str;
+
+        fn user_authorization_url(&mut self) -> String;
+
+        fn verify_authorization_code(&self, url: &str) -> AuthResult<String>;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub trait UserAuthFlow: AuthFlow + UserAuthorization {
+        fn request_token(&self, code: &str, client: &Client) -> Result<Token, ApiError<RestError>>;
+
+        fn request_token_from_redirect_url(
+            &self,
+            url: &str,
+            client: &Client,
+        ) -> Result<Token, ApiError<RestError>>;
+    }
+
+    #[async_trait]
+    pub trait AsyncUserAuthFlow: AsyncAuthFlow + UserAuthorization {
+        async fn request_token_async(
+            &self,
+            code: &str,
+            client: &reqwest::Client,
+        ) -> Result<Token, ApiError<RestError>>;
+
+        async fn request_token_from_redirect_url_async(
+            &self,
+            url: &str,
+            client: &reqwest::Client,
+        ) -> Result<Token, ApiError<RestError>>;
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn request_token(
     client: &Client,
+    accounts_url: &Url,
     authorization_header: Option<String>,
     params: FormParams<'_>,
 ) -> Result<Token, ApiError<RestError>> {
-    let (request, data) = init_http_request_and_data(authorization_header, params)?;
+    let (request, data) = init_http_request_and_data(accounts_url, authorization_header, params)?;
     let response = send_http_request(client, request, data).map_err(ApiError::client)?;
     parse_http_response(&response)
 }
 
 async fn request_token_async(
     client: &reqwest::Client,
+    accounts_url: &Url,
     authorization_header: Option<String>,
     params: FormParams<'_>,
 ) -> Result<Token, ApiError<RestError>> {
-    let (request, data) = init_http_request_and_data(authorization_header, params)?;
+    let (request, data) = init_http_request_and_data(accounts_url, authorization_header, params)?;
     let response = send_http_request_async(client, request, data)
         .await
         .map_err(ApiError::client)?;
     parse_http_response(&response)
 }
 
+/// Extracts the authorization code from a callback URL, checking its `state` parameter
+/// against the one sent in the authorization request.
+fn verify_authorization_code(expected_state: Option<&str>, url: &str) -> AuthResult<String> {
+    let expected_state = expected_state.ok_or(AuthError::NoState)?;
+
+    let url = Url::parse(url)?;
+
+    let mut code = None;
+    let mut state = None;
+
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "code" => code = Some(value),
+            "state" => state = Some(value),
+            _ => {}
+        }
+    }
+
+    let code = code.ok_or(AuthError::CodeNotFound)?;
+    let state = state.ok_or(AuthError::InvalidState {
+        expected: expected_state.to_owned(),
+        got: "None".to_owned(),
+    })?;
+
+    if expected_state == state {
+        Ok(code.to_string())
+    } else {
+        Err(AuthError::InvalidState {
+            expected: expected_state.to_owned(),
+            got: state.to_string(),
+        })
+    }
+}
+
+/// Checks that the callback URL was sent to the redirect URI, comparing the origin and path.
+fn verify_redirect_uri(redirect_uri: &str, url: &str) -> AuthResult<()> {
+    let expected = Url::parse(redirect_uri)?;
+    let url = Url::parse(url)?;
+
+    if url.origin() == expected.origin() && url.path() == expected.path() {
+        Ok(())
+    } else {
+        Err(AuthError::RedirectUriMismatch {
+            expected: redirect_uri.to_owned(),
+            got: format!("{}{}", url.origin().ascii_serialization(), url.path()),
+        })
+    }
+}
+
 fn set_authorization_header<'a>(
     headers: &'a mut HeaderMap<HeaderValue>,
     value: &str,
@@ -172,10 +328,11 @@ fn set_authorization_header<'a>(
 }
 
 fn init_http_request_and_data(
+    accounts_url: &Url,
     authorization_header: Option<String>,
     params: FormParams<'_>,
 ) -> Result<(Builder, Vec<u8>), ApiError<RestError>> {
-    let url = Url::parse("https://accounts.spotify.com/api/token")?;
+    let url = accounts_url.join("api/token")?;
 
     let mut req = Request::builder()
         .method(http::Method::POST)
@@ -204,7 +361,8 @@ fn init_http_request_and_data(
     Ok((req, data))
 }
 
-fn send_http_request(
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn send_http_request(
     client: &Client,
     request: Builder,
     data: Vec<u8>,
@@ -228,18 +386,49 @@ fn send_http_request(
     Ok(http_response.body(response.bytes()?)?)
 }
 
-async fn send_http_request_async(
+pub(crate) async fn send_http_request_async(
     client: &reqwest::Client,
     request: Builder,
     data: Vec<u8>,
 ) -> Result<http::Response<Bytes>, RestError> {
     let http_request = request.body(data)?;
-    let request = http_request.try_into()?;
+    let request: reqwest::Request = http_request.try_into()?;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        execute_async(client, request).await
+    }
+
+    // Browser requests are not `Send`, so they are driven by the local executor and only
+    // the converted response is handed back.
+    #[cfg(target_arch = "wasm32")]
+    {
+        use futures_util::FutureExt as _;
+
+        let mut request = request;
+        *request.timeout_mut() = Some(std::time::Duration::from_secs(10));
+
+        let client = client.clone();
+        let (execute, response) =
+            async move { execute_async(&client, request).await }.remote_handle();
+        wasm_bindgen_futures::spawn_local(execute);
+        response.await
+    }
+}
+
+async fn execute_async(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+) -> Result<http::Response<Bytes>, RestError> {
     let response = client.execute(request).await?;
 
-    let mut http_response = HttpResponse::builder()
-        .status(response.status())
-        .version(response.version());
+    let mut http_response = HttpResponse::builder().status(response.status());
+
+    // The HTTP version is not exposed to browser requests.
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        http_response = http_response.version(response.version());
+    }
 
     let headers = http_response
         .headers_mut()
@@ -258,16 +447,18 @@ where
 {
     let status = response.status();
 
-    let v = serde_json::from_slice(response.body())
-        .map_err(|_e| ApiError::server_error(status, response.body()))?;
-
-    if !status.is_success() {
-        return Err(ApiError::from_spotify_with_status(status, v));
-    } else if status == http::StatusCode::MOVED_PERMANENTLY {
+    if status == http::StatusCode::MOVED_PERMANENTLY {
         return Err(ApiError::moved_permanently(
             response.headers().get(header::LOCATION),
         ));
     }
 
+    let body = query::decode_body(response)?;
+    let v = serde_json::from_slice(&body).map_err(|_e| ApiError::server_error(status, &body))?;
+
+    if !status.is_success() {
+        return Err(ApiError::from_spotify_with_status(status, v));
+    }
+
     serde_json::from_value::<_>(v).map_err(ApiError::data_type::<T>)
 }
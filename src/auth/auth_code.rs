@@ -0,0 +1,341 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::private::{AuthFlow, UserAuthFlow};
+use super::{
+    AuthResult,
+    pkce::crypto,
+    private::{Accounts, AsyncAuthFlow, AsyncUserAuthFlow, UserAuthorization},
+};
+use crate::{
+    RestError,
+    api::{ApiError, FormParams, QueryParams},
+    auth::scopes::{self, Scope},
+    model::Token,
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::blocking::Client;
+use std::collections::HashSet;
+use url::Url;
+
+/// Represents the Authorization Code Flow.
+///
+/// This flow is used to authenticate users and obtain access tokens for the Spotify Web API.
+/// It is suited for server-side applications where the client secret can be securely stored,
+/// as the secret is sent with every token request.
+///
+/// For more details, see the [Spotify Authorization Guide](https://developer.spotify.com/documentation/web-api/tutorials/code-flow).
+#[derive(Clone)]
+pub struct AuthCode {
+    /// The Client ID generated after registering your application.
+    ///
+    /// This is required to identify your application to the Spotify API.
+    client_id: String,
+
+    /// The Client Secret generated after registering your application.
+    ///
+    /// This is required to authenticate your application to the Spotify API.
+    client_secret: String,
+
+    /// The URI to redirect to after the user grants or denies permission.
+    ///
+    /// This URI must match one of the Redirect URIs you specified when registering your application.
+    redirect_uri: String,
+
+    /// A space-separated list of requested [scopes](https://developer.spotify.com/documentation/web-api/concepts/scopes).
+    ///
+    /// If no scopes are specified, access will be granted only to publicly available information.
+    scopes: Option<HashSet<Scope>>,
+
+    /// A random string to be used as the `state` parameter, generated when a user authorization
+    /// URL is requested.
+    state: Option<String>,
+
+    /// The base URL of the Spotify Accounts service.
+    accounts_url: Url,
+}
+
+impl AuthCode {
+    // This sets the `state` field to `None`, as it will be generated during the authorization
+    // process.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            redirect_uri: redirect_uri.into(),
+            scopes: scopes.into(),
+            state: None,
+            accounts_url: Url::parse(super::ACCOUNTS_URL).expect("This URL is always valid"),
+        }
+    }
+
+    pub fn set_scopes(&mut self, scopes: Option<HashSet<Scope>>) {
+        self.scopes = scopes;
+    }
+
+    pub fn user_authorization_url(&mut self) -> String {
+        let state = crypto::random_string(16);
+
+        let mut params = QueryParams::default();
+        params
+            .push("client_id", &self.client_id)
+            .push("response_type", &"code")
+            .push("redirect_uri", &self.redirect_uri)
+            .push("state", &state)
+            .push_opt("scope", self.scopes.as_ref().map(scopes::to_string));
+
+        let mut url = self
+            .accounts_url
+            .join("authorize")
+            .expect("The accounts URL is always a valid base");
+
+        params.add_to_url(&mut url);
+
+        self.state = Some(state);
+
+        url.as_str().to_owned()
+    }
+
+    pub fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
+        super::verify_authorization_code(self.state.as_deref(), url)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn request_token(&self, code: &str, client: &Client) -> Result<Token, ApiError<RestError>> {
+        let params = self.token_request_params(code);
+        super::request_token(
+            client,
+            &self.accounts_url,
+            Some(self.authorization_header()),
+            params,
+        )
+    }
+
+    pub async fn request_token_async(
+        &self,
+        code: &str,
+        client: &reqwest::Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        let params = self.token_request_params(code);
+        super::request_token_async(
+            client,
+            &self.accounts_url,
+            Some(self.authorization_header()),
+            params,
+        )
+        .await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn request_token_from_redirect_url(
+        &self,
+        url: &str,
+        client: &Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        super::verify_redirect_uri(&self.redirect_uri, url)?;
+        let code = self.verify_authorization_code(url)?;
+        self.request_token(&code, client)
+    }
+
+    pub async fn request_token_from_redirect_url_async(
+        &self,
+        url: &str,
+        client: &reqwest::Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        super::verify_redirect_uri(&self.redirect_uri, url)?;
+        let code = self.verify_authorization_code(url)?;
+        self.request_token_async(&code, client).await
+    }
+
+    fn authorization_header(&self) -> String {
+        let credentials = format!("{}:{}", self.client_id, self.client_secret);
+        format!("Basic {}", general_purpose::STANDARD.encode(credentials))
+    }
+
+    fn token_request_params<'a>(&'a self, code: &'a str) -> FormParams<'a> {
+        let mut params = FormParams::default();
+        params.push("grant_type", &"authorization_code");
+        params.push("code", &code);
+        params.push("redirect_uri", &self.redirect_uri);
+        params
+    }
+
+    fn refresh_token_request_params(refresh_token: &str) -> FormParams<'_> {
+        let mut params = FormParams::default();
+        params.push("grant_type", &"refresh_token");
+        params.push("refresh_token", &refresh_token);
+        params
+    }
+}
+
+impl Accounts for AuthCode {
+    fn accounts_url(&self) -> &Url {
+        &self.accounts_url
+    }
+
+    fn set_accounts_url(&mut self, url: Url) {
+        self.accounts_url = url;
+    }
+
+    fn accept_token(&mut self, token: &mut Token) {
+        self.set_scopes(Some(token.scopes()));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AuthFlow for AuthCode {
+    fn refresh_token(
+        &self,
+        client: &Client,
+        refresh_token: &str,
+    ) -> Result<Token, ApiError<RestError>> {
+        let params = Self::refresh_token_request_params(refresh_token);
+        super::request_token(
+            client,
+            &self.accounts_url,
+            Some(self.authorization_header()),
+            params,
+        )
+    }
+}
+
+#[async_trait]
+impl AsyncAuthFlow for AuthCode {
+    async fn refresh_token_async(
+        &self,
+        client: &reqwest::Client,
+        refresh_token: &str,
+    ) -> Result<Token, ApiError<RestError>> {
+        let params = Self::refresh_token_request_params(refresh_token);
+        super::request_token_async(
+            client,
+            &self.accounts_url,
+            Some(self.authorization_header()),
+            params,
+        )
+        .await
+    }
+}
+
+impl UserAuthorization for AuthCode {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    fn user_authorization_url(&mut self) -> String {
+        Self::user_authorization_url(self)
+    }
+
+    fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
+        Self::verify_authorization_code(self, url)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl UserAuthFlow for AuthCode {
+    fn request_token(&self, code: &str, client: &Client) -> Result<Token, ApiError<RestError>> {
+        Self::request_token(self, code, client)
+    }
+
+    fn request_token_from_redirect_url(
+        &self,
+        url: &str,
+        client: &Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        Self::request_token_from_redirect_url(self, url, client)
+    }
+}
+
+#[async_trait]
+impl AsyncUserAuthFlow for AuthCode {
+    async fn request_token_async(
+        &self,
+        code: &str,
+        client: &reqwest::Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        Self::request_token_async(self, code, client).await
+    }
+
+    async fn request_token_from_redirect_url_async(
+        &self,
+        url: &str,
+        client: &reqwest::Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        Self::request_token_from_redirect_url_async(self, url, client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_request() {
+        let auth = AuthCode::new(
+            "client_id",
+            "client_secret",
+            "http://127.0.0.1:8888/callback",
+            None,
+        );
+
+        assert_eq!(
+            auth.authorization_header(),
+            "Basic Y2xpZW50X2lkOmNsaWVudF9zZWNyZXQ="
+        );
+
+        let (_, body) = auth
+            .token_request_params("code")
+            .into_body()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "grant_type=authorization_code&code=code&redirect_uri=http%3A%2F%2F127.0.0.1%3A8888%2Fcallback"
+        );
+
+        let (_, body) = AuthCode::refresh_token_request_params("refresh")
+            .into_body()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "grant_type=refresh_token&refresh_token=refresh"
+        );
+    }
+
+    #[test]
+    fn user_authorization_url() {
+        let mut auth = AuthCode::new(
+            "client_id",
+            "client_secret",
+            "http://127.0.0.1:8888/callback",
+            Scope::UserReadEmail,
+        );
+
+        let url = Url::parse(&auth.user_authorization_url()).unwrap();
+        let state = auth.state.clone().unwrap();
+
+        let pairs = url.query_pairs().into_owned().collect::<Vec<_>>();
+        assert!(pairs.contains(&("state".to_owned(), state.clone())));
+        assert!(pairs.contains(&("scope".to_owned(), "user-read-email".to_owned())));
+        assert!(
+            !pairs
+                .iter()
+                .any(|(key, _)| key.starts_with("code_challenge"))
+        );
+
+        assert_eq!(
+            auth.verify_authorization_code(&format!(
+                "http://127.0.0.1:8888/callback?code=code&state={state}"
+            ))
+            .unwrap(),
+            "code"
+        );
+    }
+}
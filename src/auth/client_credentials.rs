@@ -1,11 +1,16 @@
-use super::private::{AsyncAuthFlow, AuthFlow};
+#[cfg(not(target_arch = "wasm32"))]
+use super::private::AuthFlow;
+use super::private::{Accounts, AsyncAuthFlow};
 use crate::{
     RestError,
     api::{ApiError, FormParams},
     model::Token,
 };
+use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
+#[cfg(not(target_arch = "wasm32"))]
 use reqwest::blocking::Client;
+use url::Url;
 
 /// Represents the Client Credentials authentication flow for Spotify.
 ///
@@ -13,6 +18,7 @@ use reqwest::blocking::Client;
 /// which is commonly used for server-to-server requests where user authorization is not required.
 /// This flow involves sending a request to the Spotify Accounts service to obtain an access token
 /// using the client ID and client secret.
+#[derive(Clone)]
 pub struct ClientCredentials {
     /// The Client ID generated after registering your application.
     ///
@@ -23,22 +29,58 @@ pub struct ClientCredentials {
     ///
     /// This is required to authenticate your application to the Spotify API.
     client_secret: String,
+
+    /// The base URL of the Spotify Accounts service.
+    accounts_url: Url,
+}
+
+impl Accounts for ClientCredentials {
+    fn accounts_url(&self) -> &Url {
+        &self.accounts_url
+    }
+
+    fn set_accounts_url(&mut self, url: Url) {
+        self.accounts_url = url;
+    }
+
+    fn accept_token(&mut self, token: &mut Token) {
+        token.refresh_token = None;
+        token.scope = None;
+    }
 }
 
-impl AuthFlow for ClientCredentials {}
-impl AsyncAuthFlow for ClientCredentials {}
+#[cfg(not(target_arch = "wasm32"))]
+impl AuthFlow for ClientCredentials {
+    /// Client credentials tokens have no refresh token, but can simply be requested again.
+    fn reacquire_token(&self, client: &Client) -> Option<Result<Token, ApiError<RestError>>> {
+        Some(self.request_token(client))
+    }
+}
+
+#[async_trait]
+impl AsyncAuthFlow for ClientCredentials {
+    /// Client credentials tokens have no refresh token, but can simply be requested again.
+    async fn reacquire_token_async(
+        &self,
+        client: &reqwest::Client,
+    ) -> Option<Result<Token, ApiError<RestError>>> {
+        Some(self.request_token_async(client).await)
+    }
+}
 
 impl ClientCredentials {
     pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
         Self {
             client_id: client_id.into(),
             client_secret: client_secret.into(),
+            accounts_url: Url::parse(super::ACCOUNTS_URL).expect("This URL is always valid"),
         }
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn request_token(&self, client: &Client) -> Result<Token, ApiError<RestError>> {
         let (auth, params) = self.auth_value_and_params();
-        super::request_token(client, Some(auth), params)
+        super::request_token(client, &self.accounts_url, Some(auth), params)
     }
 
     pub async fn request_token_async(
@@ -46,7 +88,7 @@ impl ClientCredentials {
         client: &reqwest::Client,
     ) -> Result<Token, ApiError<RestError>> {
         let (auth, params) = self.auth_value_and_params();
-        super::request_token_async(client, Some(auth), params).await
+        super::request_token_async(client, &self.accounts_url, Some(auth), params).await
     }
 
     fn auth_value_and_params(&self) -> (String, FormParams<'_>) {
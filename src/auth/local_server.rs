@@ -0,0 +1,220 @@
+use super::{AuthError, AuthResult};
+use parking_lot::Mutex;
+use std::{
+    future::Future,
+    io::{self, BufRead as _, BufReader, Write as _},
+    net::{TcpListener, TcpStream},
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+use url::Url;
+
+/// How often the listener checks for a new connection, a timeout or cancellation.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+const RESPONSE: &str = "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nConnection: close\r\n\r\n\
+<html><body>Authorization complete, you can close this window.</body></html>";
+
+/// A local HTTP server which waits for the authorization callback on the redirect URI.
+pub(crate) struct CallbackServer {
+    listener: TcpListener,
+    redirect_uri: Url,
+}
+
+impl CallbackServer {
+    /// Bind to the host and port of the redirect URI.
+    pub(crate) fn bind(redirect_uri: &str) -> AuthResult<Self> {
+        let redirect_uri = Url::parse(redirect_uri)?;
+        let addrs = redirect_uri.socket_addrs(|| Some(80))?;
+        let listener = TcpListener::bind(addrs.as_slice())?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            redirect_uri,
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn local_addr(&self) -> AuthResult<std::net::SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Wait for a request on the redirect path, resolving to the full callback URL.
+    ///
+    /// Resolves to [`AuthError::Timeout`] if no callback arrives within `timeout`. Dropping
+    /// the returned future stops the listener.
+    pub(crate) fn wait(self, timeout: Duration) -> CallbackFuture {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        {
+            let shared = shared.clone();
+            let cancelled = cancelled.clone();
+            let deadline = Instant::now() + timeout;
+            thread::spawn(move || {
+                let Some(result) = self.listen(deadline, &cancelled) else {
+                    return;
+                };
+                let mut shared = shared.lock();
+                shared.result = Some(result);
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            });
+        }
+
+        CallbackFuture { shared, cancelled }
+    }
+
+    /// Accept connections until the callback arrives, the deadline passes, or the wait is
+    /// cancelled, in which case `None` is returned.
+    ///
+    /// A failed connection, such as a browser preconnect which never sends a request, is
+    /// skipped, as the callback may still arrive on another one.
+    fn listen(self, deadline: Instant, cancelled: &AtomicBool) -> Option<AuthResult<String>> {
+        loop {
+            if cancelled.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            match self.listener.accept() {
+                Ok((stream, _)) => match self.handle(stream) {
+                    Ok(Some(url)) => return Some(Ok(url)),
+                    Ok(None) => {}
+                    Err(err) => {
+                        log::warn!("failed to read an authorization callback request: {err}");
+                    }
+                },
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Some(Err(AuthError::Timeout));
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+
+    /// Read a single request, returning the callback URL if it was sent to the redirect path.
+    fn handle(&self, mut stream: TcpStream) -> io::Result<Option<String>> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+        let mut request_line = String::new();
+        BufReader::new(&stream).read_line(&mut request_line)?;
+        stream.write_all(RESPONSE.as_bytes())?;
+
+        let Some(target) = request_line.split_whitespace().nth(1) else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .redirect_uri
+            .join(target)
+            .ok()
+            .filter(|url| url.path() == self.redirect_uri.path())
+            .map(String::from))
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    result: Option<AuthResult<String>>,
+    waker: Option<Waker>,
+}
+
+/// A future resolving to the authorization callback URL received by a [`CallbackServer`].
+pub(crate) struct CallbackFuture {
+    shared: Arc<Mutex<Shared>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Future for CallbackFuture {
+    type Output = AuthResult<String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock();
+        if let Some(result) = shared.result.take() {
+            return Poll::Ready(result);
+        }
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for CallbackFuture {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REDIRECT_URI: &str = "http://127.0.0.1:0/callback";
+
+    #[tokio::test]
+    async fn callback_times_out() {
+        let server = CallbackServer::bind(REDIRECT_URI).unwrap();
+
+        let err = server.wait(Duration::from_millis(100)).await.unwrap_err();
+        assert!(matches!(err, AuthError::Timeout));
+    }
+
+    #[tokio::test]
+    async fn callback_received() {
+        let server = CallbackServer::bind(REDIRECT_URI).unwrap();
+        let addr = server.local_addr().unwrap();
+        let callback = server.wait(Duration::from_secs(5));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /favicon.ico HTTP/1.1\r\n\r\n")
+            .unwrap();
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /callback?code=code&state=state HTTP/1.1\r\n\r\n")
+            .unwrap();
+
+        let url = callback.await.unwrap();
+        assert_eq!(url, "http://127.0.0.1:0/callback?code=code&state=state");
+    }
+
+    #[tokio::test]
+    async fn callback_received_after_failed_connection() {
+        let server = CallbackServer::bind(REDIRECT_URI).unwrap();
+        let addr = server.local_addr().unwrap();
+        let callback = server.wait(Duration::from_secs(5));
+
+        // A request line which is not valid UTF-8 fails to be read.
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(b"\xff\xfe\r\n").unwrap();
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /callback?code=code&state=state HTTP/1.1\r\n\r\n")
+            .unwrap();
+
+        let url = callback.await.unwrap();
+        assert_eq!(url, "http://127.0.0.1:0/callback?code=code&state=state");
+    }
+
+    #[test]
+    fn cancelled_listener_is_dropped() {
+        let server = CallbackServer::bind(REDIRECT_URI).unwrap();
+        let addr = server.local_addr().unwrap();
+
+        drop(server.wait(Duration::from_secs(60)));
+        thread::sleep(POLL_INTERVAL * 5);
+
+        assert!(TcpListener::bind(addr).is_ok());
+    }
+}
@@ -1,6 +1,8 @@
+#[cfg(not(target_arch = "wasm32"))]
+use super::private::{AuthFlow, UserAuthFlow};
 use super::{
     AuthError, AuthResult,
-    private::{AsyncAuthFlow, AuthFlow},
+    private::{Accounts, AsyncAuthFlow, AsyncUserAuthFlow, UserAuthorization},
 };
 use crate::{
     RestError,
@@ -9,6 +11,7 @@ use crate::{
     model::Token,
 };
 use async_trait::async_trait;
+#[cfg(not(target_arch = "wasm32"))]
 use reqwest::blocking::Client;
 use std::collections::HashSet;
 use url::Url;
@@ -60,6 +63,9 @@ pub struct AuthCodePKCE {
     ///
     /// For more details, see [RFC 7636](https://datatracker.ietf.org/doc/html/rfc7636).
     code_verifier: Option<String>,
+
+    /// The base URL of the Spotify Accounts service.
+    accounts_url: Url,
 }
 
 impl AuthCodePKCE {
@@ -76,6 +82,7 @@ impl AuthCodePKCE {
             scopes: scopes.into(),
             state: None,
             code_verifier: None,
+            accounts_url: Url::parse(super::ACCOUNTS_URL).expect("This URL is always valid"),
         }
     }
 
@@ -98,8 +105,10 @@ impl AuthCodePKCE {
             .push("code_challenge_method", &"S256")
             .push("code_challenge", &code_challenge);
 
-        let mut url =
-            Url::parse("https://accounts.spotify.com/authorize").expect("This URL is always valid");
+        let mut url = self
+            .accounts_url
+            .join("authorize")
+            .expect("The accounts URL is always a valid base");
 
         params.add_to_url(&mut url);
 
@@ -110,44 +119,23 @@ impl AuthCodePKCE {
     }
 
     pub fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
-        let self_state = self.state.as_ref().ok_or(AuthError::NoState)?;
-
-        let url = Url::parse(url)?;
-
-        let mut code = None;
-        let mut state = None;
-
-        for (key, value) in url.query_pairs() {
-            match key.as_ref() {
-                "code" => code = Some(value),
-                "state" => state = Some(value),
-                _ => {}
-            }
-        }
-
-        let code = code.ok_or(AuthError::CodeNotFound)?;
-        let state = state.ok_or(AuthError::InvalidState {
-            expected: self_state.to_owned(),
-            got: "None".to_owned(),
-        })?;
+        super::verify_authorization_code(self.state.as_deref(), url)
+    }
 
-        if self_state.eq(&state) {
-            Ok(code.to_string())
-        } else {
-            Err(AuthError::InvalidState {
-                expected: self_state.to_owned(),
-                got: state.to_string(),
-            })
-        }
+    /// Checks that the callback URL was sent to the configured redirect URI, comparing the
+    /// origin and path.
+    fn verify_redirect_uri(&self, url: &str) -> AuthResult<()> {
+        super::verify_redirect_uri(&self.redirect_uri, url)
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn request_token(&self, code: &str, client: &Client) -> Result<Token, ApiError<RestError>> {
         let code_verifier = self
             .code_verifier
             .as_ref()
             .ok_or(AuthError::NoCodeVerifier)?;
         let params = self.token_request_params(code, code_verifier);
-        super::request_token(client, None, params)
+        super::request_token(client, &self.accounts_url, None, params)
     }
 
     pub async fn request_token_async(
@@ -160,21 +148,23 @@ impl AuthCodePKCE {
             .as_ref()
             .ok_or(AuthError::NoCodeVerifier)?;
         let params = self.token_request_params(code, code_verifier);
-        super::request_token_async(client, None, params).await
+        super::request_token_async(client, &self.accounts_url, None, params).await
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn request_token_from_redirect_url(
         &self,
         url: &str,
         client: &Client,
     ) -> Result<Token, ApiError<RestError>> {
+        self.verify_redirect_uri(url)?;
         let code = self.verify_authorization_code(url)?;
         let code_verifier = self
             .code_verifier
             .as_ref()
             .ok_or(AuthError::NoCodeVerifier)?;
         let params = self.token_request_params(&code, code_verifier);
-        super::request_token(client, None, params)
+        super::request_token(client, &self.accounts_url, None, params)
     }
 
     pub async fn request_token_from_redirect_url_async(
@@ -182,13 +172,14 @@ impl AuthCodePKCE {
         url: &str,
         client: &reqwest::Client,
     ) -> Result<Token, ApiError<RestError>> {
+        self.verify_redirect_uri(url)?;
         let code = self.verify_authorization_code(url)?;
         let code_verifier = self
             .code_verifier
             .as_ref()
             .ok_or(AuthError::NoCodeVerifier)?;
         let params = self.token_request_params(&code, code_verifier);
-        super::request_token_async(client, None, params).await
+        super::request_token_async(client, &self.accounts_url, None, params).await
     }
 
     fn token_request_params<'a>(&self, code: &'a str, code_verifier: &'a str) -> FormParams<'a> {
@@ -210,6 +201,21 @@ impl AuthCodePKCE {
     }
 }
 
+impl Accounts for AuthCodePKCE {
+    fn accounts_url(&self) -> &Url {
+        &self.accounts_url
+    }
+
+    fn set_accounts_url(&mut self, url: Url) {
+        self.accounts_url = url;
+    }
+
+    fn accept_token(&mut self, token: &mut Token) {
+        self.set_scopes(Some(token.scopes()));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 impl AuthFlow for AuthCodePKCE {
     fn refresh_token(
         &self,
@@ -217,7 +223,7 @@ impl AuthFlow for AuthCodePKCE {
         refresh_token: &str,
     ) -> Result<Token, ApiError<RestError>> {
         let params = self.refresh_token_request_params(refresh_token);
-        let (request, data) = super::init_http_request_and_data(None, params)?;
+        let (request, data) = super::init_http_request_and_data(&self.accounts_url, None, params)?;
         let response = super::send_http_request(client, request, data).map_err(ApiError::client)?;
         super::parse_http_response(&response)
     }
@@ -231,7 +237,7 @@ impl AsyncAuthFlow for AuthCodePKCE {
         refresh_token: &str,
     ) -> Result<Token, ApiError<RestError>> {
         let params = self.refresh_token_request_params(refresh_token);
-        let (request, data) = super::init_http_request_and_data(None, params)?;
+        let (request, data) = super::init_http_request_and_data(&self.accounts_url, None, params)?;
         let response = super::send_http_request_async(client, request, data)
             .await
             .map_err(ApiError::client)?;
@@ -239,7 +245,56 @@ impl AsyncAuthFlow for AuthCodePKCE {
     }
 }
 
-mod crypto {
+impl UserAuthorization for AuthCodePKCE {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    fn user_authorization_url(&mut self) -> String {
+        Self::user_authorization_url(self)
+    }
+
+    fn verify_authorization_code(&self, url: &str) -> AuthResult<String> {
+        Self::verify_authorization_code(self, url)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl UserAuthFlow for AuthCodePKCE {
+    fn request_token(&self, code: &str, client: &Client) -> Result<Token, ApiError<RestError>> {
+        Self::request_token(self, code, client)
+    }
+
+    fn request_token_from_redirect_url(
+        &self,
+        url: &str,
+        client: &Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        Self::request_token_from_redirect_url(self, url, client)
+    }
+}
+
+#[async_trait]
+impl AsyncUserAuthFlow for AuthCodePKCE {
+    async fn request_token_async(
+        &self,
+        code: &str,
+        client: &reqwest::Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        Self::request_token_async(self, code, client).await
+    }
+
+    async fn request_token_from_redirect_url_async(
+        &self,
+        url: &str,
+        client: &reqwest::Client,
+    ) -> Result<Token, ApiError<RestError>> {
+        Self::request_token_from_redirect_url_async(self, url, client).await
+    }
+}
+
+pub(super) mod crypto {
     use base64::{Engine as _, engine::general_purpose};
     use rand::Rng as _;
     use sha2::{Digest, Sha256};
@@ -273,6 +328,40 @@ mod crypto {
 
 #[cfg(test)]
 mod tests {
+    use super::{ApiError, AuthCodePKCE, AuthError, Client};
+
+    #[test]
+    fn redirect_uri_mismatch() {
+        let mut auth = AuthCodePKCE::new("client_id", "http://127.0.0.1:8888/callback", None);
+        auth.user_authorization_url();
+        let state = auth.state.clone().unwrap();
+
+        let err = auth
+            .request_token_from_redirect_url(
+                &format!("http://localhost:8888/callback?code=code&state={state}"),
+                &Client::new(),
+            )
+            .unwrap_err();
+
+        if let ApiError::Auth(AuthError::RedirectUriMismatch { expected, got }) = err {
+            assert_eq!(expected, "http://127.0.0.1:8888/callback");
+            assert_eq!(got, "http://localhost:8888/callback");
+        } else {
+            panic!("unexpected error: {err}");
+        }
+
+        assert!(
+            auth.verify_redirect_uri(&format!(
+                "http://127.0.0.1:8888/callback?code=code&state={state}"
+            ))
+            .is_ok()
+        );
+        assert!(
+            auth.verify_redirect_uri("http://127.0.0.1:8888/other?code=code")
+                .is_err()
+        );
+    }
+
     #[test]
     fn random_string() {
         let length = 16;
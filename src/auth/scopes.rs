@@ -46,6 +46,11 @@ pub enum Scope {
     /// being played by the user, including metadata.
     UserReadCurrentlyPlaying,
 
+    /// Communicate with the Spotify app on your device.
+    ///
+    /// Allows the application to remotely control playback in the Spotify iOS and Android apps.
+    AppRemoteControl,
+
     /// Enable playback in the Web API player.
     ///
     /// Allows the application to play audio content directly using the Spotify Web API.
@@ -116,6 +121,94 @@ pub enum Scope {
     /// Allows the application to view private information about the user’s Spotify account,
     /// such as their subscription type.
     UserReadPrivate,
+
+    /// Link a partner user account to a Spotify user account.
+    ///
+    /// Only available to Spotify Open Access partners.
+    UserSoaLink,
+
+    /// Unlink a partner user account from a Spotify account.
+    ///
+    /// Only available to Spotify Open Access partners.
+    UserSoaUnlink,
+
+    /// Modify entitlements for linked users.
+    ///
+    /// Only available to Spotify Open Access partners.
+    UserManageEntitlements,
+
+    /// Update partner information.
+    ///
+    /// Only available to Spotify Open Access partners.
+    UserManagePartner,
+
+    /// Create new partners.
+    ///
+    /// Only available to Spotify Open Access partners.
+    UserCreatePartner,
+}
+
+impl Scope {
+    /// Returns every scope variant, including the Spotify Open Access partner scopes.
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::UgcImageUpload,
+            Self::UserReadPlaybackState,
+            Self::UserModifyPlaybackState,
+            Self::UserReadCurrentlyPlaying,
+            Self::AppRemoteControl,
+            Self::Streaming,
+            Self::PlaylistReadPrivate,
+            Self::PlaylistReadCollaborative,
+            Self::PlaylistModifyPrivate,
+            Self::PlaylistModifyPublic,
+            Self::UserFollowModify,
+            Self::UserFollowRead,
+            Self::UserReadPlaybackPosition,
+            Self::UserTopRead,
+            Self::UserReadRecentlyPlayed,
+            Self::UserLibraryModify,
+            Self::UserLibraryRead,
+            Self::UserReadEmail,
+            Self::UserReadPrivate,
+            Self::UserSoaLink,
+            Self::UserSoaUnlink,
+            Self::UserManageEntitlements,
+            Self::UserManagePartner,
+            Self::UserCreatePartner,
+        ]
+    }
+
+    /// Parses a space-delimited scope string, such as the `scope` of a token.
+    ///
+    /// Scopes unknown to this library are skipped.
+    pub fn parse_set(scopes: &str) -> HashSet<Self> {
+        scopes
+            .split_whitespace()
+            .filter_map(|scope| Self::try_from(scope).ok())
+            .collect()
+    }
+
+    /// Formats a set of scopes as a space-delimited scope string, sorted alphabetically.
+    ///
+    /// This is the inverse of [`Scope::parse_set`].
+    pub fn to_scope_string(scopes: &HashSet<Self>) -> String {
+        let mut scopes = scopes.iter().map(Self::to_string).collect::<Vec<_>>();
+        scopes.sort_unstable();
+        scopes.join(" ")
+    }
+
+    /// Whether this scope is only available to Spotify Open Access partners.
+    pub fn is_open_access(&self) -> bool {
+        matches!(
+            self,
+            Self::UserSoaLink
+                | Self::UserSoaUnlink
+                | Self::UserManageEntitlements
+                | Self::UserManagePartner
+                | Self::UserCreatePartner
+        )
+    }
 }
 
 /// Returns all playlist-related scopes.
@@ -247,30 +340,15 @@ pub fn user_playback() -> HashSet<Scope> {
 /// require full access to a user's account and data.
 ///
 /// # Scopes Included
-/// This function includes all defined [`Scope`] variants.
+/// This function includes all defined [`Scope`] variants, except for the Spotify Open
+/// Access partner scopes (see [`Scope::is_open_access`]), which regular applications
+/// cannot request.
 pub fn all() -> HashSet<Scope> {
-    vec![
-        Scope::UgcImageUpload,
-        Scope::UserReadPlaybackState,
-        Scope::UserModifyPlaybackState,
-        Scope::UserReadCurrentlyPlaying,
-        Scope::Streaming,
-        Scope::PlaylistReadPrivate,
-        Scope::PlaylistReadCollaborative,
-        Scope::PlaylistModifyPrivate,
-        Scope::PlaylistModifyPublic,
-        Scope::UserFollowModify,
-        Scope::UserFollowRead,
-        Scope::UserReadPlaybackPosition,
-        Scope::UserTopRead,
-        Scope::UserReadRecentlyPlayed,
-        Scope::UserLibraryModify,
-        Scope::UserLibraryRead,
-        Scope::UserReadEmail,
-        Scope::UserReadPrivate,
-    ]
-    .into_iter()
-    .collect()
+    Scope::all()
+        .iter()
+        .filter(|scope| !scope.is_open_access())
+        .copied()
+        .collect()
 }
 
 /// Converts a set of `Scope` values into a space-separated string.
@@ -298,13 +376,9 @@ pub fn all() -> HashSet<Scope> {
 /// ```
 ///
 /// # Notes
-/// The order of the scopes in the resulting string is not guaranteed, as `HashSet` does
-/// not maintain insertion order.
+/// The scopes are sorted alphabetically, see [`Scope::to_scope_string`].
 pub fn to_string(set: &HashSet<Scope>) -> String {
-    set.iter()
-        .map(|s| s.to_string())
-        .collect::<Vec<_>>()
-        .join(" ")
+    Scope::to_scope_string(set)
 }
 
 impl std::fmt::Display for Scope {
@@ -314,6 +388,7 @@ impl std::fmt::Display for Scope {
             Self::UserReadPlaybackState => "user-read-playback-state",
             Self::UserModifyPlaybackState => "user-modify-playback-state",
             Self::UserReadCurrentlyPlaying => "user-read-currently-playing",
+            Self::AppRemoteControl => "app-remote-control",
             Self::Streaming => "streaming",
             Self::PlaylistReadPrivate => "playlist-read-private",
             Self::PlaylistReadCollaborative => "playlist-read-collaborative",
@@ -328,6 +403,11 @@ impl std::fmt::Display for Scope {
             Self::UserLibraryRead => "user-library-read",
             Self::UserReadEmail => "user-read-email",
             Self::UserReadPrivate => "user-read-private",
+            Self::UserSoaLink => "user-soa-link",
+            Self::UserSoaUnlink => "user-soa-unlink",
+            Self::UserManageEntitlements => "user-manage-entitlements",
+            Self::UserManagePartner => "user-manage-partner",
+            Self::UserCreatePartner => "user-create-partner",
         };
 
         write!(f, "{s}")
@@ -343,6 +423,7 @@ impl TryFrom<&str> for Scope {
             "user-read-playback-state" => Self::UserReadPlaybackState,
             "user-modify-playback-state" => Self::UserModifyPlaybackState,
             "user-read-currently-playing" => Self::UserReadCurrentlyPlaying,
+            "app-remote-control" => Self::AppRemoteControl,
             "streaming" => Self::Streaming,
             "playlist-read-private" => Self::PlaylistReadPrivate,
             "playlist-read-collaborative" => Self::PlaylistReadCollaborative,
@@ -357,6 +438,11 @@ impl TryFrom<&str> for Scope {
             "user-library-read" => Self::UserLibraryRead,
             "user-read-email" => Self::UserReadEmail,
             "user-read-private" => Self::UserReadPrivate,
+            "user-soa-link" => Self::UserSoaLink,
+            "user-soa-unlink" => Self::UserSoaUnlink,
+            "user-manage-entitlements" => Self::UserManageEntitlements,
+            "user-manage-partner" => Self::UserManagePartner,
+            "user-create-partner" => Self::UserCreatePartner,
             _ => return Err(()),
         };
 
@@ -385,4 +471,56 @@ mod tests {
         let scope = Scope::UserReadPlaybackState;
         assert_eq!("user-read-playback-state", scope.to_string());
     }
+
+    #[test]
+    fn scope_round_trip() {
+        for scope in Scope::all() {
+            assert_eq!(Scope::try_from(scope.to_string().as_str()), Ok(*scope));
+        }
+
+        assert_eq!(
+            Scope::try_from("ugc-image-upload"),
+            Ok(Scope::UgcImageUpload)
+        );
+        assert_eq!(Scope::try_from("user-soa-link"), Ok(Scope::UserSoaLink));
+        assert_eq!(Scope::try_from("not-a-scope"), Err(()));
+    }
+
+    #[test]
+    fn scope_string_round_trip() {
+        let scopes = Scope::parse_set(
+            "user-read-private  playlist-modify-public user-read-email\tnot-a-scope streaming",
+        );
+
+        assert_eq!(
+            scopes,
+            HashSet::from([
+                Scope::UserReadPrivate,
+                Scope::PlaylistModifyPublic,
+                Scope::UserReadEmail,
+                Scope::Streaming,
+            ])
+        );
+        assert_eq!(
+            Scope::to_scope_string(&scopes),
+            "playlist-modify-public streaming user-read-email user-read-private"
+        );
+        assert_eq!(Scope::parse_set(&Scope::to_scope_string(&scopes)), scopes);
+    }
+
+    #[test]
+    fn empty_scope_string() {
+        assert!(Scope::parse_set("").is_empty());
+        assert!(Scope::parse_set("not-a-scope").is_empty());
+        assert_eq!(Scope::to_scope_string(&HashSet::new()), "");
+    }
+
+    #[test]
+    fn all_scopes_excludes_open_access() {
+        let scopes = all();
+        assert!(scopes.contains(&Scope::UgcImageUpload));
+        assert!(scopes.contains(&Scope::AppRemoteControl));
+        assert!(!scopes.contains(&Scope::UserSoaLink));
+        assert_eq!(scopes.len(), Scope::all().len() - 5);
+    }
 }
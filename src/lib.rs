@@ -76,12 +76,14 @@
 //! Choosing one flow over the rest depends on the application you are building:
 //!
 //! - In scenarios where storing the client secret is not safe (e.g. desktop, mobile apps or JavaScript web apps running in the browser), you can use the [authorization code with PKCE](https://developer.spotify.com/documentation/web-api/tutorials/code-pkce-flow), as it provides protection against attacks where the authorization code may be intercepted.
+//! - If you are developing a long-running application (e.g. web app running on the server) in which the user grants permission only once, and the client secret can be safely stored, then the [authorization code flow](https://developer.spotify.com/documentation/web-api/tutorials/code-flow) is the recommended choice.
 //! - For some applications running on the backend, such as CLIs or daemons, the system authenticates and authorizes the app rather than a user. For these scenarios, [Client credentials](https://developer.spotify.com/documentation/web-api/tutorials/client-credentials-flow) is the typical choice. This flow does not include user authorization, so only endpoints that do not request user information (e.g. user profile data) can be accessed.
 //!
 //! The following table summarizes the flows' behaviors:
 //!
 //! | Flow | Access User Resources | Requires Secret Key (Server-Side) | Access Token Refresh |
 //! | :--- | :--- | :--- | :--- |
+//! | Authorization code | Yes | Yes | Yes |
 //! | Authorization code with PKCE | Yes | No | Yes |
 //! | Client credentials | No | Yes | No |
 //!
@@ -97,6 +99,8 @@
 //! There are additional helpers to handle different cases:
 //! - [`api::ignore`]: Ignore the Spotify response (useful for POST or PUT endpoints).
 //! - [`api::paged`]: Fetch results that are paginated.
+//! - [`api::single_page`]: Fetch one page of paginated results along with its `total` and `next` link.
+//! - [`api::LazilyCursorPagedIter`]: Iterate over results that are paginated with cursors, such as followed artists.
 //! - [`api::raw`]: Return the raw data from Spotify instead of deserializing into a structure.
 //!
 //! You're not restricted to the predefined endpoints; you can define your own by implementing the [`api::Endpoint`] trait. [See example](https://github.com/ry-sev/spotify_web_api/blob/main/examples/creds_custom_endpoint.rs).
@@ -111,6 +115,23 @@
 //!
 //! - `markets` - Enables the `available_markets` field in various models, such as [`model::Track`]. This field contains a list of markets where the content is available.
 //! - `page_items` - Enables the field in various models that contain paginated items, such as the `tracks` field in [`model::Playlist`].
+//! - `audiobooks` - Enables the audiobook and chapter endpoints in `api::audiobooks` and `api::chapters`, and their models.
+//! - `shows` - Enables the show and episode endpoints in `api::shows` and `api::episodes`, and their models.
+//! - `player` - Enables the playback endpoints in `api::player`, and their models.
+//! - `tokio` - Lets `AsyncSpotify::with_retry` wait between retries of rate limited requests with the tokio timer, and makes debug builds warn once when the blocking [`Spotify`] client is used inside a tokio runtime.
+//!
+//! The endpoint features can be disabled to build a slimmer crate when only a few endpoints are needed, such as
+//! authentication and tracks. The results of a disabled type are then left out of search results, and without
+//! `shows`, playlist items and the playback state fail to deserialize when they contain an episode.
+//!
+//! The following feature flags are **disabled by default**:
+//!
+//! - `cache` - Enables `api::CachingClient` and `api::LruCache`, which cache single track, album and artist lookups for a fixed amount of time.
+//! - `record` - Enables `api::RecordingClient`, a client wrapper that records every request made through it. Useful for asserting which Spotify calls your code made in tests.
+//! - `wasm` - Required when building for `wasm32-unknown-unknown`, for example for browser apps. Only [`AsyncSpotify`] is available on this target, and requests are sent with the browser's `fetch`, so proxies, redirect policies and the local authorization server are not supported. The `getrandom_backend="wasm_js"` configuration flag must also be set, see the [`getrandom` documentation](https://docs.rs/getrandom/0.3/#webassembly-support). Every other feature can be combined with `wasm`, although `gzip` and `brotli` have no effect there since the browser negotiates compression itself.
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("the `wasm` feature must be enabled when building for wasm32");
 
 mod spotify;
 
@@ -10,44 +10,68 @@
 //! - `markets` (default): Includes `available_markets` fields on tracks, albums, etc.
 //! - `page_items` (default): Includes `items` fields on paginated responses.
 //!
+//! Some modules are only compiled with the corresponding endpoint feature:
+//!
+//! - `audiobooks` (default): The `audiobooks` and `chapters` models.
+//! - `shows` (default): The `shows` and `episodes` models, including episodes in search
+//!   results, playlist items and the playback state.
+//! - `player` (default): The `player` models.
+//!
 //! # Common Types
 //!
 //! - [`Page`] - Paginated response wrapper
 //! - [`Image`] - Image metadata
 //! - [`ExternalUrls`] - External URL links (Spotify URLs)
 //! - [`Followers`] - Follower information
+//!
+//! The [`sort`] module has helpers for ranking tracks and artists.
 
 pub mod albums;
 pub mod artists;
+pub mod audio_features;
+#[cfg(feature = "audiobooks")]
 pub mod audiobooks;
 pub mod categories;
+#[cfg(feature = "audiobooks")]
 pub mod chapters;
+#[cfg(feature = "shows")]
 pub mod episodes;
 pub mod genres;
 pub mod id;
 pub mod markets;
 pub mod misc;
+#[cfg(feature = "player")]
 pub mod player;
 pub mod playlists;
+pub mod recommendations;
 pub mod search;
+#[cfg(feature = "shows")]
 pub mod shows;
+pub mod sort;
 pub mod token;
 pub mod tracks;
 pub mod users;
 
 pub use albums::*;
 pub use artists::*;
+pub use audio_features::*;
+#[cfg(feature = "audiobooks")]
 pub use audiobooks::*;
 pub use categories::*;
+#[cfg(feature = "audiobooks")]
 pub use chapters::*;
+#[cfg(feature = "shows")]
 pub use episodes::*;
 pub use genres::*;
 pub use id::*;
 pub use markets::*;
 pub use misc::*;
+#[cfg(feature = "player")]
 pub use player::*;
 pub use playlists::*;
+pub use recommendations::*;
 pub use search::*;
+#[cfg(feature = "shows")]
 pub use shows::*;
 pub use token::*;
 pub use tracks::*;
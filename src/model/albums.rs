@@ -1,8 +1,13 @@
+#[cfg(feature = "markets")]
+use super::Market;
+#[cfg(feature = "page_items")]
+use super::SimplifiedTrack;
 use super::{
-    Copyright, ExternalIds, ExternalUrls, Image, ItemType, Market, Page, ReleaseDatePrecision,
-    Restrictions, SimplifiedArtist, SimplifiedTrack,
+    Copyright, ExternalIds, ExternalUrls, Image, ItemType, Page, ReleaseDate, ReleaseDatePrecision,
+    Restrictions, SimplifiedArtist, misc::release_date_key,
 };
 use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap};
 
 /// The type of an album.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -185,6 +190,64 @@ pub struct SimplifiedAlbum {
 
     /// The artists of the album. Each artist object includes a link in href to more detailed information about the artist.
     pub artists: Vec<SimplifiedArtist>,
+
+    /// This field describes the relationship between the artist and the album.
+    /// Only present when fetching an artist's albums.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub album_group: Option<AlbumType>,
+}
+
+impl Album {
+    /// Parses the release date of the album, to the precision it is known.
+    pub fn release_date_parsed(&self) -> Option<ReleaseDate> {
+        ReleaseDate::parse(&self.release_date, Some(&self.release_date_precision))
+    }
+
+    /// Compares the release dates of two albums, regardless of their precision.
+    ///
+    /// Dates are compared as if missing months and days were the first of the period, so
+    /// `"1981"` is treated as `1981-01-01`. When the dates are otherwise equal, the less
+    /// precise date sorts first.
+    pub fn release_cmp(&self, other: &Self) -> Ordering {
+        release_date_key(Some(&self.release_date), Some(&self.release_date_precision)).cmp(
+            &release_date_key(
+                Some(&other.release_date),
+                Some(&other.release_date_precision),
+            ),
+        )
+    }
+}
+
+impl SimplifiedAlbum {
+    /// The relationship between the artist and the album, falling back to the album type
+    /// when the response did not include an `album_group`.
+    pub fn group(&self) -> &AlbumType {
+        self.album_group.as_ref().unwrap_or(&self.album_type)
+    }
+
+    /// Parses the release date of the album, to the precision it is known.
+    ///
+    /// Returns `None` when the response did not include a release date.
+    pub fn release_date_parsed(&self) -> Option<ReleaseDate> {
+        ReleaseDate::parse(
+            self.release_date.as_deref()?,
+            self.release_date_precision.as_ref(),
+        )
+    }
+
+    /// Compares the release dates of two albums, regardless of their precision.
+    ///
+    /// See [`Album::release_cmp`]. Albums without a release date sort first.
+    pub fn release_cmp(&self, other: &Self) -> Ordering {
+        release_date_key(
+            self.release_date.as_deref(),
+            self.release_date_precision.as_ref(),
+        )
+        .cmp(&release_date_key(
+            other.release_date.as_deref(),
+            other.release_date_precision.as_ref(),
+        ))
+    }
 }
 
 impl From<Album> for SimplifiedAlbum {
@@ -205,6 +268,7 @@ impl From<Album> for SimplifiedAlbum {
             type_: album.type_,
             uri: album.uri,
             artists: album.artists,
+            album_group: album.album_group,
         }
     }
 }
@@ -233,6 +297,52 @@ pub struct NewReleases {
     pub albums: Page<SimplifiedAlbum>,
 }
 
+/// Removes duplicate albums from an artist's discography.
+///
+/// Spotify lists the same release several times across the `album`, `single`, `compilation`
+/// and `appears_on` groups, as well as once per regional variant. Albums are considered
+/// duplicates when their names (ignoring case and surrounding or repeated whitespace) and
+/// release dates match.
+///
+/// When duplicates are found, the entry from the most significant group is kept, in the order
+/// `album`, `single`, `compilation`, `appears_on`. The position of the first occurrence is
+/// preserved.
+pub fn dedup_discography<I>(albums: I) -> Vec<SimplifiedAlbum>
+where
+    I: IntoIterator<Item = SimplifiedAlbum>,
+{
+    fn group_rank(group: &AlbumType) -> usize {
+        AlbumType::all()
+            .iter()
+            .position(|g| g == group)
+            .unwrap_or(usize::MAX)
+    }
+
+    let mut deduped: Vec<SimplifiedAlbum> = Vec::new();
+    let mut seen: HashMap<(String, Option<String>), usize> = HashMap::new();
+
+    for album in albums {
+        let name = album
+            .name
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let key = (name, album.release_date.clone());
+
+        if let Some(&index) = seen.get(&key) {
+            if group_rank(album.group()) < group_rank(deduped[index].group()) {
+                deduped[index] = album;
+            }
+        } else {
+            seen.insert(key, deduped.len());
+            deduped.push(album);
+        }
+    }
+
+    deduped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,4 +502,74 @@ mod tests {
 
         crate::test::assert_deserialized!(SimplifiedAlbum, json);
     }
+
+    fn discography_album(id: &str, name: &str, release_date: &str, group: &str) -> SimplifiedAlbum {
+        let json = format!(
+            r#"
+            {{
+                "album_type": "album",
+                "total_tracks": 9,
+                "external_urls": {{ "spotify": "string" }},
+                "href": "string",
+                "id": "{id}",
+                "images": [],
+                "name": "{name}",
+                "release_date": "{release_date}",
+                "release_date_precision": "day",
+                "type": "album",
+                "uri": "spotify:album:{id}",
+                "artists": [],
+                "album_group": "{group}"
+            }}
+            "#
+        );
+
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn dedup_discography_prefers_album_group() {
+        let albums = vec![
+            discography_album("a", "Hits", "2001-01-01", "appears_on"),
+            discography_album("b", "Debut", "1999-05-01", "single"),
+            discography_album("c", "hits ", "2001-01-01", "album"),
+            discography_album("d", "Debut", "1999-05-01", "single"),
+            discography_album("e", "Debut", "2010-05-01", "compilation"),
+            discography_album("f", "Second  Album", "2003-03-03", "album"),
+            discography_album("g", "second album", "2003-03-03", "compilation"),
+        ];
+
+        let deduped = dedup_discography(albums);
+        let ids = deduped.iter().map(|a| a.id.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(ids, ["c", "b", "e", "f"]);
+        assert_eq!(deduped[0].group(), &AlbumType::Album);
+    }
+
+    #[test]
+    fn release_cmp_mixed_precision() {
+        let dated = |id: &str, release_date: &str, precision| {
+            let mut album = discography_album(id, id, release_date, "album");
+            album.release_date_precision = Some(precision);
+            album
+        };
+
+        let mut albums = [
+            dated("a", "1981-12-04", ReleaseDatePrecision::Day),
+            dated("b", "1981", ReleaseDatePrecision::Year),
+            dated("c", "1980-06", ReleaseDatePrecision::Month),
+            dated("d", "1981-01-01", ReleaseDatePrecision::Day),
+            dated("e", "1981-12", ReleaseDatePrecision::Month),
+        ];
+        albums.sort_by(SimplifiedAlbum::release_cmp);
+
+        let ids = albums.iter().map(|a| a.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, ["c", "b", "d", "e", "a"]);
+    }
+
+    #[test]
+    fn release_date_precision_order() {
+        assert!(ReleaseDatePrecision::Year < ReleaseDatePrecision::Month);
+        assert!(ReleaseDatePrecision::Month < ReleaseDatePrecision::Day);
+    }
 }
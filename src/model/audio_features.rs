@@ -0,0 +1,152 @@
+use super::ItemType;
+use serde::{Deserialize, Serialize};
+
+/// Audio features of a track, such as its tempo, key and energy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AudioFeatures {
+    /// A confidence measure from 0.0 to 1.0 of whether the track is acoustic.
+    /// 1.0 represents high confidence the track is acoustic.
+    pub acousticness: f32,
+
+    /// A URL to access the full audio analysis of this track.
+    pub analysis_url: String,
+
+    /// Danceability describes how suitable a track is for dancing based on a combination of musical elements
+    /// including tempo, rhythm stability, beat strength, and overall regularity.
+    /// A value of 0.0 is least danceable and 1.0 is most danceable.
+    pub danceability: f32,
+
+    /// The duration of the track in milliseconds.
+    pub duration_ms: u32,
+
+    /// Energy is a measure from 0.0 to 1.0 and represents a perceptual measure of intensity and activity.
+    pub energy: f32,
+
+    /// The Spotify ID for the track.
+    pub id: String,
+
+    /// Predicts whether a track contains no vocals. The closer the instrumentalness value is to 1.0,
+    /// the greater likelihood the track contains no vocal content.
+    pub instrumentalness: f32,
+
+    /// The key the track is in. Integers map to pitches using standard
+    /// [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class). E.g. 0 = C, 1 = C♯/D♭, 2 = D, and so on.
+    /// If no key was detected, the value is -1.
+    pub key: i32,
+
+    /// Detects the presence of an audience in the recording. A value above 0.8 provides strong likelihood
+    /// that the track is live.
+    pub liveness: f32,
+
+    /// The overall loudness of a track in decibels (dB), typically ranging between -60 and 0 db.
+    pub loudness: f32,
+
+    /// Mode indicates the modality (major or minor) of a track. Major is represented by 1 and minor is 0.
+    pub mode: u32,
+
+    /// Speechiness detects the presence of spoken words in a track, from 0.0 to 1.0.
+    pub speechiness: f32,
+
+    /// The overall estimated tempo of a track in beats per minute (BPM).
+    pub tempo: f32,
+
+    /// An estimated time signature, ranging from 3 to 7 indicating time signatures of "3/4", to "7/4".
+    pub time_signature: u32,
+
+    /// A link to the Web API endpoint providing full details of the track.
+    pub track_href: String,
+
+    /// The object type.
+    ///
+    /// Allowed values: `"audio_features"`
+    #[serde(rename = "type")]
+    pub type_: ItemType,
+
+    /// The Spotify URI for the track.
+    pub uri: String,
+
+    /// A measure from 0.0 to 1.0 describing the musical positiveness conveyed by a track.
+    /// Tracks with high valence sound more positive, while tracks with low valence sound more negative.
+    pub valence: f32,
+}
+
+/// Audio features for several tracks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeveralAudioFeatures {
+    pub audio_features: Vec<Option<AudioFeatures>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_features() {
+        let json = r#"
+        {
+			"acousticness": 0.00242,
+			"analysis_url": "https://api.spotify.com/v1/audio-analysis/2takcwOaAZWiXQijPHIx7B",
+			"danceability": 0.585,
+			"duration_ms": 237040,
+			"energy": 0.842,
+			"id": "2takcwOaAZWiXQijPHIx7B",
+			"instrumentalness": 0.00686,
+			"key": 9,
+			"liveness": 0.0866,
+			"loudness": -5.883,
+			"mode": 0,
+			"speechiness": 0.0556,
+			"tempo": 118.211,
+			"time_signature": 4,
+			"track_href": "https://api.spotify.com/v1/tracks/2takcwOaAZWiXQijPHIx7B",
+			"type": "audio_features",
+			"uri": "spotify:track:2takcwOaAZWiXQijPHIx7B",
+			"valence": 0.428
+        }
+        "#;
+
+        crate::test::assert_deserialized!(AudioFeatures, json);
+
+        let features: AudioFeatures = serde_json::from_str(json).unwrap();
+        assert_eq!(features.key, 9);
+        assert_eq!(features.loudness, -5.883);
+        assert_eq!(features.type_, ItemType::AudioFeatures);
+    }
+
+    #[test]
+    fn several_audio_features() {
+        let json = r#"
+        {
+			"audio_features": [
+				{
+					"acousticness": 0.00242,
+					"analysis_url": "https://api.spotify.com/v1/audio-analysis/2takcwOaAZWiXQijPHIx7B",
+					"danceability": 0.585,
+					"duration_ms": 237040,
+					"energy": 0.842,
+					"id": "2takcwOaAZWiXQijPHIx7B",
+					"instrumentalness": 0.00686,
+					"key": -1,
+					"liveness": 0.0866,
+					"loudness": -5.883,
+					"mode": 1,
+					"speechiness": 0.0556,
+					"tempo": 118.211,
+					"time_signature": 4,
+					"track_href": "https://api.spotify.com/v1/tracks/2takcwOaAZWiXQijPHIx7B",
+					"type": "audio_features",
+					"uri": "spotify:track:2takcwOaAZWiXQijPHIx7B",
+					"valence": 0.428
+				},
+				null
+			]
+        }
+        "#;
+
+        crate::test::assert_deserialized!(SeveralAudioFeatures, json);
+
+        let features: SeveralAudioFeatures = serde_json::from_str(json).unwrap();
+        assert_eq!(features.iter().len(), 2);
+        assert_eq!(features.present().count(), 1);
+    }
+}
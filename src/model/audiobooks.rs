@@ -1,4 +1,8 @@
-use super::{Copyright, ExternalUrls, Image, ItemType, Market, Page, SimplifiedChapter};
+#[cfg(feature = "markets")]
+use super::Market;
+use super::{Copyright, ExternalUrls, Image, ItemType};
+#[cfg(feature = "page_items")]
+use super::{Page, SimplifiedChapter};
 use serde::{Deserialize, Serialize};
 
 /// An audiobook author.
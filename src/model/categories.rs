@@ -49,4 +49,36 @@ mod tests {
 
         crate::test::assert_deserialized!(Category, json);
     }
+
+    #[test]
+    fn categories() {
+        let json = r#"
+        {
+			"categories": {
+				"href": "https://api.spotify.com/v1/browse/categories?offset=0&limit=1",
+				"limit": 1,
+				"next": "https://api.spotify.com/v1/browse/categories?offset=1&limit=1",
+				"offset": 0,
+				"previous": null,
+				"total": 2,
+				"items": [
+					{
+						"href": "https://api.spotify.com/v1/browse/categories/0JQ5DAt0tbjZptfcdMSKl3",
+						"icons": [
+							{
+								"url": "https://t.scdn.co/images/728ed47fc1674feb95f7ac20236eb6d7.jpeg",
+								"height": 274,
+								"width": 274
+							}
+						],
+						"id": "0JQ5DAt0tbjZptfcdMSKl3",
+						"name": "Made For You"
+					}
+				]
+			}
+        }
+        "#;
+
+        crate::test::assert_deserialized!(Categories, json);
+    }
 }
@@ -1,6 +1,8 @@
+#[cfg(feature = "markets")]
+use super::Market;
 use super::{
-    ExternalUrls, Image, ItemType, Market, ReleaseDatePrecision, Restrictions, ResumePoint,
-    SimplifiedAudiobook,
+    ExternalUrls, Image, ItemType, ReleaseDate, ReleaseDatePrecision, Restrictions, ResumePoint,
+    SimplifiedAudiobook, SpotifyObject,
 };
 use serde::{Deserialize, Serialize};
 
@@ -68,6 +70,9 @@ pub struct Chapter {
     /// The object type.
     ///
     /// Allowed values: "episode"
+    ///
+    /// Spotify reports chapters as episodes, so prefer [`SpotifyObject::item_type`] when dispatching on
+    /// the type of an item.
     #[serde(rename = "type")]
     pub type_: ItemType,
 
@@ -146,6 +151,9 @@ pub struct SimplifiedChapter {
     /// The object type.
     ///
     /// Allowed values: "episode"
+    ///
+    /// Spotify reports chapters as episodes, so prefer [`SpotifyObject::item_type`] when dispatching on
+    /// the type of an item.
     #[serde(rename = "type")]
     pub type_: ItemType,
 
@@ -184,6 +192,35 @@ impl From<Chapter> for SimplifiedChapter {
     }
 }
 
+impl Chapter {
+    /// Parses the release date of the chapter, to the precision it is known.
+    pub fn release_date_parsed(&self) -> Option<ReleaseDate> {
+        ReleaseDate::parse(&self.release_date, self.release_date_precision.as_ref())
+    }
+}
+
+// Spotify sends `"type": "episode"` for chapters, so the `type_` field cannot be used to tell
+// chapters and podcast episodes apart.
+impl SpotifyObject for Chapter {
+    fn spotify_id(&self) -> &str {
+        &self.id
+    }
+
+    fn item_type(&self) -> &ItemType {
+        &ItemType::Chapter
+    }
+}
+
+impl SpotifyObject for SimplifiedChapter {
+    fn spotify_id(&self) -> &str {
+        &self.id
+    }
+
+    fn item_type(&self) -> &ItemType {
+        &ItemType::Chapter
+    }
+}
+
 /// Spotify catalog information for several audiobook chapters
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Chapters {
@@ -193,6 +230,7 @@ pub struct Chapters {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::fixtures;
 
     #[test]
     fn chapter() {
@@ -321,4 +359,22 @@ mod tests {
 
         crate::test::assert_deserialized!(SimplifiedChapter, json);
     }
+
+    #[test]
+    fn chapter_item_type() {
+        let chapter: SimplifiedChapter =
+            serde_json::from_value(fixtures::chapter("5Xt5DXGzch68nYYamXrNxZ")).unwrap();
+
+        assert_eq!(chapter.type_, ItemType::Episode);
+        assert_eq!(chapter.item_type(), &ItemType::Chapter);
+        assert_eq!(
+            chapter.open_url(),
+            "https://open.spotify.com/chapter/5Xt5DXGzch68nYYamXrNxZ"
+        );
+
+        fn generic_item_type<T: SpotifyObject>(item: &T) -> &ItemType {
+            item.item_type()
+        }
+        assert_eq!(generic_item_type(&chapter), &ItemType::Chapter);
+    }
 }
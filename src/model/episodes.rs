@@ -1,5 +1,6 @@
 use super::{
-    ExternalUrls, Image, ItemType, ReleaseDatePrecision, Restrictions, ResumePoint, SimplifiedShow,
+    ExternalUrls, Image, ItemType, ReleaseDate, ReleaseDatePrecision, Restrictions, ResumePoint,
+    SimplifiedShow,
 };
 use serde::{Deserialize, Serialize};
 
@@ -142,6 +143,13 @@ pub struct SimplifiedEpisode {
     pub restrictions: Option<Restrictions>,
 }
 
+impl Episode {
+    /// Parses the release date of the episode, to the precision it is known.
+    pub fn release_date_parsed(&self) -> Option<ReleaseDate> {
+        ReleaseDate::parse(&self.release_date, Some(&self.release_date_precision))
+    }
+}
+
 impl From<Episode> for SimplifiedEpisode {
     fn from(episode: Episode) -> Self {
         Self {
@@ -1,13 +1,22 @@
+use super::ItemType;
 use thiserror::Error;
+use url::Url;
 
 /// Errors that can occur when parsing or validating Spotify IDs.
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum IdError {
     #[error("The ID is not in correct format.")]
     InvalidFormat,
 
     #[error("The ID is not the correct length. Got {got}, expected {expected}.")]
     InvalidLength { got: usize, expected: usize },
+
+    #[error("Items of type {0} cannot be identified by an ID.")]
+    UnsupportedItemType(ItemType),
+
+    #[error("Expected an ID of type {expected}, got {got}.")]
+    TypeMismatch { expected: IdType, got: IdType },
 }
 
 /// The type of a Spotify resource identified by an ID.
@@ -37,6 +46,162 @@ impl std::fmt::Display for IdType {
     }
 }
 
+impl From<IdType> for ItemType {
+    fn from(id_type: IdType) -> Self {
+        match id_type {
+            IdType::User => Self::User,
+            IdType::Album => Self::Album,
+            IdType::Artist => Self::Artist,
+            IdType::Playlist => Self::Playlist,
+            IdType::Track => Self::Track,
+            IdType::Show => Self::Show,
+            IdType::Episode => Self::Episode,
+        }
+    }
+}
+
+impl TryFrom<ItemType> for IdType {
+    type Error = IdError;
+
+    fn try_from(item_type: ItemType) -> Result<Self, Self::Error> {
+        match item_type {
+            ItemType::User => Ok(Self::User),
+            ItemType::Album => Ok(Self::Album),
+            ItemType::Artist => Ok(Self::Artist),
+            ItemType::Playlist => Ok(Self::Playlist),
+            ItemType::Track => Ok(Self::Track),
+            ItemType::Show => Ok(Self::Show),
+            ItemType::Episode => Ok(Self::Episode),
+            ItemType::Audiobook
+            | ItemType::Unknown
+            | ItemType::Chapter
+            | ItemType::Collection
+            | ItemType::AudioFeatures => Err(IdError::UnsupportedItemType(item_type)),
+        }
+    }
+}
+
+impl ItemType {
+    /// The ID type for items of this type, if they have one.
+    pub fn to_id_type(&self) -> Option<IdType> {
+        IdType::try_from(self.clone()).ok()
+    }
+}
+
+/// An ID of any type.
+///
+/// This is useful for generic code which only learns the type of an item at runtime, such as
+/// when reading heterogeneous references. See [`id_from_parts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnyId {
+    User(UserId),
+    Album(AlbumId),
+    Artist(ArtistId),
+    Playlist(PlaylistId),
+    Track(TrackId),
+    Show(ShowId),
+    Episode(EpisodeId),
+}
+
+impl AnyId {
+    /// The base-62 identifier of the resource.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::User(id) => id.id(),
+            Self::Album(id) => id.id(),
+            Self::Artist(id) => id.id(),
+            Self::Playlist(id) => id.id(),
+            Self::Track(id) => id.id(),
+            Self::Show(id) => id.id(),
+            Self::Episode(id) => id.id(),
+        }
+    }
+
+    /// The type of the resource.
+    pub fn _type(&self) -> IdType {
+        match self {
+            Self::User(_) => IdType::User,
+            Self::Album(_) => IdType::Album,
+            Self::Artist(_) => IdType::Artist,
+            Self::Playlist(_) => IdType::Playlist,
+            Self::Track(_) => IdType::Track,
+            Self::Show(_) => IdType::Show,
+            Self::Episode(_) => IdType::Episode,
+        }
+    }
+
+    /// The resource identifier of the resource.
+    pub fn uri(&self) -> String {
+        format!("spotify:{}:{}", self._type(), self.id())
+    }
+}
+
+/// Creates a typed ID from an item type and a base-62 Spotify identifier.
+///
+/// # Errors
+/// Returns [`IdError::UnsupportedItemType`] if items of the given type have no ID type, or
+/// any error from validating the ID itself.
+pub fn id_from_parts(item_type: ItemType, id: &str) -> Result<AnyId, IdError> {
+    any_id(&IdType::try_from(item_type)?, id)
+}
+
+/// Parses a typed ID from a Spotify share link or URI.
+///
+/// Share links such as `https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6?si=...`, including
+/// localized `intl-*` links, and URIs such as `spotify:track:6rqhFgbbKwnb9MLmUQDhG6` are
+/// accepted.
+///
+/// # Errors
+/// Returns [`IdError::InvalidFormat`] if the input is not a Spotify share link or URI for a
+/// type of item with an ID, or any error from validating the ID itself.
+pub fn id_from_url(url: &str) -> Result<AnyId, IdError> {
+    if let Some(uri) = url.strip_prefix("spotify:") {
+        let (id_type, id) = uri.split_once(':').ok_or(IdError::InvalidFormat)?;
+        return any_id(&parse_id_type(id_type)?, id);
+    }
+
+    let url = Url::parse(url).map_err(|_e| IdError::InvalidFormat)?;
+    if url.host_str() != Some("open.spotify.com") {
+        return Err(IdError::InvalidFormat);
+    }
+
+    // The type and ID are the last two segments, after any `intl-*` or legacy `user/{id}` prefix.
+    let mut segments = url
+        .path_segments()
+        .ok_or(IdError::InvalidFormat)?
+        .filter(|segment| !segment.is_empty())
+        .rev();
+    let id = segments.next().ok_or(IdError::InvalidFormat)?;
+    let id_type = segments.next().ok_or(IdError::InvalidFormat)?;
+
+    any_id(&parse_id_type(id_type)?, id)
+}
+
+fn parse_id_type(s: &str) -> Result<IdType, IdError> {
+    match s {
+        "user" => Ok(IdType::User),
+        "album" => Ok(IdType::Album),
+        "artist" => Ok(IdType::Artist),
+        "playlist" => Ok(IdType::Playlist),
+        "track" => Ok(IdType::Track),
+        "show" => Ok(IdType::Show),
+        "episode" => Ok(IdType::Episode),
+        _ => Err(IdError::InvalidFormat),
+    }
+}
+
+fn any_id(id_type: &IdType, id: &str) -> Result<AnyId, IdError> {
+    Ok(match id_type {
+        IdType::User => AnyId::User(UserId::from_id(id)?),
+        IdType::Album => AnyId::Album(AlbumId::from_id(id)?),
+        IdType::Artist => AnyId::Artist(ArtistId::from_id(id)?),
+        IdType::Playlist => AnyId::Playlist(PlaylistId::from_id(id)?),
+        IdType::Track => AnyId::Track(TrackId::from_id(id)?),
+        IdType::Show => AnyId::Show(ShowId::from_id(id)?),
+        IdType::Episode => AnyId::Episode(EpisodeId::from_id(id)?),
+    })
+}
+
 /// A playback context type with its associated ID.
 ///
 /// Represents items that can be used as a playback context (the source from
@@ -143,6 +308,27 @@ macro_rules! impl_ids {
 					}
 				}
 
+                /// Parses an ID from a bare ID, a Spotify URI or an `open.spotify.com` share link.
+                ///
+                /// Query strings such as `?si=...` on share links are ignored.
+                ///
+                /// # Errors
+                /// Returns [`IdError::TypeMismatch`] if the URI or link is for another type of item,
+                /// or any error from [`id_from_url`] or from validating the ID itself.
+                pub fn parse(s: &str) -> Result<Self, IdError> {
+                    if !s.contains([':', '/']) {
+                        return Self::from_id(s);
+                    }
+
+                    match id_from_url(s)? {
+                        AnyId::$id_type(id) => Ok(id),
+                        other => Err(IdError::TypeMismatch {
+                            expected: IdType::$id_type,
+                            got: other._type(),
+                        }),
+                    }
+                }
+
                 /// The base-62 identifier found at the end of the Spotify URI (see above) for an artist, track, album, playlist, etc.
                 /// Unlike a Spotify URI, a Spotify ID does not clearly identify the type of resource; that information is provided elsewhere in the call.
                 pub fn id(&self) -> &str {
@@ -159,6 +345,28 @@ macro_rules! impl_ids {
         			format!("spotify:{}:{}", self._type(), self.id())
     			}
             }
+
+            impl TryFrom<&str> for $struct_name {
+                type Error = IdError;
+
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    Self::parse(s)
+                }
+            }
+
+            impl std::str::FromStr for $struct_name {
+                type Err = IdError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::from_id(s)
+                }
+            }
+
+            impl std::fmt::Display for $struct_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.id())
+                }
+            }
         )*
     }
 }
@@ -222,4 +430,118 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_id_from_parts() {
+        let id = id_from_parts(ItemType::Track, "6rqhFgbbKwnb9MLmUQDhG6").unwrap();
+        assert_eq!(
+            id,
+            AnyId::Track(TrackId::from_id("6rqhFgbbKwnb9MLmUQDhG6").unwrap())
+        );
+        assert_eq!(id.uri(), "spotify:track:6rqhFgbbKwnb9MLmUQDhG6");
+
+        let id = id_from_parts(ItemType::Playlist, "37i9dQZF1DXcBWIGoYBM5M").unwrap();
+        assert_eq!(
+            id,
+            AnyId::Playlist(PlaylistId::from_id("37i9dQZF1DXcBWIGoYBM5M").unwrap())
+        );
+        assert_eq!(id._type(), IdType::Playlist);
+
+        assert_eq!(
+            id_from_parts(ItemType::Chapter, "0IsXVP0JmcB2adSE338GkK"),
+            Err(IdError::UnsupportedItemType(ItemType::Chapter))
+        );
+    }
+
+    #[test]
+    fn test_id_from_url() {
+        let track = AnyId::Track(TrackId::from_id("6rqhFgbbKwnb9MLmUQDhG6").unwrap());
+
+        for url in [
+            "https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6",
+            "https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6?si=1a2b3c4d5e6f",
+            "https://open.spotify.com/intl-de/track/6rqhFgbbKwnb9MLmUQDhG6",
+            "spotify:track:6rqhFgbbKwnb9MLmUQDhG6",
+        ] {
+            assert_eq!(id_from_url(url).unwrap(), track, "{url}");
+        }
+
+        assert_eq!(
+            id_from_url("https://open.spotify.com/user/smedjan/playlist/3cEYpjA9oz9GiPac4AsH4n")
+                .unwrap(),
+            AnyId::Playlist(PlaylistId::from_id("3cEYpjA9oz9GiPac4AsH4n").unwrap())
+        );
+
+        for url in [
+            "https://example.com/track/6rqhFgbbKwnb9MLmUQDhG6",
+            "https://open.spotify.com/genre/6rqhFgbbKwnb9MLmUQDhG6",
+            "https://open.spotify.com/",
+            "spotify:track",
+            "not a url",
+        ] {
+            assert_eq!(id_from_url(url), Err(IdError::InvalidFormat), "{url}");
+        }
+    }
+
+    #[test]
+    fn test_id_parse() {
+        let track = TrackId::from_id("6rqhFgbbKwnb9MLmUQDhG6").unwrap();
+
+        for s in [
+            "https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6?si=1a2b3c4d5e6f",
+            "spotify:track:6rqhFgbbKwnb9MLmUQDhG6",
+            "6rqhFgbbKwnb9MLmUQDhG6",
+        ] {
+            assert_eq!(TrackId::parse(s).unwrap(), track, "{s}");
+            assert_eq!(TrackId::try_from(s).unwrap(), track, "{s}");
+        }
+
+        assert_eq!(
+            TrackId::parse("https://open.spotify.com/album/6rqhFgbbKwnb9MLmUQDhG6"),
+            Err(IdError::TypeMismatch {
+                expected: IdType::Track,
+                got: IdType::Album,
+            })
+        );
+        assert_eq!(
+            AlbumId::try_from("spotify:track:6rqhFgbbKwnb9MLmUQDhG6"),
+            Err(IdError::TypeMismatch {
+                expected: IdType::Album,
+                got: IdType::Track,
+            })
+        );
+        assert_eq!(
+            TrackId::parse("6rqhFgbb"),
+            Err(IdError::InvalidLength {
+                got: 8,
+                expected: 22,
+            })
+        );
+    }
+
+    #[test]
+    fn test_id_display_and_from_str() {
+        let track = "6rqhFgbbKwnb9MLmUQDhG6".parse::<TrackId>().unwrap();
+        assert_eq!(track, TrackId::from_id("6rqhFgbbKwnb9MLmUQDhG6").unwrap());
+        assert_eq!(track.to_string(), track.id());
+        assert_eq!(track.to_string().parse::<TrackId>().unwrap(), track);
+
+        let user = "smedjan".parse::<UserId>().unwrap();
+        assert_eq!(user.to_string(), "smedjan");
+
+        assert_eq!(
+            "spotify:track:6rqhFgbbKwnb9MLmUQDhG6".parse::<TrackId>(),
+            Err(IdError::InvalidLength {
+                got: 36,
+                expected: 22,
+            })
+        );
+    }
+
+    #[test]
+    fn test_item_type_conversions() {
+        assert_eq!(ItemType::Track.to_id_type(), Some(IdType::Track));
+        assert_eq!(ItemType::Collection.to_id_type(), None);
+        assert_eq!(ItemType::from(IdType::Show), ItemType::Show);
+    }
 }
@@ -1,7 +1,123 @@
 use serde::{Deserialize, Serialize};
+use std::{borrow::Borrow, fmt, str::FromStr};
+use thiserror::Error;
 
 /// An [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country code.
-pub type Market = String;
+///
+/// Markets created with [`Market::from_country_code`] or [`str::parse`] are checked to be
+/// one of the codes known to [`country_name`]. Markets converted from a string with [`From`],
+/// and markets returned by Spotify, are taken as is, so that newly launched markets can be
+/// used; [`Market::validate`] checks them later if needed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Market(String);
+
+/// Errors that can occur when parsing a [`Market`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum MarketError {
+    #[error("The country code must be two uppercase letters, got {0:?}.")]
+    InvalidFormat(String),
+
+    #[error("{0} is not a known country code.")]
+    UnknownCountry(String),
+}
+
+impl Market {
+    /// Creates a market from a two-letter ISO 3166-1 alpha-2 country code, such as `"US"`.
+    ///
+    /// # Errors
+    /// Returns [`MarketError::InvalidFormat`] if the code is not two uppercase letters.
+    /// Returns [`MarketError::UnknownCountry`] if the code is not a known country.
+    pub fn from_country_code(code: &str) -> Result<Self, MarketError> {
+        let market = Self(code.to_owned());
+        market.validate()?;
+        Ok(market)
+    }
+
+    /// Checks that the market is a known country code, as [`Market::from_country_code`] does.
+    ///
+    /// # Errors
+    /// See [`Market::from_country_code`].
+    pub fn validate(&self) -> Result<(), MarketError> {
+        let code = self.as_str();
+        if code.len() != 2 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(MarketError::InvalidFormat(code.to_owned()));
+        }
+
+        if country_name(code) == "Unknown" {
+            return Err(MarketError::UnknownCountry(code.to_owned()));
+        }
+
+        Ok(())
+    }
+
+    /// The country code of the market.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The name of the market's country, see [`country_name`].
+    pub fn country_name(&self) -> &'static str {
+        country_name(&self.0)
+    }
+}
+
+impl FromStr for Market {
+    type Err = MarketError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::from_country_code(code)
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Market {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Market {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Market {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Market {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<&str> for Market {
+    fn from(code: &str) -> Self {
+        Self(code.to_owned())
+    }
+}
+
+impl From<String> for Market {
+    fn from(code: String) -> Self {
+        Self(code)
+    }
+}
+
+impl From<Market> for String {
+    fn from(market: Market) -> Self {
+        market.0
+    }
+}
 
 /// Returns the country name for a given ISO 3166-1 alpha-2 country code.
 ///
@@ -207,6 +323,13 @@ pub struct Markets {
     pub markets: Vec<Market>,
 }
 
+impl Markets {
+    /// Whether Spotify is available in the market with the given country code.
+    pub fn contains(&self, country_code: &str) -> bool {
+        self.markets.iter().any(|market| market == country_code)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +353,59 @@ mod tests {
 
         crate::test::assert_deserialized!(Markets, json);
     }
+
+    #[test]
+    fn markets_contains() {
+        let markets: Markets = serde_json::from_str(r#"{"markets": ["CA", "BR", "IT"]}"#).unwrap();
+        assert!(markets.contains("BR"));
+        assert!(!markets.contains("US"));
+        assert!(!markets.contains("br"));
+    }
+
+    #[test]
+    fn market_from_country_code() {
+        let us = Market::from_country_code("US").unwrap();
+        assert_eq!(us.as_str(), "US");
+        assert_eq!(us.to_string(), "US");
+        assert_eq!(us.country_name(), "United States");
+
+        let se: Market = "SE".parse().unwrap();
+        assert_eq!(se, "SE");
+        assert_eq!(se.country_name(), "Sweden");
+
+        assert_eq!(
+            Market::from_country_code("USA"),
+            Err(MarketError::InvalidFormat("USA".to_owned()))
+        );
+        assert_eq!(
+            Market::from_country_code("zz"),
+            Err(MarketError::InvalidFormat("zz".to_owned()))
+        );
+        assert_eq!(
+            Market::from_country_code("ZZ"),
+            Err(MarketError::UnknownCountry("ZZ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn market_from_string() {
+        let market = Market::from("ES");
+        assert_eq!(market, Market::from("ES".to_owned()));
+        assert_eq!(market.validate(), Ok(()));
+
+        // Conversions are not checked until asked to.
+        let unknown = Market::from("ZZ");
+        assert_eq!(unknown.as_str(), "ZZ");
+        assert_eq!(
+            unknown.validate(),
+            Err(MarketError::UnknownCountry("ZZ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn market_serde() {
+        let market: Market = serde_json::from_str(r#""SE""#).unwrap();
+        assert_eq!(market, Market::from_country_code("SE").unwrap());
+        assert_eq!(serde_json::to_string(&market).unwrap(), r#""SE""#);
+    }
 }
@@ -1,8 +1,14 @@
-use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
+use thiserror::Error;
 
 /// The type of a Spotify item.
+///
+/// This enum is marked as `#[non_exhaustive]`, meaning new variants may be added in future versions.
+/// When matching against it, include a wildcard arm (`_`) to account for any future variants.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum ItemType {
     User,
     Album,
@@ -15,6 +21,8 @@ pub enum ItemType {
     Unknown,
     Chapter,
     Collection,
+    #[serde(rename = "audio_features")]
+    AudioFeatures,
 }
 
 impl std::fmt::Display for ItemType {
@@ -31,6 +39,7 @@ impl std::fmt::Display for ItemType {
             Self::Unknown => "unknown",
             Self::Chapter => "chapter",
             Self::Collection => "collection",
+            Self::AudioFeatures => "audio_features",
         };
 
         write!(f, "{s}")
@@ -74,6 +83,9 @@ pub struct VideoThumbnail {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ExternalUrls {
     /// The [Spotify URL](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) for the object.
+    ///
+    /// This is empty when Spotify omits it; use [`SpotifyObject::open_url`] for a URL which is always available.
+    #[serde(default)]
     pub spotify: String,
 }
 
@@ -104,7 +116,9 @@ pub struct Followers {
 }
 
 /// The precision of a release date.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// Precisions are ordered from the least to the most precise.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "lowercase")]
 pub enum ReleaseDatePrecision {
     Year,
@@ -112,6 +126,79 @@ pub enum ReleaseDatePrecision {
     Day,
 }
 
+/// A release date, known to the precision Spotify reports for it.
+///
+/// Parse one from an item with `release_date_parsed`, for example
+/// [`Album::release_date_parsed`](super::Album::release_date_parsed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReleaseDate {
+    /// The year of release.
+    pub year: i32,
+
+    /// The month of release, from 1 to 12, when it is known.
+    pub month: Option<u32>,
+
+    /// The day of the month of release, when it is known.
+    pub day: Option<u32>,
+}
+
+impl ReleaseDate {
+    /// Parses a `release_date` such as `"1981"`, `"1981-12"` or `"1981-12-15"`.
+    ///
+    /// When `precision` is given the date is truncated to it, and `None` is returned if the
+    /// date is less precise than claimed. Otherwise the precision is taken from the date itself.
+    /// Dates which do not exist in the calendar, such as `"1981-02-30"`, are rejected.
+    pub fn parse(date: &str, precision: Option<&ReleaseDatePrecision>) -> Option<Self> {
+        let mut parts = date.splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next().map(str::parse).transpose().ok()?;
+        let day = parts.next().map(str::parse).transpose().ok()?;
+
+        let (month, day) = match precision {
+            None => (month, day),
+            Some(ReleaseDatePrecision::Year) => (None, None),
+            Some(ReleaseDatePrecision::Month) => (Some(month?), None),
+            Some(ReleaseDatePrecision::Day) => (Some(month?), Some(day?)),
+        };
+
+        NaiveDate::from_ymd_opt(year, month.unwrap_or(1), day.unwrap_or(1))?;
+
+        Some(Self { year, month, day })
+    }
+
+    /// The precision with which this date is known.
+    pub fn precision(&self) -> ReleaseDatePrecision {
+        match (self.month, self.day) {
+            (Some(_), Some(_)) => ReleaseDatePrecision::Day,
+            (Some(_), None) => ReleaseDatePrecision::Month,
+            (None, _) => ReleaseDatePrecision::Year,
+        }
+    }
+
+    /// Converts the date to a [`NaiveDate`], if it is known to the day.
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month?, self.day?)
+    }
+}
+
+/// A sort key for a release date of any precision.
+///
+/// The date is parsed with [`ReleaseDate::parse`], and missing months and days are treated as
+/// the first of the period, so `"1981"` sorts as `1981-01-01`, with the less precise date first
+/// when two dates are otherwise equal. Dates which cannot be parsed sort before all others.
+pub(crate) fn release_date_key(
+    date: Option<&str>,
+    precision: Option<&ReleaseDatePrecision>,
+) -> Option<(i32, u32, u32, ReleaseDatePrecision)> {
+    let date = ReleaseDate::parse(date?, precision)?;
+    Some((
+        date.year,
+        date.month.unwrap_or(1),
+        date.day.unwrap_or(1),
+        date.precision(),
+    ))
+}
+
 /// Content restrictions applied to an item.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Restrictions {
@@ -167,6 +254,74 @@ pub struct Page<T> {
     pub items: Vec<T>,
 }
 
+impl<T> Page<T> {
+    /// Returns an iterator over the items of this page.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Consumes the page, returning its items.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Transforms the items of this page, keeping its paging information.
+    pub fn map<U, F>(self, f: F) -> Page<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Page {
+            href: self.href,
+            limit: self.limit,
+            next: self.next,
+            offset: self.offset,
+            previous: self.previous,
+            total: self.total,
+            items: self.items.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Whether there is a page of items after this one.
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+}
+
+impl<T> Page<Option<T>> {
+    /// Drops the `null` items of this page, such as those Spotify returns in search results.
+    ///
+    /// The paging information is kept as is, so `total` still counts the dropped items.
+    pub fn flatten(self) -> Page<T> {
+        Page {
+            href: self.href,
+            limit: self.limit,
+            next: self.next,
+            offset: self.offset,
+            previous: self.previous,
+            total: self.total,
+            items: self.items.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
 /// Cursors for cursor-based pagination.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Cursors {
@@ -190,18 +345,64 @@ pub struct ResumePoint {
 }
 
 /// A currently playing item, which can be either a track or an episode.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// The variant is chosen using the item's `type` field.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
 pub enum TrackItem {
     Track(super::Track),
+    #[cfg(feature = "shows")]
     Episode(super::Episode),
 }
 
+impl<'de> Deserialize<'de> for TrackItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match item_type_of::<D::Error>(&value)? {
+            ItemType::Track => serde_json::from_value(value).map(Self::Track),
+            #[cfg(feature = "shows")]
+            ItemType::Episode => serde_json::from_value(value).map(Self::Episode),
+            #[cfg(not(feature = "shows"))]
+            ItemType::Episode => {
+                return Err(D::Error::custom(
+                    "episodes are only supported with the `shows` feature",
+                ));
+            }
+            other => return Err(D::Error::custom(format!("unexpected item type: {other}"))),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+/// Reads the `type` field of an item, which is used to pick the variant of an item enum.
+fn item_type_of<E>(value: &serde_json::Value) -> Result<ItemType, E>
+where
+    E: serde::de::Error,
+{
+    let item_type = value.get("type").ok_or_else(|| E::missing_field("type"))?;
+    ItemType::deserialize(item_type).map_err(E::custom)
+}
+
+/// An error returned when an item is not of the expected type.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("expected an item of type {expected}, got {got}")]
+pub struct TypeMismatch {
+    /// The type which was expected.
+    pub expected: ItemType,
+
+    /// The type of the item.
+    pub got: ItemType,
+}
+
 impl TrackItem {
     pub fn id(&self) -> &str {
         match &self {
             Self::Track(track) => track.id.as_str(),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.id.as_str(),
         }
     }
@@ -209,6 +410,7 @@ impl TrackItem {
     pub fn name(&self) -> &str {
         match &self {
             Self::Track(track) => track.name.as_str(),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.name.as_str(),
         }
     }
@@ -216,6 +418,7 @@ impl TrackItem {
     pub fn is_playable(&self) -> bool {
         match &self {
             Self::Track(track) => track.is_playable.unwrap_or(false),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.is_playable,
         }
     }
@@ -223,6 +426,7 @@ impl TrackItem {
     pub fn duration_ms(&self) -> u32 {
         match &self {
             Self::Track(track) => track.duration_ms,
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.duration_ms,
         }
     }
@@ -230,6 +434,7 @@ impl TrackItem {
     pub fn external_url(&self) -> &str {
         match &self {
             Self::Track(track) => track.external_urls.spotify.as_str(),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.external_urls.spotify.as_str(),
         }
     }
@@ -237,6 +442,7 @@ impl TrackItem {
     pub fn href(&self) -> &str {
         match &self {
             Self::Track(track) => track.href.as_str(),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.href.as_str(),
         }
     }
@@ -244,6 +450,7 @@ impl TrackItem {
     pub fn restrictions(&self) -> Option<&Restrictions> {
         match &self {
             Self::Track(track) => track.restrictions.as_ref(),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.restrictions.as_ref(),
         }
     }
@@ -251,6 +458,7 @@ impl TrackItem {
     pub fn explicit(&self) -> bool {
         match &self {
             Self::Track(track) => track.explicit,
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.explicit,
         }
     }
@@ -258,11 +466,231 @@ impl TrackItem {
     pub fn uri(&self) -> &str {
         match &self {
             Self::Track(track) => track.uri.as_str(),
+            #[cfg(feature = "shows")]
             Self::Episode(episode) => episode.uri.as_str(),
         }
     }
 }
 
+/// A catalog object of any type with an ID, as returned by `get_by_url` on the clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum SpotifyResource {
+    Track(super::Track),
+    Album(super::Album),
+    Artist(super::Artist),
+    Playlist(super::Playlist),
+    #[cfg(feature = "shows")]
+    Show(super::Show),
+    #[cfg(feature = "shows")]
+    Episode(super::Episode),
+}
+
+/// Availability of an item for playback.
+///
+/// Spotify signals availability through a combination of the `is_playable` and
+/// `restrictions` fields, which are only present in some responses (for example when
+/// a `market` is supplied and [Track Relinking](https://developer.spotify.com/documentation/web-api/concepts/track-relinking)
+/// is applied). This trait centralizes the interpretation of those fields.
+pub trait Playable {
+    /// Returns `true` if the item can be played.
+    ///
+    /// An item is available when `is_playable` is `true`, or when `is_playable` is absent
+    /// and no restriction applies. It is unavailable otherwise.
+    fn is_available(&self) -> bool;
+}
+
+/// An object from the Spotify catalog or a Spotify user, identified by its type and ID.
+pub trait SpotifyObject {
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/concepts/spotify-uris-ids) of the object.
+    fn spotify_id(&self) -> &str;
+
+    /// The type of the object.
+    fn item_type(&self) -> &ItemType;
+
+    /// The shareable `https://open.spotify.com/{type}/{id}` URL of the object.
+    ///
+    /// Unlike `external_urls`, this is computed from the type and ID, so it is available even
+    /// when `external_urls` is empty.
+    fn open_url(&self) -> String {
+        format!(
+            "https://open.spotify.com/{}/{}",
+            self.item_type(),
+            self.spotify_id()
+        )
+    }
+}
+
+macro_rules! impl_spotify_object {
+    ($($type:ident),+ $(,)?) => {
+        $(
+            impl SpotifyObject for super::$type {
+                fn spotify_id(&self) -> &str {
+                    &self.id
+                }
+
+                fn item_type(&self) -> &ItemType {
+                    &self.type_
+                }
+            }
+        )+
+    };
+}
+
+impl_spotify_object!(
+    Album,
+    SimplifiedAlbum,
+    Artist,
+    SimplifiedArtist,
+    Playlist,
+    SimplifiedPlaylist,
+    Track,
+    SimplifiedTrack,
+    CurrentUserProfile,
+    UserProfile,
+    UserReference,
+);
+
+#[cfg(feature = "audiobooks")]
+impl_spotify_object!(Audiobook, SimplifiedAudiobook);
+
+#[cfg(feature = "shows")]
+impl_spotify_object!(Episode, SimplifiedEpisode, Show, SimplifiedShow);
+
+macro_rules! impl_batch {
+    ($($type:ident { $field:ident: $item:ident }),+ $(,)?) => {
+        $(
+            impl super::$type {
+                /// Returns an iterator over the items, in the order they were requested.
+                pub fn iter(&self) -> std::slice::Iter<'_, Option<super::$item>> {
+                    self.$field.iter()
+                }
+
+                /// Returns an iterator over the items which were found, skipping the `null`
+                /// entries Spotify returns for unknown IDs.
+                pub fn present(&self) -> impl Iterator<Item = &super::$item> {
+                    self.$field.iter().flatten()
+                }
+            }
+
+            impl IntoIterator for super::$type {
+                type Item = Option<super::$item>;
+                type IntoIter = std::vec::IntoIter<Option<super::$item>>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.$field.into_iter()
+                }
+            }
+
+            impl<'a> IntoIterator for &'a super::$type {
+                type Item = &'a Option<super::$item>;
+                type IntoIter = std::slice::Iter<'a, Option<super::$item>>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.iter()
+                }
+            }
+        )+
+    };
+}
+
+impl_batch!(
+    Albums { albums: Album },
+    Artists { artists: Artist },
+    SeveralAudioFeatures {
+        audio_features: AudioFeatures
+    },
+    Tracks { tracks: Track },
+);
+
+#[cfg(feature = "audiobooks")]
+impl_batch!(
+    Audiobooks {
+        audiobooks: Audiobook
+    },
+    Chapters { chapters: Chapter },
+);
+
+#[cfg(feature = "shows")]
+impl_batch!(Episodes { episodes: Episode });
+
+fn is_available(is_playable: Option<bool>, restrictions: Option<&Restrictions>) -> bool {
+    is_playable.unwrap_or(restrictions.is_none())
+}
+
+impl Playable for super::Track {
+    fn is_available(&self) -> bool {
+        is_available(self.is_playable, self.restrictions.as_ref())
+    }
+}
+
+impl Playable for super::SimplifiedTrack {
+    fn is_available(&self) -> bool {
+        is_available(self.is_playable, self.restrictions.as_ref())
+    }
+}
+
+#[cfg(feature = "shows")]
+impl Playable for super::Episode {
+    fn is_available(&self) -> bool {
+        is_available(Some(self.is_playable), self.restrictions.as_ref())
+    }
+}
+
+#[cfg(feature = "shows")]
+impl Playable for super::SimplifiedEpisode {
+    fn is_available(&self) -> bool {
+        is_available(Some(self.is_playable), self.restrictions.as_ref())
+    }
+}
+
+#[cfg(feature = "audiobooks")]
+impl Playable for super::Chapter {
+    fn is_available(&self) -> bool {
+        is_available(self.is_playable, self.restrictions.as_ref())
+    }
+}
+
+#[cfg(feature = "audiobooks")]
+impl Playable for super::SimplifiedChapter {
+    fn is_available(&self) -> bool {
+        is_available(self.is_playable, self.restrictions.as_ref())
+    }
+}
+
+impl Playable for TrackItem {
+    fn is_available(&self) -> bool {
+        match self {
+            Self::Track(track) => track.is_available(),
+            #[cfg(feature = "shows")]
+            Self::Episode(episode) => episode.is_available(),
+        }
+    }
+}
+
+impl<T> Playable for &T
+where
+    T: Playable,
+{
+    fn is_available(&self) -> bool {
+        (*self).is_available()
+    }
+}
+
+/// Keeps only the items that are available for playback.
+///
+/// See [`Playable::is_available`] for how availability is determined.
+pub fn filter_playable<I, T>(items: I) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    T: Playable,
+{
+    items
+        .into_iter()
+        .filter(|item| item.is_available())
+        .collect()
+}
+
 /// Time range for fetching user's top items.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -306,7 +734,9 @@ impl std::fmt::Display for TopItemType {
 }
 
 /// A user's top item, which can be either an artist or a track.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// The variant is chosen using the item's `type` field.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
 pub enum TopItem {
@@ -314,6 +744,59 @@ pub enum TopItem {
     Track(super::Track),
 }
 
+impl TopItem {
+    /// The type of the item.
+    pub fn item_type(&self) -> ItemType {
+        match self {
+            Self::Artist(_) => ItemType::Artist,
+            Self::Track(_) => ItemType::Track,
+        }
+    }
+
+    /// Converts the item into an artist.
+    ///
+    /// # Errors
+    /// Returns a [`TypeMismatch`] if the item is a track.
+    pub fn into_artist(self) -> Result<super::Artist, TypeMismatch> {
+        match self {
+            Self::Artist(artist) => Ok(artist),
+            Self::Track(_) => Err(TypeMismatch {
+                expected: ItemType::Artist,
+                got: ItemType::Track,
+            }),
+        }
+    }
+
+    /// Converts the item into a track.
+    ///
+    /// # Errors
+    /// Returns a [`TypeMismatch`] if the item is an artist.
+    pub fn into_track(self) -> Result<super::Track, TypeMismatch> {
+        match self {
+            Self::Track(track) => Ok(track),
+            Self::Artist(_) => Err(TypeMismatch {
+                expected: ItemType::Track,
+                got: ItemType::Artist,
+            }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TopItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match item_type_of::<D::Error>(&value)? {
+            ItemType::Artist => serde_json::from_value(value).map(Self::Artist),
+            ItemType::Track => serde_json::from_value(value).map(Self::Track),
+            other => return Err(D::Error::custom(format!("unexpected item type: {other}"))),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
 /// A paginated list of the user's top items.
 pub type TopItems = Page<TopItem>;
 
@@ -367,3 +850,251 @@ impl std::fmt::Display for IncludeExternalType {
         write!(f, "{s}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::Track, test::fixtures};
+    use serde_json::json;
+
+    fn track(id: &str, is_playable: Option<bool>, restricted: bool, relinked: bool) -> Track {
+        let mut track = fixtures::track(id);
+
+        if let Some(is_playable) = is_playable {
+            track["is_playable"] = is_playable.into();
+        }
+
+        if restricted {
+            track["restrictions"] = json!({ "reason": "market" });
+        }
+
+        if relinked {
+            track["linked_from"] = json!({ "id": "original", "type": "track" });
+        }
+
+        serde_json::from_value(track).unwrap()
+    }
+
+    #[test]
+    fn playable_availability() {
+        assert!(track("a", None, false, false).is_available());
+        assert!(track("b", Some(true), false, true).is_available());
+        assert!(!track("c", Some(false), false, false).is_available());
+        assert!(!track("d", None, true, false).is_available());
+        assert!(!track("e", Some(false), true, true).is_available());
+        assert!(TrackItem::Track(track("f", Some(true), false, true)).is_available());
+    }
+
+    #[test]
+    fn filter_playable_tracks() {
+        let tracks = vec![
+            track("a", None, false, false),
+            track("b", Some(true), false, true),
+            track("c", Some(false), false, false),
+            track("d", None, true, false),
+        ];
+
+        let ids = filter_playable(&tracks)
+            .into_iter()
+            .map(|track| track.id.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(filter_playable(tracks).len(), 2);
+    }
+
+    #[test]
+    fn iterate_batch() {
+        let tracks = crate::model::Tracks {
+            tracks: vec![
+                Some(track("a", None, false, false)),
+                None,
+                Some(track("b", None, false, false)),
+            ],
+        };
+
+        let ids = tracks
+            .present()
+            .map(|track| track.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["a", "b"]);
+
+        assert_eq!(tracks.iter().filter(|t| t.is_none()).count(), 1);
+
+        let mut count = 0;
+        for track in tracks {
+            count += 1;
+            if count == 2 {
+                assert!(track.is_none());
+            }
+        }
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn open_url() {
+        let track = track("11dFghVXANMlKmJXsNCbNl", None, false, false);
+        assert_eq!(
+            track.open_url(),
+            "https://open.spotify.com/track/11dFghVXANMlKmJXsNCbNl"
+        );
+
+        let playlist: crate::model::SimplifiedPlaylist = serde_json::from_value(json!({
+            "collaborative": false,
+            "description": null,
+            "external_urls": {},
+            "href": "string",
+            "id": "3cEYpjA9oz9GiPac4AsH4n",
+            "images": null,
+            "name": "string",
+            "owner": {
+                "external_urls": {},
+                "href": "string",
+                "id": "smedjan",
+                "type": "user",
+                "uri": "spotify:user:smedjan"
+            },
+            "primary_color": null,
+            "public": null,
+            "snapshot_id": "string",
+            "tracks": null,
+            "type": "playlist",
+            "uri": "spotify:playlist:3cEYpjA9oz9GiPac4AsH4n"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            playlist.open_url(),
+            "https://open.spotify.com/playlist/3cEYpjA9oz9GiPac4AsH4n"
+        );
+        assert_eq!(
+            playlist.owner.open_url(),
+            "https://open.spotify.com/user/smedjan"
+        );
+    }
+
+    #[test]
+    fn top_item_uses_type_field() {
+        let artist: TopItem = serde_json::from_str(fixtures::ARTIST).unwrap();
+        assert_eq!(artist.item_type(), ItemType::Artist);
+        assert_eq!(artist.clone().into_artist().unwrap().name, "Pitbull");
+        assert_eq!(
+            artist.into_track(),
+            Err(TypeMismatch {
+                expected: ItemType::Track,
+                got: ItemType::Artist
+            })
+        );
+
+        // A track carrying every field of an artist is still a track.
+        let mut value =
+            serde_json::to_value(track("11dFghVXANMlKmJXsNCbNl", None, false, false)).unwrap();
+        value["followers"] = json!({ "href": null, "total": 0 });
+        value["genres"] = json!([]);
+        value["images"] = json!([]);
+
+        let track: TopItem = serde_json::from_value(value).unwrap();
+        assert_eq!(track.item_type(), ItemType::Track);
+        assert_eq!(track.into_track().unwrap().id, "11dFghVXANMlKmJXsNCbNl");
+    }
+
+    fn page<T>(items: Vec<T>) -> Page<T> {
+        Page {
+            href: "https://api.spotify.com/v1/search?offset=20&limit=3".to_owned(),
+            limit: 3,
+            next: Some("https://api.spotify.com/v1/search?offset=23&limit=3".to_owned()),
+            offset: 20,
+            previous: None,
+            total: 30,
+            items,
+        }
+    }
+
+    #[test]
+    fn page_map() {
+        let mapped = page(vec![1, 2, 3]).map(|item| item.to_string());
+
+        assert_eq!(
+            mapped,
+            page(vec!["1".to_owned(), "2".to_owned(), "3".to_owned()])
+        );
+        assert!(mapped.has_next());
+        assert_eq!(mapped.iter().count(), 3);
+        assert_eq!(mapped.into_items(), ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn page_flatten() {
+        let flattened = page(vec![Some(1), None, Some(3)]).flatten();
+
+        assert_eq!(flattened, page(vec![1, 3]));
+        assert_eq!(flattened.total, 30);
+        assert!(page(Vec::<Option<u8>>::new()).flatten().items.is_empty());
+
+        let mut last = page(vec![None::<u8>]);
+        last.next = None;
+        assert!(!last.flatten().has_next());
+    }
+
+    #[test]
+    fn release_date() {
+        let year = ReleaseDate::parse("1981", Some(&ReleaseDatePrecision::Year)).unwrap();
+        assert_eq!(
+            year,
+            ReleaseDate {
+                year: 1981,
+                month: None,
+                day: None
+            }
+        );
+        assert_eq!(year.precision(), ReleaseDatePrecision::Year);
+        assert_eq!(year.to_naive_date(), None);
+
+        let month = ReleaseDate::parse("1981-12", Some(&ReleaseDatePrecision::Month)).unwrap();
+        assert_eq!(
+            month,
+            ReleaseDate {
+                year: 1981,
+                month: Some(12),
+                day: None
+            }
+        );
+        assert_eq!(month.precision(), ReleaseDatePrecision::Month);
+        assert_eq!(month.to_naive_date(), None);
+
+        let day = ReleaseDate::parse("1981-12-15", Some(&ReleaseDatePrecision::Day)).unwrap();
+        assert_eq!(day.precision(), ReleaseDatePrecision::Day);
+        assert_eq!(day.to_naive_date(), NaiveDate::from_ymd_opt(1981, 12, 15));
+        assert_eq!(ReleaseDate::parse("1981-12-15", None), Some(day));
+    }
+
+    #[test]
+    fn release_date_precision_mismatch() {
+        assert_eq!(
+            ReleaseDate::parse("1981-12-15", Some(&ReleaseDatePrecision::Year))
+                .unwrap()
+                .precision(),
+            ReleaseDatePrecision::Year
+        );
+        assert_eq!(
+            ReleaseDate::parse("1981", Some(&ReleaseDatePrecision::Day)),
+            None
+        );
+        assert_eq!(ReleaseDate::parse("1981-02-30", None), None);
+        assert_eq!(ReleaseDate::parse("0000-00-00", None), None);
+        assert_eq!(ReleaseDate::parse("", None), None);
+    }
+
+    #[test]
+    fn release_date_key_agrees_with_parse() {
+        let day = Some(&ReleaseDatePrecision::Day);
+
+        assert_eq!(release_date_key(Some("1981-02-30"), day), None);
+        assert_eq!(release_date_key(Some("1981"), day), None);
+        assert_eq!(
+            release_date_key(Some("1981-12-15"), Some(&ReleaseDatePrecision::Year)),
+            Some((1981, 1, 1, ReleaseDatePrecision::Year))
+        );
+        assert!(release_date_key(Some("1981"), None) < release_date_key(Some("1981-01-01"), day));
+    }
+}
@@ -1,5 +1,6 @@
-use super::{ContextType, Cursors, EpisodeId, ExternalUrls, ItemType, Track, TrackId, TrackItem};
-use serde::{Deserialize, Serialize};
+use super::{Cursors, EpisodeId, ExternalUrls, ItemType, PlaylistItem, Track, TrackId, TrackItem};
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap as _};
+use std::collections::{HashMap, HashSet};
 
 /// A playback device (speaker, phone, computer, etc.).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -166,6 +167,65 @@ pub struct Actions {
     pub transferring_playback: Option<bool>,
 }
 
+/// A playback action which can be available in the current context, see [`Actions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaybackAction {
+    InterruptingPlayback,
+    Pausing,
+    Resuming,
+    Seeking,
+    SkippingNext,
+    SkippingPrev,
+    TogglingRepeatContext,
+    TogglingShuffle,
+    TogglingRepeatTrack,
+    TransferringPlayback,
+}
+
+impl Actions {
+    /// Returns the actions which are available, in the order the fields are declared.
+    ///
+    /// Actions which Spotify omitted are considered unavailable.
+    pub fn allowed(&self) -> Vec<PlaybackAction> {
+        [
+            (
+                self.interrupting_playback,
+                PlaybackAction::InterruptingPlayback,
+            ),
+            (self.pausing, PlaybackAction::Pausing),
+            (self.resuming, PlaybackAction::Resuming),
+            (self.seeking, PlaybackAction::Seeking),
+            (self.skipping_next, PlaybackAction::SkippingNext),
+            (self.skipping_prev, PlaybackAction::SkippingPrev),
+            (
+                self.toggling_repeat_context,
+                PlaybackAction::TogglingRepeatContext,
+            ),
+            (self.toggling_shuffle, PlaybackAction::TogglingShuffle),
+            (
+                self.toggling_repeat_track,
+                PlaybackAction::TogglingRepeatTrack,
+            ),
+            (
+                self.transferring_playback,
+                PlaybackAction::TransferringPlayback,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(allowed, action)| (allowed == Some(true)).then_some(action))
+        .collect()
+    }
+}
+
+impl PlaybackState {
+    /// Returns the playback actions which are available in the current context.
+    ///
+    /// See [`Actions::allowed`].
+    pub fn allowed_actions(&self) -> Vec<PlaybackAction> {
+        self.actions.allowed()
+    }
+}
+
 /// Information about the currently playing track or episode.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CurrentlyPlaying {
@@ -191,6 +251,19 @@ pub struct CurrentlyPlaying {
     pub actions: Actions,
 }
 
+impl CurrentlyPlaying {
+    /// The name of the show, if the currently playing item is an episode.
+    ///
+    /// Episodes are only returned when requested with `include_episodes`.
+    #[cfg(feature = "shows")]
+    pub fn show_name(&self) -> Option<&str> {
+        match &self.item {
+            Some(TrackItem::Episode(episode)) => Some(&episode.show.name),
+            _ => None,
+        }
+    }
+}
+
 /// A track in the user's play history.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PlayHistory {
@@ -226,6 +299,29 @@ pub struct RecentlyPlayedTracks {
     pub items: Vec<PlayHistory>,
 }
 
+impl RecentlyPlayedTracks {
+    /// Returns the played tracks without repeats, most recently played first.
+    ///
+    /// When a track was played several times, only its most recent play is kept.
+    pub fn unique_tracks(&self) -> Vec<&Track> {
+        let mut seen = HashSet::new();
+        self.items
+            .iter()
+            .map(|item| &item.track)
+            .filter(|track| seen.insert(track.id.as_str()))
+            .collect()
+    }
+
+    /// Returns how many times each track was played, keyed by track ID.
+    pub fn play_counts(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for item in &self.items {
+            *counts.entry(item.track.id.clone()).or_default() += 1;
+        }
+        counts
+    }
+}
+
 /// The user's playback queue.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Queue {
@@ -239,8 +335,11 @@ pub struct Queue {
 /// An offset for starting playback at a specific position or URI.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Offset {
+    /// The zero-based position of the item in the context.
     Position(usize),
-    Uri(ContextType),
+
+    /// The track or episode of the context to start with.
+    Uri(PlaylistItem),
 }
 
 impl From<usize> for Offset {
@@ -249,45 +348,51 @@ impl From<usize> for Offset {
     }
 }
 
-/// A time range for querying recently played tracks.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum QueryRange {
-    Before(i64),
-    After(i64),
-}
-
-/// An item that can be added to a playlist (track or episode).
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum PlaylistItem {
-    Track(TrackId),
-    Episode(EpisodeId),
+impl From<PlaylistItem> for Offset {
+    fn from(item: PlaylistItem) -> Self {
+        Self::Uri(item)
+    }
 }
 
-impl From<TrackId> for PlaylistItem {
+impl From<TrackId> for Offset {
     fn from(track: TrackId) -> Self {
-        Self::Track(track)
+        Self::Uri(track.into())
     }
 }
 
-impl From<EpisodeId> for PlaylistItem {
+impl From<EpisodeId> for Offset {
     fn from(episode: EpisodeId) -> Self {
-        Self::Episode(episode)
+        Self::Uri(episode.into())
     }
 }
 
-impl std::fmt::Display for PlaylistItem {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            Self::Track(track) => track.uri(),
-            Self::Episode(episode) => episode.uri(),
-        };
-        write!(f, "{s}")
+impl Serialize for Offset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+
+        match self {
+            Self::Position(position) => map.serialize_entry("position", position)?,
+            Self::Uri(item) => map.serialize_entry("uri", &item.to_string())?,
+        }
+
+        map.end()
     }
 }
 
+/// A time range for querying recently played tracks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryRange {
+    Before(i64),
+    After(i64),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::fixtures;
 
     #[test]
     fn playback_state() {
@@ -424,4 +529,168 @@ mod tests {
 
         crate::test::assert_deserialized!(PlaybackState, json);
     }
+
+    #[test]
+    fn allowed_actions() {
+        let actions: Actions = serde_json::from_str(
+            r#"{
+                "pausing": true,
+                "resuming": false,
+                "seeking": true,
+                "skipping_next": true,
+                "toggling_shuffle": false
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            actions.allowed(),
+            [
+                PlaybackAction::Pausing,
+                PlaybackAction::Seeking,
+                PlaybackAction::SkippingNext
+            ]
+        );
+
+        let actions: Actions = serde_json::from_str("{}").unwrap();
+        assert!(actions.allowed().is_empty());
+    }
+
+    #[test]
+    fn offset_serialize() {
+        let position = serde_json::to_string(&Offset::Position(5)).unwrap();
+        assert_eq!(position, r#"{"position":5}"#);
+
+        let uri = serde_json::to_string(&Offset::from(
+            TrackId::from_id("1301WleyT98MSxVHPZCA6M").unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(uri, r#"{"uri":"spotify:track:1301WleyT98MSxVHPZCA6M"}"#);
+
+        let episode = serde_json::to_value(Offset::from(
+            EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(
+            episode,
+            serde_json::json!({ "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ" })
+        );
+
+        assert_eq!(
+            serde_json::to_value(Offset::from(0)).unwrap(),
+            serde_json::json!({ "position": 0 })
+        );
+    }
+
+    #[cfg(feature = "shows")]
+    #[test]
+    fn currently_playing_episode() {
+        let json = r#"
+        {
+			"context": null,
+			"timestamp": 0,
+			"progress_ms": 1000,
+			"is_playing": true,
+			"item": {
+				"audio_preview_url": null,
+				"description": "string",
+				"html_description": "string",
+				"duration_ms": 1686230,
+				"explicit": false,
+				"external_urls": {
+					"spotify": "string"
+				},
+				"href": "https://api.spotify.com/v1/episodes/5Xt5DXGzch68nYYamXrNxZ",
+				"id": "5Xt5DXGzch68nYYamXrNxZ",
+				"images": [],
+				"is_externally_hosted": false,
+				"is_playable": true,
+				"language": "en",
+				"languages": ["en"],
+				"name": "Starting Your Own Podcast",
+				"release_date": "1981-12-15",
+				"release_date_precision": "day",
+				"type": "episode",
+				"uri": "spotify:episode:5Xt5DXGzch68nYYamXrNxZ",
+				"show": {
+					"available_markets": ["US"],
+					"copyrights": [],
+					"description": "string",
+					"html_description": "string",
+					"explicit": false,
+					"external_urls": {
+						"spotify": "string"
+					},
+					"href": "string",
+					"id": "string",
+					"images": [],
+					"is_externally_hosted": false,
+					"languages": ["en"],
+					"media_type": "audio",
+					"name": "Spotify: The Podcast",
+					"publisher": "Spotify",
+					"type": "show",
+					"uri": "string",
+					"total_episodes": 10
+				}
+			},
+			"currently_playing_type": "episode",
+			"actions": {}
+        }
+        "#;
+
+        let currently_playing: CurrentlyPlaying = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(
+            currently_playing.item,
+            Some(TrackItem::Episode(_))
+        ));
+        assert_eq!(currently_playing.show_name(), Some("Spotify: The Podcast"));
+    }
+
+    #[test]
+    fn recently_played_unique_tracks() {
+        let play = |id: &str, played_at: &str| {
+            serde_json::json!({
+                "track": fixtures::track(id),
+                "played_at": played_at,
+                "context": {
+                    "type": "playlist",
+                    "href": null,
+                    "external_urls": { "spotify": "string" },
+                    "uri": "string"
+                }
+            })
+        };
+
+        let recently_played: RecentlyPlayedTracks = serde_json::from_value(serde_json::json!({
+            "href": "string",
+            "limit": 20,
+            "next": null,
+            "cursors": null,
+            "total": null,
+            "items": [
+                play("a", "2024-01-01T10:04:00Z"),
+                play("b", "2024-01-01T10:03:00Z"),
+                play("a", "2024-01-01T10:02:00Z"),
+                play("c", "2024-01-01T10:01:00Z"),
+                play("b", "2024-01-01T10:00:00Z"),
+                play("a", "2024-01-01T09:59:00Z"),
+            ]
+        }))
+        .unwrap();
+
+        let ids = recently_played
+            .unique_tracks()
+            .into_iter()
+            .map(|track| track.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["a", "b", "c"]);
+
+        let counts = recently_played.play_counts();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 2);
+        assert_eq!(counts["c"], 1);
+    }
 }
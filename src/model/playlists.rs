@@ -1,6 +1,8 @@
+#[cfg(feature = "page_items")]
+use super::Page;
 use super::{
-    ExternalUrls, Followers, Image, ItemType, Page, TrackItem, TrackReference, UserReference,
-    VideoThumbnail,
+    EpisodeId, ExternalUrls, Followers, Image, ItemType, TrackId, TrackItem, TrackReference,
+    UserReference, VideoThumbnail,
 };
 use serde::{Deserialize, Serialize};
 
@@ -212,6 +214,35 @@ pub struct SnapshotId {
     pub snapshot_id: String,
 }
 
+/// An item that can be added to a playlist (track or episode).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaylistItem {
+    Track(TrackId),
+    Episode(EpisodeId),
+}
+
+impl From<TrackId> for PlaylistItem {
+    fn from(track: TrackId) -> Self {
+        Self::Track(track)
+    }
+}
+
+impl From<EpisodeId> for PlaylistItem {
+    fn from(episode: EpisodeId) -> Self {
+        Self::Episode(episode)
+    }
+}
+
+impl std::fmt::Display for PlaylistItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Track(track) => track.uri(),
+            Self::Episode(episode) => episode.uri(),
+        };
+        write!(f, "{s}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -432,4 +463,16 @@ mod tests {
 
         crate::test::assert_deserialized!(SimplifiedPlaylist, json);
     }
+
+    #[test]
+    fn playlist_item_uri() {
+        let track = PlaylistItem::from(TrackId::from_id("4iV5W9uYEdYUVa79Axb7Rh").unwrap());
+        let episode = PlaylistItem::from(EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap());
+
+        assert_eq!(track.to_string(), "spotify:track:4iV5W9uYEdYUVa79Axb7Rh");
+        assert_eq!(
+            episode.to_string(),
+            "spotify:episode:512ojhOuo1ktJprKbVcKyQ"
+        );
+    }
 }
@@ -0,0 +1,73 @@
+use super::Track;
+use serde::{Deserialize, Serialize};
+
+/// Recommended tracks along with the seeds they were generated from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Recommendations {
+    /// The seeds used to generate the recommendations.
+    pub seeds: Vec<RecommendationSeed>,
+
+    /// The recommended tracks, ordered by relevance.
+    pub tracks: Vec<Track>,
+}
+
+/// A seed used to generate [`Recommendations`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecommendationSeed {
+    /// The number of tracks available after `min_*` and `max_*` filters have been applied.
+    #[serde(rename = "afterFilteringSize")]
+    pub after_filtering_size: u32,
+
+    /// The number of tracks available after relinking for regional availability.
+    #[serde(rename = "afterRelinkingSize")]
+    pub after_relinking_size: u32,
+
+    /// A link to the full track or artist data for this seed. For genre seeds, this value will be null.
+    pub href: Option<String>,
+
+    /// The id used to select this seed. This will be the same as the string used in the
+    /// `seed_artists`, `seed_tracks` or `seed_genres` parameter.
+    pub id: String,
+
+    /// The number of recommended tracks available for this seed.
+    #[serde(rename = "initialPoolSize")]
+    pub initial_pool_size: u32,
+
+    /// The entity type of this seed. One of `artist`, `track` or `genre`.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recommendations() {
+        let json = r#"
+        {
+			"seeds": [
+				{
+					"afterFilteringSize": 250,
+					"afterRelinkingSize": 250,
+					"href": "https://api.spotify.com/v1/artists/4NHQUGzhtTLFvgF5SZesLK",
+					"id": "4NHQUGzhtTLFvgF5SZesLK",
+					"initialPoolSize": 250,
+					"type": "ARTIST"
+				},
+				{
+					"afterFilteringSize": 250,
+					"afterRelinkingSize": 250,
+					"href": null,
+					"id": "classical",
+					"initialPoolSize": 250,
+					"type": "GENRE"
+				}
+			],
+			"tracks": []
+        }
+        "#;
+
+        crate::test::assert_deserialized!(Recommendations, json);
+    }
+}
@@ -1,7 +1,8 @@
-use super::{
-    Artist, Page, SimplifiedAlbum, SimplifiedAudiobook, SimplifiedEpisode, SimplifiedPlaylist,
-    SimplifiedShow, Track,
-};
+#[cfg(feature = "audiobooks")]
+use super::SimplifiedAudiobook;
+use super::{Artist, Page, SimplifiedAlbum, SimplifiedPlaylist, Track};
+#[cfg(feature = "shows")]
+use super::{SimplifiedEpisode, SimplifiedShow};
 use serde::{Deserialize, Serialize};
 
 /// Spotify catalog information about albums, artists, playlists, tracks, shows, episodes or audiobooks.
@@ -33,19 +34,100 @@ pub struct SearchResults {
     /// Matching shows (podcasts), if shows were included in the search.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
+    #[cfg(feature = "shows")]
     pub shows: Option<Page<Option<SimplifiedShow>>>,
 
     /// Matching episodes, if episodes were included in the search.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
+    #[cfg(feature = "shows")]
     pub episodes: Option<Page<Option<SimplifiedEpisode>>>,
 
     /// Matching audiobooks, if audiobooks were included in the search.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
+    #[cfg(feature = "audiobooks")]
     pub audiobooks: Option<Page<Option<SimplifiedAudiobook>>>,
 }
 
+impl SearchResults {
+    /// Combines the results of every type into a single ranked list of at most `n` items.
+    ///
+    /// Items are interleaved by their position within their own type: the first track,
+    /// artist, album, playlist, show, episode and audiobook come first, followed by the
+    /// second of each, and so on. Types which were not searched for, and `null` items,
+    /// are skipped.
+    pub fn top_results(&self, n: usize) -> Vec<SearchItem> {
+        fn items<T: Clone>(
+            page: Option<&Page<Option<T>>>,
+            f: fn(T) -> SearchItem,
+        ) -> Vec<SearchItem> {
+            page.map(|page| page.items.iter().flatten().cloned().map(f).collect())
+                .unwrap_or_default()
+        }
+
+        let mut lists = [
+            items(self.tracks.as_ref(), SearchItem::Track),
+            items(self.artists.as_ref(), SearchItem::Artist),
+            items(self.albums.as_ref(), SearchItem::Album),
+            items(self.playlists.as_ref(), SearchItem::Playlist),
+            #[cfg(feature = "shows")]
+            items(self.shows.as_ref(), SearchItem::Show),
+            #[cfg(feature = "shows")]
+            items(self.episodes.as_ref(), SearchItem::Episode),
+            #[cfg(feature = "audiobooks")]
+            items(self.audiobooks.as_ref(), SearchItem::Audiobook),
+        ]
+        .map(Vec::into_iter);
+
+        let longest = lists.iter().map(ExactSizeIterator::len).max().unwrap_or(0);
+
+        (0..longest)
+            .flat_map(|_| {
+                lists
+                    .iter_mut()
+                    .filter_map(Iterator::next)
+                    .collect::<Vec<_>>()
+            })
+            .take(n)
+            .collect()
+    }
+}
+
+/// A single item from any of the types in [`SearchResults`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum SearchItem {
+    Album(SimplifiedAlbum),
+    Artist(Artist),
+    Playlist(SimplifiedPlaylist),
+    Track(Track),
+    #[cfg(feature = "shows")]
+    Show(SimplifiedShow),
+    #[cfg(feature = "shows")]
+    Episode(SimplifiedEpisode),
+    #[cfg(feature = "audiobooks")]
+    Audiobook(SimplifiedAudiobook),
+}
+
+impl SearchItem {
+    /// The type of this item.
+    pub fn search_type(&self) -> SearchType {
+        match self {
+            Self::Album(_) => SearchType::Album,
+            Self::Artist(_) => SearchType::Artist,
+            Self::Playlist(_) => SearchType::Playlist,
+            Self::Track(_) => SearchType::Track,
+            #[cfg(feature = "shows")]
+            Self::Show(_) => SearchType::Show,
+            #[cfg(feature = "shows")]
+            Self::Episode(_) => SearchType::Episode,
+            #[cfg(feature = "audiobooks")]
+            Self::Audiobook(_) => SearchType::Audiobook,
+        }
+    }
+}
+
 /// The type of item to search for in the Spotify catalog.
 ///
 /// Used with the search endpoint to specify which types of items to include
@@ -92,13 +174,121 @@ impl std::fmt::Display for SearchType {
     }
 }
 
+/// A builder for search query strings with [field filters](https://developer.spotify.com/documentation/web-api/reference/search).
+///
+/// Filter values containing whitespace are quoted, and double quotes inside values are
+/// dropped as Spotify offers no way to escape them.
+///
+/// ```
+/// use spotify_web_api::model::SearchQuery;
+///
+/// let query = SearchQuery::default()
+///     .keyword("remaster")
+///     .track("Doxy")
+///     .artist("Miles Davis")
+///     .year_range(1955, 1960);
+///
+/// assert_eq!(
+///     query.to_string(),
+///     r#"remaster track:Doxy artist:"Miles Davis" year:1955-1960"#
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    /// Match items containing `keyword` in any field.
+    pub fn keyword(mut self, keyword: &str) -> Self {
+        let keyword = keyword.trim();
+        if !keyword.is_empty() {
+            self.terms.push(keyword.to_owned());
+        }
+        self
+    }
+
+    /// Match albums, artists and tracks by the name of the artist.
+    pub fn artist(self, artist: &str) -> Self {
+        self.filter("artist", artist)
+    }
+
+    /// Match albums and tracks by the name of the album.
+    pub fn album(self, album: &str) -> Self {
+        self.filter("album", album)
+    }
+
+    /// Match tracks by their name.
+    pub fn track(self, track: &str) -> Self {
+        self.filter("track", track)
+    }
+
+    /// Match artists and tracks by genre.
+    pub fn genre(self, genre: &str) -> Self {
+        self.filter("genre", genre)
+    }
+
+    /// Match tracks by their [International Standard Recording Code](https://en.wikipedia.org/wiki/International_Standard_Recording_Code).
+    pub fn isrc(self, isrc: &str) -> Self {
+        self.filter("isrc", isrc)
+    }
+
+    /// Match albums by their [Universal Product Code](https://en.wikipedia.org/wiki/Universal_Product_Code).
+    pub fn upc(self, upc: &str) -> Self {
+        self.filter("upc", upc)
+    }
+
+    /// Match albums, artists and tracks released in `year`.
+    pub fn year(self, year: u16) -> Self {
+        self.filter("year", &year.to_string())
+    }
+
+    /// Match albums, artists and tracks released between `from` and `to`, inclusive.
+    pub fn year_range(self, from: u16, to: u16) -> Self {
+        self.filter("year", &format!("{from}-{to}"))
+    }
+
+    /// Only match albums released in the past two weeks.
+    pub fn tag_new(self) -> Self {
+        self.filter("tag", "new")
+    }
+
+    /// Only match albums with the lowest 10% popularity.
+    pub fn tag_hipster(self) -> Self {
+        self.filter("tag", "hipster")
+    }
+
+    fn filter(mut self, field: &str, value: &str) -> Self {
+        let value = value.replace('"', "");
+        let value = value.trim();
+
+        if value.contains(char::is_whitespace) {
+            self.terms.push(format!(r#"{field}:"{value}""#));
+        } else if !value.is_empty() {
+            self.terms.push(format!("{field}:{value}"));
+        }
+
+        self
+    }
+}
+
+impl std::fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.terms.join(" "))
+    }
+}
+
+impl From<SearchQuery> for String {
+    fn from(query: SearchQuery) -> Self {
+        query.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn search() {
-        let json = r#"
+    const SEARCH: &str = r#"
         {
 			"tracks": {
 				"href": "https://api.spotify.com/v1/me/shows?offset=0&limit=20",
@@ -458,7 +648,9 @@ mod tests {
         }
         "#;
 
-        crate::test::assert_deserialized!(SearchResults, json);
+    #[test]
+    fn search() {
+        crate::test::assert_deserialized!(SearchResults, SEARCH);
     }
 
     #[test]
@@ -553,4 +745,94 @@ mod tests {
 
         crate::test::assert_deserialized!(SearchResults, json);
     }
+
+    #[test]
+    fn top_results_interleaves_types() {
+        let results: SearchResults = serde_json::from_str(SEARCH).unwrap();
+
+        let kinds = |items: Vec<SearchItem>| {
+            items
+                .iter()
+                .map(SearchItem::search_type)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds(results.top_results(10)),
+            [
+                SearchType::Track,
+                SearchType::Artist,
+                SearchType::Album,
+                SearchType::Playlist,
+                #[cfg(feature = "shows")]
+                SearchType::Show,
+                #[cfg(feature = "shows")]
+                SearchType::Episode,
+                #[cfg(feature = "audiobooks")]
+                SearchType::Audiobook,
+            ]
+        );
+        assert_eq!(
+            kinds(results.top_results(2)),
+            [SearchType::Track, SearchType::Artist]
+        );
+
+        let mut results = results;
+        let tracks = results.tracks.as_mut().unwrap();
+        tracks.items.push(tracks.items[0].clone());
+
+        assert_eq!(
+            kinds(results.top_results(9)),
+            [
+                SearchType::Track,
+                SearchType::Artist,
+                SearchType::Album,
+                SearchType::Playlist,
+                #[cfg(feature = "shows")]
+                SearchType::Show,
+                #[cfg(feature = "shows")]
+                SearchType::Episode,
+                #[cfg(feature = "audiobooks")]
+                SearchType::Audiobook,
+                SearchType::Track,
+            ]
+        );
+    }
+
+    #[test]
+    fn search_query() {
+        let query = SearchQuery::default()
+            .keyword("live")
+            .artist("Weyes Blood")
+            .album("Titanic Rising")
+            .year_range(2018, 2020)
+            .tag_new();
+
+        assert_eq!(
+            query.to_string(),
+            r#"live artist:"Weyes Blood" album:"Titanic Rising" year:2018-2020 tag:new"#
+        );
+
+        let query = SearchQuery::default()
+            .genre("jazz")
+            .year(1959)
+            .tag_hipster()
+            .isrc("USUM71703861")
+            .upc("  ");
+        assert_eq!(
+            String::from(query),
+            "genre:jazz year:1959 tag:hipster isrc:USUM71703861"
+        );
+    }
+
+    #[test]
+    fn search_query_quotes() {
+        let query = SearchQuery::default()
+            .track(r#"Say "Hello""#)
+            .artist(r#""Adele""#)
+            .keyword("  ");
+
+        assert_eq!(query.to_string(), r#"track:"Say Hello" artist:Adele"#);
+        assert_eq!(SearchQuery::default().to_string(), "");
+    }
 }
@@ -1,4 +1,8 @@
-use super::{Copyright, ExternalUrls, Image, ItemType, Market, Page, SimplifiedEpisode};
+#[cfg(feature = "markets")]
+use super::Market;
+use super::{Copyright, ExternalUrls, Image, ItemType};
+#[cfg(feature = "page_items")]
+use super::{Page, SimplifiedEpisode};
 use serde::{Deserialize, Serialize};
 
 /// Full show (podcast) information from the Spotify catalog.
@@ -174,18 +178,20 @@ pub struct SavedShow {
 }
 
 /// Spotify catalog information for several shows.
+///
+/// The batch endpoint only returns [`SimplifiedShow`]s, so these shows do not include
+/// their episodes. Fetch each [`Show`] individually when the episodes are needed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Shows {
     pub shows: Vec<SimplifiedShow>,
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
-    #[test]
-    fn show() {
-        let json = r#"
+    /// A full show, as returned by [`GetShow`](crate::api::shows::GetShow).
+    pub(crate) const SHOW: &str = r#"
         {
 			"available_markets": ["US"],
 			"copyrights": [
@@ -263,9 +269,11 @@ mod tests {
 				]
 			}
 		}
-        "#;
+    "#;
 
-        crate::test::assert_deserialized!(Show, json);
+    #[test]
+    fn show() {
+        crate::test::assert_deserialized!(Show, SHOW);
     }
 
     #[test]
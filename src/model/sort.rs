@@ -0,0 +1,94 @@
+//! Helpers for ordering model collections.
+
+use super::{Artist, Track};
+use std::cmp::Reverse;
+
+/// Returns the tracks ordered from most to least popular.
+///
+/// Tracks with the same popularity are ordered by name, and tracks which share both
+/// keep the order they had in `tracks`.
+pub fn rank_tracks_by_popularity(tracks: &[Track]) -> Vec<&Track> {
+    let mut ranked = tracks.iter().collect::<Vec<_>>();
+    ranked.sort_by(|a, b| {
+        Reverse(a.popularity)
+            .cmp(&Reverse(b.popularity))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    ranked
+}
+
+/// Returns the artists ordered from most to least followed.
+///
+/// Artists with the same number of followers are ordered by name, and artists which
+/// share both keep the order they had in `artists`.
+pub fn rank_artists_by_followers(artists: &[Artist]) -> Vec<&Artist> {
+    let mut ranked = artists.iter().collect::<Vec<_>>();
+    ranked.sort_by(|a, b| {
+        Reverse(a.followers.total)
+            .cmp(&Reverse(b.followers.total))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::fixtures;
+
+    fn track(id: &str, name: &str, popularity: u8) -> Track {
+        let mut track = fixtures::track(id);
+        track["name"] = name.into();
+        track["popularity"] = popularity.into();
+        serde_json::from_value(track).unwrap()
+    }
+
+    fn artist(id: &str, name: &str, followers: usize) -> Artist {
+        let mut artist = fixtures::artist(id);
+        artist["name"] = name.into();
+        artist["followers"]["total"] = followers.into();
+        serde_json::from_value(artist).unwrap()
+    }
+
+    #[test]
+    fn tracks_by_popularity() {
+        let tracks = [
+            track("1", "b", 50),
+            track("2", "c", 80),
+            track("3", "a", 50),
+            track("4", "b", 50),
+            track("5", "d", 10),
+        ];
+
+        let ids = rank_tracks_by_popularity(&tracks)
+            .into_iter()
+            .map(|track| track.id.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, ["2", "3", "1", "4", "5"]);
+    }
+
+    #[test]
+    fn artists_by_followers() {
+        let artists = [
+            artist("1", "b", 1_000),
+            artist("2", "a", 1_000),
+            artist("3", "c", 5_000),
+            artist("4", "a", 1_000),
+            artist("5", "d", 0),
+        ];
+
+        let ids = rank_artists_by_followers(&artists)
+            .into_iter()
+            .map(|artist| artist.id.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, ["3", "2", "4", "1", "5"]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(rank_tracks_by_popularity(&[]).is_empty());
+        assert!(rank_artists_by_followers(&[]).is_empty());
+    }
+}
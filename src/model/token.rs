@@ -1,5 +1,8 @@
+use crate::{SpotifyError, SpotifyResult, auth::scopes::Scope};
+use base64::{Engine as _, engine::general_purpose};
 use chrono::{DateTime, TimeDelta, Utc, serde::ts_seconds_option};
 use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, time::Duration};
 
 /// Represents an OAuth 2.0 access token for authenticating API requests.
 ///
@@ -61,8 +64,102 @@ impl Token {
     /// - `true`: If the token is expired or the expiration time is not set.
     /// - `false`: If the token is still valid.
     pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+
+    /// Checks if the access token has expired at the given point in time.
+    ///
+    /// This behaves like [`Token::is_expired`], but takes the current time as a parameter,
+    /// which makes the expiry logic deterministic.
+    ///
+    /// # Returns
+    /// - `true`: If the token is expired at `now` or the expiration time is not set.
+    /// - `false`: If the token is still valid at `now`.
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_within_at(Duration::from_secs(10), now)
+    }
+
+    /// Checks if the access token expires within `skew` from now.
+    ///
+    /// A token which is about to expire can be refreshed before a request is sent, so that it
+    /// does not expire while the request is in flight.
+    ///
+    /// If `expires_at` is not set, it assumes the token is expired.
+    pub fn expires_within(&self, skew: Duration) -> bool {
+        self.expires_within_at(skew, Utc::now())
+    }
+
+    /// Checks if the access token expires within `skew` from the given point in time.
+    ///
+    /// This behaves like [`Token::expires_within`], but takes the current time as a parameter.
+    pub fn expires_within_at(&self, skew: Duration, now: DateTime<Utc>) -> bool {
+        let deadline = TimeDelta::from_std(skew)
+            .ok()
+            .and_then(|skew| now.checked_add_signed(skew));
+
         self.expires_at
-            .is_none_or(|expires_at| Utc::now() + TimeDelta::seconds(10) >= expires_at)
+            .is_none_or(|expires_at| deadline.is_none_or(|deadline| deadline >= expires_at))
+    }
+
+    /// Returns the scopes which have been granted for this token.
+    ///
+    /// Tokens obtained through the Client Credentials flow have no `scope`, in which case the
+    /// set is empty. Scopes unknown to this library are skipped.
+    pub fn scopes(&self) -> HashSet<Scope> {
+        self.scope
+            .as_deref()
+            .map(Scope::parse_set)
+            .unwrap_or_default()
+    }
+
+    /// Serializes the token to a JSON string.
+    ///
+    /// The refresh token and expiration time are preserved, so the token can be restored
+    /// later with [`Token::from_json`].
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::DataType` if serialization of the token fails.
+    pub fn to_json(&self) -> SpotifyResult<String> {
+        serde_json::to_string(self).map_err(SpotifyError::data_type::<Self>)
+    }
+
+    /// Deserializes a token from a JSON string produced by [`Token::to_json`].
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::DataType` if the string is not a valid token.
+    pub fn from_json(json: &str) -> SpotifyResult<Self> {
+        serde_json::from_str(json).map_err(SpotifyError::data_type::<Self>)
+    }
+
+    /// Deserializes a token from a string produced by [`Token::to_json`].
+    ///
+    /// This is an alias of [`Token::from_json`], matching `Spotify::with_token_str`.
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::DataType` if the string is not a valid token.
+    pub fn from_json_str(json: &str) -> SpotifyResult<Self> {
+        Self::from_json(json)
+    }
+
+    /// Serializes the token to an opaque, URL-safe base64 string.
+    ///
+    /// This is suitable for storing the token in a cookie or session store. The token can be
+    /// restored with [`Token::from_base64`].
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::DataType` if serialization of the token fails.
+    pub fn to_base64(&self) -> SpotifyResult<String> {
+        Ok(general_purpose::URL_SAFE_NO_PAD.encode(self.to_json()?))
+    }
+
+    /// Deserializes a token from a string produced by [`Token::to_base64`].
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::Base64` if the string is not valid base64, or a
+    /// `SpotifyError::DataType` if the decoded data is not a valid token.
+    pub fn from_base64(encoded: &str) -> SpotifyResult<Self> {
+        let json = general_purpose::URL_SAFE_NO_PAD.decode(encoded)?;
+        serde_json::from_slice(&json).map_err(SpotifyError::data_type::<Self>)
     }
 }
 
@@ -84,4 +181,103 @@ mod tests {
 
         crate::test::assert_deserialized!(Token, json);
     }
+
+    #[test]
+    fn client_credentials_token() {
+        let json = r#"
+        {
+			"access_token": "string",
+			"token_type": "Bearer",
+			"expires_in": 3600
+        }
+        "#;
+
+        let token = Token::from_json(json).unwrap();
+        assert_eq!(token.refresh_token, None);
+        assert_eq!(token.scope, None);
+        assert!(token.scopes().is_empty());
+    }
+
+    #[test]
+    fn scopes() {
+        let token = Token {
+            scope: Some("user-read-email  user-read-private unknown-scope".to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            token.scopes(),
+            HashSet::from([Scope::UserReadEmail, Scope::UserReadPrivate])
+        );
+    }
+
+    fn token_expiring_at(expires_at: Option<DateTime<Utc>>) -> Token {
+        Token {
+            access_token: "string".to_owned(),
+            token_type: "Bearer".to_owned(),
+            expires_in: 3600,
+            expires_at,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn is_expired_at() {
+        let expires_at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let token = token_expiring_at(Some(expires_at));
+
+        assert!(!token.is_expired_at(expires_at - TimeDelta::minutes(5)));
+        assert!(token.is_expired_at(expires_at - TimeDelta::seconds(5)));
+        assert!(token.is_expired_at(expires_at + TimeDelta::minutes(5)));
+
+        let token = token_expiring_at(None);
+        assert!(token.is_expired_at(expires_at - TimeDelta::minutes(5)));
+    }
+
+    #[test]
+    fn expires_within_at() {
+        let expires_at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let token = token_expiring_at(Some(expires_at));
+        let now = expires_at - TimeDelta::seconds(20);
+
+        assert!(token.expires_within_at(Duration::from_secs(30), now));
+        assert!(token.expires_within_at(Duration::from_secs(20), now));
+        assert!(!token.expires_within_at(Duration::from_secs(10), now));
+        assert!(!token.expires_within_at(Duration::ZERO, now));
+        assert!(token.expires_within_at(Duration::MAX, now));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let token = Token {
+            refresh_token: Some("refresh".to_owned()),
+            scope: Some("user-read-email".to_owned()),
+            ..token_expiring_at(DateTime::from_timestamp(1_700_000_000, 0))
+        };
+
+        let json = token.to_json().unwrap();
+        assert_eq!(Token::from_json(&json).unwrap(), token);
+
+        assert!(matches!(
+            Token::from_json("{}"),
+            Err(SpotifyError::DataType { .. })
+        ));
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let token = Token {
+            refresh_token: Some("refresh".to_owned()),
+            ..token_expiring_at(DateTime::from_timestamp(1_700_000_000, 0))
+        };
+
+        let encoded = token.to_base64().unwrap();
+        assert!(!encoded.contains(['+', '/', '=']));
+        assert_eq!(Token::from_base64(&encoded).unwrap(), token);
+
+        assert!(matches!(
+            Token::from_base64("not base64!"),
+            Err(SpotifyError::Base64(_))
+        ));
+    }
 }
@@ -1,6 +1,6 @@
-use super::{
-    ExternalIds, ExternalUrls, ItemType, Market, Restrictions, SimplifiedAlbum, SimplifiedArtist,
-};
+#[cfg(feature = "markets")]
+use super::Market;
+use super::{ExternalIds, ExternalUrls, ItemType, Restrictions, SimplifiedAlbum, SimplifiedArtist};
 use serde::{Deserialize, Serialize};
 
 /// Linked track information for re-linked tracks.
@@ -207,6 +207,14 @@ pub struct Tracks {
     pub tracks: Vec<Option<Track>>,
 }
 
+/// An artist's top tracks, as returned by [`GetArtistTopTracks`](crate::api::artists::GetArtistTopTracks).
+///
+/// Unlike [`Tracks`], every track is present.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArtistTopTracks {
+    pub tracks: Vec<Track>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TrackReference {
     /// A link to the Web API endpoint where full details of the playlist's tracks can be retrieved.
@@ -304,4 +312,47 @@ mod tests {
 
         crate::test::assert_deserialized!(Track, json);
     }
+
+    #[test]
+    fn artist_top_tracks() {
+        let json = r#"
+        {
+			"tracks": [
+				{
+					"album": {
+						"album_type": "album",
+						"total_tracks": 9,
+						"external_urls": { "spotify": "string" },
+						"href": "string",
+						"id": "2up3OPMp9Tb4dAKM2erWXQ",
+						"images": [],
+						"name": "string",
+						"release_date": "1981-12",
+						"release_date_precision": "year",
+						"type": "album",
+						"uri": "spotify:album:2up3OPMp9Tb4dAKM2erWXQ",
+						"artists": []
+					},
+					"artists": [],
+					"disc_number": 1,
+					"duration_ms": 1000,
+					"explicit": false,
+					"external_ids": {},
+					"external_urls": { "spotify": "string" },
+					"href": "string",
+					"id": "11dFghVXANMlKmJXsNCbNl",
+					"name": "string",
+					"popularity": 80,
+					"track_number": 1,
+					"type": "track",
+					"uri": "spotify:track:11dFghVXANMlKmJXsNCbNl",
+					"is_local": false
+				}
+			]
+        }
+        "#;
+
+        crate::test::assert_deserialized!(ArtistTopTracks, json);
+        assert!(serde_json::from_str::<ArtistTopTracks>(r#"{"tracks": [null]}"#).is_err());
+    }
 }
@@ -1,32 +1,92 @@
+#[cfg(feature = "shows")]
+use crate::api::{episodes::GetEpisode, shows::GetShow};
+#[cfg(not(feature = "markets"))]
+use crate::model::Playable as _;
+#[cfg(not(target_arch = "wasm32"))]
 use crate::{
-    api::{self, ApiError, RestClient},
+    api::Query as _,
     auth::{
-        AuthCodePKCE, AuthError, AuthResult, ClientCredentials,
-        private::{AsyncAuthFlow, AuthFlow},
+        local_server::CallbackServer,
+        private::{AuthFlow, UserAuthFlow},
+        send_http_request,
+    },
+};
+use crate::{
+    api::{
+        self, ApiError, AsyncQuery as _, RestClient,
+        albums::GetAlbum,
+        artists::{GetArtist, GetArtistAlbums},
+        genres::{Genre, GenreError, GenreSeedCache},
+        paged_take,
+        playlists::GetPlaylist,
+        search::SearchForItem,
+        tracks::GetTrack,
+        users::{GetCurrentUserProfile, GetUserTopItems},
+    },
+    auth::{
+        AuthCode, AuthCodePKCE, AuthError, AuthResult, ClientCredentials,
+        private::{AsyncAuthFlow, AsyncUserAuthFlow},
         scopes::Scope,
+        send_http_request_async,
+    },
+    model::{
+        Album, AlbumId, AlbumType, AnyId, Artist, CurrentUserProfile, IdError, Market,
+        SearchResults, SearchType, SimplifiedAlbum, SpotifyResource, TimeRange, Token, TopItem,
+        TopItemType, Track, TrackId, dedup_discography, id_from_url,
     },
-    model::Token,
 };
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::{DateTime, TimeDelta, Utc};
 use http::{HeaderMap, HeaderValue, Response as HttpResponse};
 use parking_lot::RwLock;
-use reqwest::{Client as AsyncClient, blocking::Client};
-use std::{collections::HashSet, sync::Arc};
+use reqwest::Client as AsyncClient;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{Proxy, blocking::Client};
+#[cfg(all(debug_assertions, feature = "tokio", not(target_arch = "wasm32")))]
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+/// The policy for following HTTP redirects, see [`Spotify::redirect_policy`].
+#[cfg(not(target_arch = "wasm32"))]
+pub use reqwest::redirect::Policy as RedirectPolicy;
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 use thiserror::Error;
 use url::Url;
 
 const BASE_API_URL: &str = "https://api.spotify.com/v1/";
 
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long before it expires the access token is refreshed by default.
+const DEFAULT_REFRESH_SKEW: Duration = Duration::from_secs(30);
+
 /// Type alias for a blocking Spotify client using Authorization Code with PKCE flow.
+#[cfg(not(target_arch = "wasm32"))]
 pub type SpotifyPKCE = Spotify<AuthCodePKCE>;
 
+/// Type alias for a blocking Spotify client using Authorization Code flow.
+#[cfg(not(target_arch = "wasm32"))]
+pub type SpotifyAuthCode = Spotify<AuthCode>;
+
 /// Type alias for a blocking Spotify client using Client Credentials flow.
+#[cfg(not(target_arch = "wasm32"))]
 pub type SpotifyClientCredentials = Spotify<ClientCredentials>;
 
 /// Type alias for an async Spotify client using Authorization Code with PKCE flow.
 pub type AsyncSpotifyPKCE = AsyncSpotify<AuthCodePKCE>;
 
+/// Type alias for an async Spotify client using Authorization Code flow.
+pub type AsyncSpotifyAuthCode = AsyncSpotify<AuthCode>;
+
 /// Type alias for an async Spotify client using Client Credentials flow.
 pub type AsyncSpotifyClientCredentials = AsyncSpotify<ClientCredentials>;
 
@@ -55,10 +115,22 @@ pub enum RestError {
 
     /// An error during communication with the Spotify API.
     ///
-    /// This variant wraps a `reqwest::Error`, which can occur due to network
-    /// connectivity issues, timeouts, or unexpected responses from the Spotify API.
+    /// This variant wraps the `reqwest::Error`s which are not classified as a
+    /// [`RestError::Timeout`], [`RestError::Connect`] or [`RestError::Decode`].
     #[error("communication with spotify: {0}")]
-    Communication(#[from] reqwest::Error),
+    Communication(#[source] reqwest::Error),
+
+    /// The request did not complete within the configured timeout.
+    #[error("request to spotify timed out: {0}")]
+    Timeout(#[source] reqwest::Error),
+
+    /// The connection to the Spotify API could not be established.
+    #[error("failed to connect to spotify: {0}")]
+    Connect(#[source] reqwest::Error),
+
+    /// The response body could not be read or decompressed.
+    #[error("failed to decode spotify response: {0}")]
+    Decode(#[source] reqwest::Error),
 
     /// An error related to constructing or processing HTTP requests.
     ///
@@ -68,6 +140,25 @@ pub enum RestError {
     Http(#[from] http::Error),
 }
 
+impl From<reqwest::Error> for RestError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return Self::Timeout(err);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if err.is_connect() {
+            return Self::Connect(err);
+        }
+
+        if err.is_decode() {
+            Self::Decode(err)
+        } else {
+            Self::Communication(err)
+        }
+    }
+}
+
 /// Represents errors that can occur while interacting with the Spotify API.
 ///
 /// This enum captures various error scenarios, including URL parsing failures,
@@ -134,6 +225,27 @@ pub enum SpotifyError {
     /// the underlying REST error.
     #[error("api error: {0}")]
     Api(#[from] ApiError<RestError>),
+
+    /// An error that occurs when decoding base64 data fails.
+    ///
+    /// This variant wraps a `base64::DecodeError`, which indicates that the
+    /// input was not valid base64.
+    #[error("failed to decode base64 data: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    /// An error that occurs when a Spotify ID, URI or share link is invalid.
+    ///
+    /// This variant wraps an [`IdError`] describing why the ID was rejected.
+    #[error("invalid spotify id: {0}")]
+    Id(#[from] IdError),
+
+    /// The HTTP client was supplied by the caller and cannot be reconfigured.
+    ///
+    /// Returned when the proxy, redirect policy or timeout is set on a client created with
+    /// one of the `with_client_and_*` constructors. Configure these on the `reqwest` client
+    /// before passing it in instead.
+    #[error("the injected HTTP client cannot be reconfigured, set this on the client itself")]
+    InjectedClient,
 }
 
 impl SpotifyError {
@@ -145,6 +257,169 @@ impl SpotifyError {
     }
 }
 
+/// How requests which are rate limited with `429 Too Many Requests` are retried, see
+/// [`Spotify::with_retry`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The maximum number of times a request is retried before the `429` is returned.
+    /// The default is 3.
+    pub max_retries: u32,
+
+    /// Whether to wait for the number of seconds given in the `Retry-After` header, when
+    /// the response has one. The default is `true`.
+    pub respect_retry_after: bool,
+
+    /// The time to wait before the first retry when there is no `Retry-After` to respect,
+    /// which doubles with every attempt. The default is 1 second.
+    pub base_backoff: Duration,
+
+    /// The longest time to wait before a retry, whether it comes from `Retry-After` or the
+    /// backoff. The default is 60 seconds, so that a misbehaving server cannot stall the
+    /// request for hours.
+    pub max_delay: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            respect_retry_after: true,
+            base_backoff: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RetryConfig {
+    /// Returns how long to wait before retrying after `response`, or `None` if it should
+    /// be returned as is.
+    fn delay(&self, attempt: u32, response: &HttpResponse<Bytes>) -> Option<Duration> {
+        if response.status() != http::StatusCode::TOO_MANY_REQUESTS || attempt >= self.max_retries {
+            return None;
+        }
+
+        let retry_after = response
+            .headers()
+            .get(http::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+            .filter(|_| self.respect_retry_after)
+            .map(Duration::from_secs);
+
+        let delay = retry_after.unwrap_or_else(|| {
+            self.base_backoff
+                .saturating_mul(2u32.saturating_pow(attempt))
+        });
+        Some(delay.min(self.max_delay))
+    }
+}
+
+/// Returns a request builder and body which recreate `request`, so that it can be sent again.
+#[cfg(not(target_arch = "wasm32"))]
+fn clone_request(request: &http::Request<Vec<u8>>) -> (http::request::Builder, Vec<u8>) {
+    let mut builder = http::Request::builder()
+        .method(request.method().clone())
+        .uri(request.uri().clone())
+        .version(request.version());
+
+    *builder
+        .headers_mut()
+        .expect("failed to get headers on the request builder") = request.headers().clone();
+
+    (builder, request.body().clone())
+}
+
+/// Whether the warning about using the blocking client inside an async runtime was logged.
+#[cfg(all(debug_assertions, feature = "tokio", not(target_arch = "wasm32")))]
+static ASYNC_RUNTIME_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Logs a warning the first time the blocking client is used from within a tokio runtime,
+/// returning whether it was.
+///
+/// The blocking HTTP client can panic when used inside an async runtime, and otherwise blocks
+/// one of its worker threads, which is an easy mistake to make. Debug builds point towards
+/// [`AsyncSpotify`] instead, once, so that the log is not flooded with the same warning.
+#[cfg(all(debug_assertions, feature = "tokio", not(target_arch = "wasm32")))]
+fn warn_if_in_async_runtime(warned: &AtomicBool) -> bool {
+    let in_runtime = tokio::runtime::Handle::try_current().is_ok();
+    if in_runtime && !warned.swap(true, Ordering::Relaxed) {
+        log::warn!(
+            "the blocking `Spotify` client is being used inside an async runtime, where it can \
+             panic or block the runtime; use `AsyncSpotify` instead"
+        );
+    }
+    in_runtime
+}
+
+/// The only field of a playlist fetched when checking it for changes.
+#[derive(Deserialize)]
+struct PlaylistSnapshot {
+    snapshot_id: String,
+}
+
+fn playlist_snapshot_endpoint(playlist_id: impl Into<String>) -> GetPlaylist {
+    GetPlaylist {
+        fields: Some("snapshot_id".to_owned()),
+        include_episodes: false,
+        ..GetPlaylist::from(playlist_id)
+    }
+}
+
+fn top_items_endpoint(type_: TopItemType, time_range: TimeRange) -> GetUserTopItems {
+    GetUserTopItems {
+        time_range: Some(time_range),
+        ..GetUserTopItems::from(type_)
+    }
+}
+
+/// An artist albums endpoint including every album group.
+fn discography_endpoint(artist_id: impl Into<String>) -> GetArtistAlbums {
+    AlbumType::all()
+        .iter()
+        .fold(GetArtistAlbums::from(artist_id), |endpoint, group| {
+            endpoint.include_group(group.clone())
+        })
+}
+
+#[cfg(feature = "markets")]
+fn markets_availability(track: &Track, markets: &[Market]) -> HashMap<Market, bool> {
+    markets
+        .iter()
+        .map(|market| (market.clone(), track.available_markets.contains(market)))
+        .collect()
+}
+
+/// Diagnostics about the access token of a client, see [`Spotify::token_metrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenMetrics {
+    /// When the current access token was issued, if there is one.
+    pub issued_at: Option<DateTime<Utc>>,
+
+    /// When the current access token expires, if there is one.
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// How many times the access token has been refreshed since the client was constructed.
+    pub refresh_count: usize,
+}
+
+impl TokenMetrics {
+    fn new(token: Option<&Token>, refresh_count: &AtomicUsize) -> Self {
+        let expires_at = token.and_then(|token| token.expires_at);
+        let issued_at = token.zip(expires_at).and_then(|(token, expires_at)| {
+            expires_at.checked_sub_signed(TimeDelta::seconds(token.expires_in.into()))
+        });
+
+        Self {
+            issued_at,
+            expires_at,
+            refresh_count: refresh_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// A blocking client for interacting with the Spotify Web API.
 ///
 /// This struct provides synchronous methods for making API requests to Spotify.
@@ -156,6 +431,12 @@ impl SpotifyError {
 /// - [`SpotifyClientCredentials`] for app-only access (Client Credentials flow)
 ///
 /// See [`AsyncSpotify`] for an async version of this client.
+///
+/// The client can be cheaply cloned, for example to move it into a spawned task. Clones
+/// share the same HTTP client, access token and token callback, so a token refreshed by
+/// one clone is seen by all of them.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
 pub struct Spotify<A>
 where
     A: AuthFlow,
@@ -173,55 +454,244 @@ where
     token: Arc<RwLock<Option<Token>>>,
 
     /// A handler to call when the access token acquires a new value.
-    token_callback: Option<Box<dyn Fn(Token) + 'static>>,
+    token_callback: Option<Arc<dyn Fn(Token) + Send + Sync + 'static>>,
+
+    /// A handler to call when the access token has expired and cannot be refreshed.
+    token_expired_callback: Option<Arc<dyn Fn(Token) + Send + Sync + 'static>>,
+
+    /// The number of times the access token has been refreshed.
+    refresh_count: Arc<AtomicUsize>,
+
+    /// The available genre seeds, once fetched.
+    genre_seeds: GenreSeedCache,
+
+    /// Whether the HTTP client was supplied by the caller, in which case it is never rebuilt.
+    injected_client: bool,
+
+    /// The proxy to route requests through, if any.
+    proxy: Option<Proxy>,
+
+    /// The policy for following redirects.
+    redirect_policy: Arc<RedirectPolicy>,
+
+    /// The total time allowed for each request.
+    timeout: Duration,
+
+    /// How rate limited requests are retried, if at all.
+    retry: Option<RetryConfig>,
+
+    /// How long before it expires the access token is refreshed.
+    refresh_skew: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<A> std::fmt::Debug for Spotify<A>
+where
+    A: AuthFlow,
+{
+    /// The access token, client secret and PKCE verifier are never printed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Spotify")
+            .field("api_url", &self.api_url.as_str())
+            .field("auth", &std::any::type_name::<A>())
+            .field("token", &self.token.read().as_ref().map(|_| "<redacted>"))
+            .finish_non_exhaustive()
+    }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<A> Spotify<A>
 where
     A: AuthFlow,
 {
     fn new_impl(auth: A) -> SpotifyResult<Self> {
+        let client = Self::build_client(None, &Arc::new(RedirectPolicy::none()), DEFAULT_TIMEOUT)?;
+        Self::from_parts(auth, client)
+    }
+
+    fn from_parts(auth: A, client: Client) -> SpotifyResult<Self> {
         let api_url = Url::parse(BASE_API_URL)?;
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()?;
         let api = Self {
             client,
             api_url,
             auth,
             token: Arc::new(RwLock::new(None)),
             token_callback: None,
+            token_expired_callback: None,
+            refresh_count: Arc::default(),
+            genre_seeds: GenreSeedCache::default(),
+            injected_client: false,
+            proxy: None,
+            redirect_policy: Arc::new(RedirectPolicy::none()),
+            timeout: DEFAULT_TIMEOUT,
+            retry: None,
+            refresh_skew: DEFAULT_REFRESH_SKEW,
         };
         Ok(api)
     }
 
+    fn from_injected_client(auth: A, client: Client) -> SpotifyResult<Self> {
+        let mut api = Self::from_parts(auth, client)?;
+        api.injected_client = true;
+        Ok(api)
+    }
+
+    /// Fails if the HTTP client was supplied by the caller, as it cannot be rebuilt.
+    fn ensure_own_client(&self) -> SpotifyResult<()> {
+        if self.injected_client {
+            return Err(SpotifyError::InjectedClient);
+        }
+        Ok(())
+    }
+
+    fn build_client(
+        proxy: Option<Proxy>,
+        redirect_policy: &Arc<RedirectPolicy>,
+        timeout: Duration,
+    ) -> reqwest::Result<Client> {
+        let redirect_policy = redirect_policy.clone();
+        let mut builder = Client::builder()
+            .timeout(timeout)
+            .redirect(RedirectPolicy::custom(move |attempt| {
+                redirect_policy.redirect(attempt)
+            }));
+        // Responses are decompressed by `reqwest`, which also advertises the encodings.
+        #[cfg(feature = "gzip")]
+        {
+            builder = builder.gzip(true);
+        }
+        #[cfg(feature = "brotli")]
+        {
+            builder = builder.brotli(true);
+        }
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy);
+        }
+        builder.build()
+    }
+
+    /// Routes all requests made by this client through the given proxy.
+    ///
+    /// This applies to both API and authorization requests.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Communication`] if the proxy URL is invalid or the
+    /// underlying HTTP client could not be rebuilt, or a [`SpotifyError::InjectedClient`] if
+    /// it was supplied by the caller.
+    pub fn with_proxy(mut self, url: &str) -> SpotifyResult<Self> {
+        self.ensure_own_client()?;
+        let proxy = Proxy::all(url)?;
+        self.client = Self::build_client(Some(proxy.clone()), &self.redirect_policy, self.timeout)?;
+        self.proxy = Some(proxy);
+        Ok(self)
+    }
+
+    /// Sets the policy for following HTTP redirects.
+    ///
+    /// Redirects are not followed by default, so that a `301 Moved Permanently` response
+    /// is reported as [`ApiError::MovedPermanently`] along with its `Location` rather than
+    /// being followed silently.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Communication`] if the underlying HTTP client could not be
+    /// rebuilt, or a [`SpotifyError::InjectedClient`] if it was supplied by the caller.
+    pub fn redirect_policy(mut self, policy: RedirectPolicy) -> SpotifyResult<Self> {
+        self.ensure_own_client()?;
+        self.redirect_policy = Arc::new(policy);
+        self.client = Self::build_client(self.proxy.clone(), &self.redirect_policy, self.timeout)?;
+        Ok(self)
+    }
+
+    /// Sets the total time allowed for each request, from connecting until the response
+    /// body has been read. The default is 10 seconds.
+    ///
+    /// This applies to both API and authorization requests. A request which takes longer
+    /// fails with a [`RestError::Timeout`] error.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Communication`] if the underlying HTTP client could not be
+    /// rebuilt, or a [`SpotifyError::InjectedClient`] if it was supplied by the caller.
+    pub fn with_timeout(mut self, timeout: Duration) -> SpotifyResult<Self> {
+        self.ensure_own_client()?;
+        self.client = Self::build_client(self.proxy.clone(), &self.redirect_policy, timeout)?;
+        self.timeout = timeout;
+        Ok(self)
+    }
+
+    /// Retries requests which are rate limited with `429 Too Many Requests`, waiting between
+    /// attempts as described by `config`.
+    ///
+    /// Requests are not retried by default. Once `config.max_retries` is reached, the
+    /// `429` response is returned as usual.
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Sets how long before it expires the access token is refreshed. The default is 30 seconds.
+    ///
+    /// The token is refreshed before a request is sent once it expires within `skew`, so that
+    /// it does not expire while the request is in flight.
+    pub fn with_refresh_skew(mut self, skew: Duration) -> Self {
+        self.refresh_skew = skew;
+        self
+    }
+
+    /// Sets a handler to be called when a request finds the access token expired and there is
+    /// no refresh token to renew it.
+    ///
+    /// The handler receives the expired token, and the request then fails with
+    /// [`AuthError::ExpiredAccessToken`] without being sent. This happens when a token without
+    /// a refresh token was set with `with_token`. The client credentials flow never calls the
+    /// handler, as its tokens are requested again instead.
+    pub fn on_token_expired(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
+        self.token_expired_callback = Some(Arc::new(handler));
+        self
+    }
+
     /// Perform a REST query with a given auth.
     fn rest_auth(
         &self,
         mut request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<HttpResponse<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        #[cfg(all(debug_assertions, feature = "tokio"))]
+        warn_if_in_async_runtime(&ASYNC_RUNTIME_WARNED);
+
         let is_expired = self
             .token
             .read()
             .as_ref()
             .ok_or(AuthError::EmptyAccessToken)?
-            .is_expired();
-
-        let refresh_token = if is_expired {
-            self.token
-                .read()
-                .as_ref()
-                .ok_or(AuthError::EmptyAccessToken)?
-                .refresh_token
-                .clone()
+            .expires_within(self.refresh_skew);
+
+        let (refresh_token, expired_token) = if is_expired {
+            let token = self.token.read();
+            let token = token.as_ref().ok_or(AuthError::EmptyAccessToken)?;
+            // A token without an expiry time is still sent, and left to Spotify to reject.
+            let expired_token = (token.refresh_token.is_none() && token.expires_at.is_some())
+                .then(|| token.clone());
+            (token.refresh_token.clone(), expired_token)
         } else {
-            None
+            (None, None)
         };
 
+        if let Some(token) = expired_token {
+            match self.auth.reacquire_token(&self.client) {
+                Some(new_token) => self.set_refreshed_token(new_token?),
+                None if token.is_expired() => {
+                    if let Some(callback) = &self.token_expired_callback {
+                        callback(token);
+                    }
+                    return Err(AuthError::ExpiredAccessToken.into());
+                }
+                None => {}
+            }
+        }
+
         if let Some(refresh_token) = refresh_token {
             let new_token = self.auth.refresh_token(&self.client, &refresh_token)?;
-            self.set_token(new_token);
+            self.set_refreshed_token(new_token);
         }
 
         let call = || -> Result<_, RestError> {
@@ -231,23 +701,19 @@ where
                     .expect("failed to get headers on the request builder"),
             )?;
 
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request)?;
-
-            let mut http_rsp = HttpResponse::builder()
-                .status(rsp.status())
-                .version(rsp.version());
+            let request = request.body(body)?;
 
-            let headers = http_rsp
-                .headers_mut()
-                .expect("failed to get headers on the request builder");
+            let mut attempt = 0;
+            loop {
+                let (builder, data) = clone_request(&request);
+                let rsp = send_http_request(&self.client, builder, data)?;
 
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
+                match self.retry.and_then(|retry| retry.delay(attempt, &rsp)) {
+                    Some(delay) => std::thread::sleep(delay),
+                    None => return Ok(rsp),
+                }
+                attempt += 1;
             }
-
-            Ok(http_rsp.body(rsp.bytes()?)?)
         };
 
         call().map_err(ApiError::client)
@@ -286,6 +752,23 @@ where
         self.token.clone()
     }
 
+    /// Returns the scopes which have been granted for the current access token.
+    ///
+    /// This is the same as [`RestClient::granted_scopes`], without having to import the trait.
+    /// It is `None` when no token is set, or when the token does not list its scopes, as with
+    /// the Client Credentials flow. Scopes unknown to this library are skipped.
+    pub fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        RestClient::granted_scopes(self)
+    }
+
+    /// Returns the lifetime of the current access token and how often it has been refreshed.
+    ///
+    /// The refresh count includes automatic refreshes of expired tokens and explicit calls to
+    /// `refresh_token`, and is shared between clones of the client.
+    pub fn token_metrics(&self) -> TokenMetrics {
+        TokenMetrics::new(self.token.read().as_ref(), &self.refresh_count)
+    }
+
     /// Serializes the currently stored access token to a JSON string.
     ///
     /// This method converts the access token into a `String` in JSON format, allowing
@@ -298,17 +781,249 @@ where
     /// * `Ok(Some(String))` - The serialized token string, if available.
     /// * `Ok(None)` - If no token is currently stored.
     pub fn token_to_string(&self) -> SpotifyResult<Option<String>> {
-        let token = self.token.read();
-        let Some(token) = token.as_ref() else {
-            return Ok(None);
-        };
-        let s = serde_json::to_string(token).map_err(SpotifyError::data_type::<Token>)?;
-        Ok(Some(s))
+        self.token.read().as_ref().map(Token::to_json).transpose()
+    }
+
+    /// Sets the access token for the Spotify client and returns the updated instance.
+    ///
+    /// For the user authorization flows, the scopes in the token override the configured
+    /// scopes. For the Client Credentials flow, the `refresh_token` and `scope` fields of the
+    /// token are set to `None`, and a new token is requested once it has expired.
+    pub fn with_token(mut self, mut token: Token) -> Self {
+        self.auth.accept_token(&mut token);
+        self.token = Arc::new(RwLock::new(Some(token)));
+        self
+    }
+
+    /// Parses a token serialized with [`Self::token_to_string`] and sets it as with
+    /// [`Self::with_token`].
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::DataType` if the string is not a valid token.
+    pub fn with_token_str(self, token: &str) -> SpotifyResult<Self> {
+        Ok(self.with_token(Token::from_json_str(token)?))
+    }
+
+    /// Overrides the base URL of the Spotify Web API.
+    ///
+    /// API requests are sent to this URL instead of `https://api.spotify.com/v1/`, which is
+    /// mostly useful for pointing the client at a mock server in tests, together with
+    /// [`Self::with_accounts_url`]. A trailing slash is appended to the path if it is missing,
+    /// so that endpoints are resolved below it.
+    pub fn with_base_url(mut self, url: Url) -> Self {
+        self.api_url = with_trailing_slash(url);
+        self
+    }
+
+    /// Overrides the base URL of the Spotify Accounts service.
+    ///
+    /// Authorization and token requests are sent to this URL instead of
+    /// `https://accounts.spotify.com/`, which is mostly useful for pointing the client
+    /// at a mock server in tests. A trailing slash is appended to the path if it is missing.
+    pub fn with_accounts_url(mut self, url: Url) -> Self {
+        self.auth.set_accounts_url(with_trailing_slash(url));
+        self
+    }
+
+    /// Fetches an artist's entire discography with duplicate releases removed.
+    ///
+    /// This pages through the artist's albums across every group (`album`, `single`,
+    /// `compilation` and `appears_on`) and removes duplicates using [`dedup_discography`],
+    /// preferring the `album` group when the same release appears more than once.
+    ///
+    /// # Parameters
+    /// - `artist_id`: The Spotify ID of the artist.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if any of the requests fail.
+    pub fn artist_discography_deduped(
+        &self,
+        artist_id: impl Into<String>,
+    ) -> SpotifyResult<Vec<SimplifiedAlbum>> {
+        let albums: Vec<SimplifiedAlbum> =
+            api::paged_all(discography_endpoint(artist_id)).query(self)?;
+        Ok(dedup_discography(albums))
+    }
+
+    /// Checks whether a playlist has changed since the given snapshot.
+    ///
+    /// Only the playlist's `snapshot_id` is fetched, which makes this much cheaper than
+    /// diffing the playlist's contents when polling for changes.
+    ///
+    /// # Parameters
+    /// - `playlist_id`: The Spotify ID of the playlist.
+    /// - `snapshot_id`: The snapshot ID of the playlist when it was last seen.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub fn playlist_changed_since(
+        &self,
+        playlist_id: impl Into<String>,
+        snapshot_id: &str,
+    ) -> SpotifyResult<bool> {
+        let current: PlaylistSnapshot = playlist_snapshot_endpoint(playlist_id).query(self)?;
+        Ok(current.snapshot_id != snapshot_id)
+    }
+
+    /// Fetches the catalog object a Spotify share link or URI points to.
+    ///
+    /// Links such as `https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6` and URIs such as
+    /// `spotify:album:4aawyAB9vmqN3uQ7FjRGTy` are accepted, see [`id_from_url`].
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Id`] if the link is invalid or points to a user, or a
+    /// [`SpotifyError::Api`] if the request fails.
+    pub fn get_by_url(&self, url: &str) -> SpotifyResult<SpotifyResource> {
+        Ok(match id_from_url(url)? {
+            AnyId::Track(id) => SpotifyResource::Track(GetTrack::from(id.id()).query(self)?),
+            AnyId::Album(id) => SpotifyResource::Album(GetAlbum::from(id.id()).query(self)?),
+            AnyId::Artist(id) => SpotifyResource::Artist(GetArtist::from(id.id()).query(self)?),
+            AnyId::Playlist(id) => {
+                SpotifyResource::Playlist(GetPlaylist::from(id.id()).query(self)?)
+            }
+            #[cfg(feature = "shows")]
+            AnyId::Show(id) => SpotifyResource::Show(GetShow::from(id.id()).query(self)?),
+            #[cfg(feature = "shows")]
+            AnyId::Episode(id) => SpotifyResource::Episode(GetEpisode::from(id.id()).query(self)?),
+            id => return Err(IdError::UnsupportedItemType(id._type().into()).into()),
+        })
+    }
+
+    /// Fetches the profile of the current user.
+    ///
+    /// This requires a flow which authorizes a user, such as the Authorization Code flow.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{Spotify, auth::scopes};
+    ///
+    /// # fn example() -> spotify_web_api::SpotifyResult<()> {
+    /// let spotify = Spotify::with_authorization_code_pkce(
+    ///     "your-client-id",
+    ///     "your-redirect-uri",
+    ///     scopes::user_details(),
+    /// )?;
+    /// // Authorize the user first, then:
+    /// let profile = spotify.current_user_profile()?;
+    /// println!("{:?}", profile.display_name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub fn current_user_profile(&self) -> SpotifyResult<CurrentUserProfile> {
+        Ok(GetCurrentUserProfile.query(self)?)
+    }
+
+    /// Fetches a track from the catalog.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{Spotify, model::TrackId};
+    ///
+    /// # fn example() -> spotify_web_api::SpotifyResult<()> {
+    /// let spotify = Spotify::with_client_credentials("your-client-id", "your-client-secret")?;
+    /// spotify.request_token()?;
+    ///
+    /// let id = TrackId::from_id("11dFghVXANMlKmJXsNCbNl")?;
+    /// let track = spotify.track(&id)?;
+    /// println!("{}", track.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub fn track(&self, id: &TrackId) -> SpotifyResult<Track> {
+        Ok(GetTrack::from(id.id()).query(self)?)
+    }
+
+    /// Fetches an album from the catalog.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub fn album(&self, id: &AlbumId) -> SpotifyResult<Album> {
+        Ok(GetAlbum::from(id.id()).query(self)?)
+    }
+
+    /// Validates a genre seed for [`GetRecommendations`](api::recommendations::GetRecommendations).
+    ///
+    /// The available genre seeds are fetched on first use and cached on this client, which is
+    /// shared with its clones.
+    ///
+    /// # Errors
+    /// Returns [`GenreError::Unknown`] if `name` is not an available genre seed, or
+    /// [`GenreError::Api`] if the available genre seeds could not be fetched.
+    pub fn genre(&self, name: impl Into<String>) -> Result<Genre, GenreError<RestError>> {
+        self.genre_seeds.genre(self, name)
+    }
+
+    /// Searches the catalog for items of the given types matching `query`.
+    ///
+    /// Use [`SearchForItem`] directly to also filter by market, page through the results or
+    /// include external audio.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{Spotify, model::SearchType};
+    ///
+    /// # fn example() -> spotify_web_api::SpotifyResult<()> {
+    /// let spotify = Spotify::with_client_credentials("your-client-id", "your-client-secret")?;
+    /// spotify.request_token()?;
+    ///
+    /// let results = spotify.search("tania bowra", [SearchType::Artist])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub fn search(
+        &self,
+        query: impl Into<String>,
+        search_types: impl IntoIterator<Item = SearchType>,
+    ) -> SpotifyResult<SearchResults> {
+        Ok(SearchForItem::new(query, search_types).query(self)?)
+    }
+
+    /// Reports in which of the given markets a track can be played.
+    ///
+    /// With the `markets` feature the track is fetched once and its `available_markets` are
+    /// checked. Otherwise the track is fetched once per market, and its availability is read
+    /// from the relinking information Spotify returns, see [`Playable`](crate::model::Playable).
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if a request fails.
+    pub fn track_availability(
+        &self,
+        track_id: &str,
+        markets: &[Market],
+    ) -> SpotifyResult<HashMap<Market, bool>> {
+        #[cfg(feature = "markets")]
+        {
+            let track: Track = GetTrack::from(track_id).query(self)?;
+            Ok(markets_availability(&track, markets))
+        }
+
+        #[cfg(not(feature = "markets"))]
+        {
+            let mut availability = HashMap::with_capacity(markets.len());
+            for market in markets {
+                let track: Track = GetTrack {
+                    id: track_id.to_owned(),
+                    market: Some(market.clone()),
+                }
+                .query(self)?;
+                availability.insert(market.clone(), track.is_available());
+            }
+            Ok(availability)
+        }
     }
 
     fn set_token(&self, mut token: Token) {
-        token.expires_at = chrono::Utc::now()
-            .checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
+        token.expires_at =
+            Utc::now().checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
 
         if let Some(callback) = &self.token_callback {
             callback(token.clone());
@@ -316,8 +1031,14 @@ where
 
         *self.token.write() = Some(token);
     }
+
+    fn set_refreshed_token(&self, token: Token) {
+        self.refresh_count.fetch_add(1, Ordering::Relaxed);
+        self.set_token(token);
+    }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Spotify<AuthCodePKCE> {
     /// Creates a new instance of `Spotify` configured for the Authorization Code PKCE flow.
     ///
@@ -353,44 +1074,149 @@ impl Spotify<AuthCodePKCE> {
         Self::new_impl(auth)
     }
 
-    /// Sets the access token for the Spotify client and returns the updated instance.
+    /// Creates a new instance of `Spotify` using Authorization Code with PKCE authentication
+    /// and the given HTTP client.
     ///
-    /// This method allows chaining by consuming the current instance, updating the
-    /// stored access token, and returning the updated instance.
+    /// The client is used as is for both API and authorization requests, so it can carry its own
+    /// TLS roots, proxy, timeout or connection pool settings. It must be built with
+    /// `redirect(reqwest::redirect::Policy::none())`, otherwise a `301 Moved Permanently` is
+    /// followed instead of being reported as [`ApiError::MovedPermanently`].
     ///
-    /// The scopes in the token will override the scopes in the `AuthCodePKCE`.
+    /// [`Spotify::with_proxy`], [`Spotify::redirect_policy`] and [`Spotify::with_timeout`] return
+    /// [`SpotifyError::InjectedClient`] for such a client, as they would have to replace it.
     ///
-    /// # Parameters
-    /// * `token` - The new access token to be stored in the client.
+    /// # Errors
+    /// Returns a [`SpotifyError`] if initialization fails.
+    pub fn with_client_and_authorization_code_pkce(
+        client: Client,
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCodePKCE::new(client_id, redirect_uri, scopes);
+        Self::from_injected_client(auth, client)
+    }
+
+    /// Creates a new instance of `Spotify` from a previously obtained refresh token.
     ///
-    /// # Returns
-    /// The updated `Spotify` instance with the new token set.
-    pub fn with_token(mut self, token: Token) -> Self {
-        let mut scopes = HashSet::new();
-
-        if let Some(scope_str) = &token.scope {
-            for s in scope_str.split_whitespace() {
-                if let Ok(scope) = Scope::try_from(s) {
-                    scopes.insert(scope);
-                }
-            }
-        }
-        self.auth.set_scopes(Some(scopes));
-        self.token = Arc::new(RwLock::new(Some(token)));
-        self
+    /// The client is configured for the Authorization Code PKCE flow and immediately exchanges
+    /// the refresh token for a new access token, so it is ready to use without going through
+    /// user authorization again. The scopes are taken from the returned token.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if the client cannot be created or the refresh request fails.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::SpotifyPKCE;
+    ///
+    /// let spotify = SpotifyPKCE::from_refresh_token("your-client-id", "your-redirect-uri", "refresh-token")
+    ///     .expect("Failed to create Spotify client");
+    /// ```
+    pub fn from_refresh_token(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> SpotifyResult<Self> {
+        Self::with_authorization_code_pkce(client_id, redirect_uri, None)?
+            .with_refresh_token(refresh_token)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Spotify<AuthCode> {
+    /// Creates a new instance of `Spotify` configured for the Authorization Code flow.
+    ///
+    /// The Authorization Code flow is suited for server-side applications which can securely
+    /// store the client secret. Unlike the PKCE flow, the client secret is sent with every token
+    /// request instead of a code verifier.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `client_secret`: The Client Secret of your Spotify application.
+    /// - `redirect_uri`: The URI to which the user will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{Spotify, auth::scopes};
+    ///
+    /// let spotify = Spotify::with_authorization_code(
+    ///     "your-client-id",
+    ///     "your-client-secret",
+    ///     "your-redirect-uri",
+    ///     scopes::user_details(),
+    /// )
+    /// .expect("Failed to create Spotify client");
+    /// ```
+    pub fn with_authorization_code(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCode::new(client_id, client_secret, redirect_uri, scopes);
+        Self::new_impl(auth)
+    }
+
+    /// Creates a new instance of `Spotify` using Authorization Code authentication and the
+    /// given HTTP client.
+    ///
+    /// The client is used as is for both API and authorization requests, so it can carry its own
+    /// TLS roots, proxy, timeout or connection pool settings. It must be built with
+    /// `redirect(reqwest::redirect::Policy::none())`, otherwise a `301 Moved Permanently` is
+    /// followed instead of being reported as [`ApiError::MovedPermanently`].
+    ///
+    /// [`Spotify::with_proxy`], [`Spotify::redirect_policy`] and [`Spotify::with_timeout`] return
+    /// [`SpotifyError::InjectedClient`] for such a client, as they would have to replace it.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if initialization fails.
+    pub fn with_client_and_authorization_code(
+        client: Client,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCode::new(client_id, client_secret, redirect_uri, scopes);
+        Self::from_injected_client(auth, client)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<A> Spotify<A>
+where
+    A: UserAuthFlow,
+{
+    /// Exchanges a refresh token for a new access token and returns the updated instance.
+    ///
+    /// If Spotify does not issue a new refresh token, the given one is kept so that the
+    /// token can be refreshed again later. See [`Spotify::from_refresh_token`].
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if the refresh request fails.
+    pub fn with_refresh_token(self, refresh_token: impl Into<String>) -> SpotifyResult<Self> {
+        let refresh_token = refresh_token.into();
+        let mut token = self.auth.refresh_token(&self.client, &refresh_token)?;
+        token.refresh_token.get_or_insert(refresh_token);
+
+        let spotify = self.with_token(token.clone());
+        spotify.set_token(token);
+        Ok(spotify)
     }
 
     /// Sets a handler to be called when the access token acquires a new value.
-    pub fn token_callback(mut self, handler: impl Fn(Token) + 'static) -> Self {
-        self.token_callback = Some(Box::new(handler));
+    pub fn token_callback(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
+        self.token_callback = Some(Arc::new(handler));
         self
     }
 
     /// Constructs the full URL for user authorization.
     ///
-    /// This method generates the state and code verifier parameters to produce the complete
-    /// authorization URL. The user should be redirected to this URL to begin the authorization
-    /// process.
+    /// This method generates the state parameter, and the code verifier for the PKCE flow, to
+    /// produce the complete authorization URL. The user should be redirected to this URL to begin
+    /// the authorization process.
     ///
     /// # Returns
     /// * `String` - The fully constructed authorization URL.
@@ -412,7 +1238,7 @@ impl Spotify<AuthCodePKCE> {
     /// * `Err(AuthError)` if the `code` or `state` is missing, or if the `state` does not match.
     ///
     /// # Errors
-    /// * `AuthError::NoState` - Returned if the `self.state` is None.
+    /// * `AuthError::NoState` - Returned if no user authorization URL was generated.
     /// * `AuthError::CodeNotFound` - Returned if the `code` parameter is missing in the URL.
     /// * `AuthError::InvalidState` - Returned if the `state` parameter is missing or does not match
     ///   the expected value.
@@ -424,7 +1250,8 @@ impl Spotify<AuthCodePKCE> {
     ///
     /// This method exchanges the authorization code obtained from the callback URL for an access token.
     /// The access token is required to authenticate API requests. The obtained token is stored
-    /// internally and is valid for the duration specified by Spotify.
+    /// internally and is valid for the duration specified by Spotify. The Authorization Code flow
+    /// authenticates the request with the client credentials, the PKCE flow with the code verifier.
     ///
     /// # Arguments
     /// * `code` - A string slice containing the authorization code provided by Spotify.
@@ -442,7 +1269,8 @@ impl Spotify<AuthCodePKCE> {
     ///
     /// This method extracts the authorization code from the callback URL and exchanges it for an
     /// access token. It combines the `verify_authorization_code` and `request_token` methods
-    /// for convenience, handling both verification and token retrieval in a single call.
+    /// for convenience, handling both verification and token retrieval in a single call, and also
+    /// checks that the URL was sent to the configured redirect URI.
     ///
     /// # Arguments
     /// * `url` - A string slice containing the callback URL redirected to by Spotify after user authorization.
@@ -480,12 +1308,43 @@ impl Spotify<AuthCodePKCE> {
             .ok_or(AuthError::EmptyRefreshToken)?;
 
         let token = self.auth.refresh_token(&self.client, &refresh_token)?;
-        self.set_token(token);
+        self.set_refreshed_token(token);
 
         Ok(())
     }
+
+    /// Fetches the current user's top tracks over the given time range.
+    ///
+    /// Up to `limit` tracks are returned, fetching several pages if needed.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if a request fails.
+    pub fn top_tracks(&self, time_range: TimeRange, limit: usize) -> SpotifyResult<Vec<Track>> {
+        let items: Vec<TopItem> =
+            paged_take(top_items_endpoint(TopItemType::Tracks, time_range), limit).query(self)?;
+        Ok(items
+            .into_iter()
+            .filter_map(|item| item.into_track().ok())
+            .collect())
+    }
+
+    /// Fetches the current user's top artists over the given time range.
+    ///
+    /// Up to `limit` artists are returned, fetching several pages if needed.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if a request fails.
+    pub fn top_artists(&self, time_range: TimeRange, limit: usize) -> SpotifyResult<Vec<Artist>> {
+        let items: Vec<TopItem> =
+            paged_take(top_items_endpoint(TopItemType::Artists, time_range), limit).query(self)?;
+        Ok(items
+            .into_iter()
+            .filter_map(|item| item.into_artist().ok())
+            .collect())
+    }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Spotify<ClientCredentials> {
     /// Creates a new instance of `Spotify` configured for the Client Credentials flow.
     ///
@@ -519,26 +1378,26 @@ impl Spotify<ClientCredentials> {
         Self::new_impl(auth)
     }
 
-    /// Sets the access token for the Spotify client and returns the updated instance.
+    /// Creates a new instance of `Spotify` using Client Credentials authentication and the
+    /// given HTTP client.
     ///
-    /// This method allows chaining by consuming the current instance, updating the
-    /// stored access token, and returning the updated instance.
+    /// The client is used as is for both API and authorization requests, so it can carry its own
+    /// TLS roots, proxy, timeout or connection pool settings. It must be built with
+    /// `redirect(reqwest::redirect::Policy::none())`, otherwise a `301 Moved Permanently` is
+    /// followed instead of being reported as [`ApiError::MovedPermanently`].
     ///
-    /// The `refresh_token` and `scope` fields will be set to `None` in the token.
+    /// [`Spotify::with_proxy`], [`Spotify::redirect_policy`] and [`Spotify::with_timeout`] return
+    /// [`SpotifyError::InjectedClient`] for such a client, as they would have to replace it.
     ///
-    /// # Parameters
-    /// * `token` - The new access token to be stored in the client.
-    ///
-    /// # Returns
-    /// The updated `Spotify` instance with the new token set.
-    ///
-    /// # Note:
-    /// Once the token is expired, subsequent requests will fail.
-    pub fn with_token(mut self, mut token: Token) -> Self {
-        token.refresh_token = None;
-        token.scope = None;
-        self.token = Arc::new(RwLock::new(Some(token)));
-        self
+    /// # Errors
+    /// Returns a [`SpotifyError`] if initialization fails.
+    pub fn with_client_and_credentials(
+        client: Client,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> SpotifyResult<Self> {
+        let auth = ClientCredentials::new(client_id, client_secret);
+        Self::from_injected_client(auth, client)
     }
 
     /// Requests an access token using the configured Client Credentials flow.
@@ -570,6 +1429,7 @@ impl Spotify<ClientCredentials> {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<A> RestClient for Spotify<A>
 where
     A: AuthFlow,
@@ -580,8 +1440,18 @@ where
         log::info!("REST api call {endpoint}");
         Ok(self.api_url.join(endpoint)?)
     }
+
+    /// The scopes of the current token, unless it does not list the scopes it was granted.
+    fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        self.token
+            .read()
+            .as_ref()
+            .filter(|token| token.scope.is_some())
+            .map(Token::scopes)
+    }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<A> api::Client for Spotify<A>
 where
     A: AuthFlow,
@@ -606,6 +1476,11 @@ where
 /// - [`AsyncSpotifyClientCredentials`] for app-only access (Client Credentials flow)
 ///
 /// See [`Spotify`] for a blocking version of this client.
+///
+/// The client can be cheaply cloned, for example to move it into a spawned task. Clones
+/// share the same HTTP client, access token and token callback, so a token refreshed by
+/// one clone is seen by all of them.
+#[derive(Clone)]
 pub struct AsyncSpotify<A>
 where
     A: AsyncAuthFlow,
@@ -623,7 +1498,53 @@ where
     token: Arc<RwLock<Option<Token>>>,
 
     /// A handler to call when the access token acquires a new value.
-    token_callback: Option<Box<dyn Fn(Token) + Send + Sync + 'static>>,
+    token_callback: Option<Arc<dyn Fn(Token) + Send + Sync + 'static>>,
+
+    /// A handler to call when the access token has expired and cannot be refreshed.
+    token_expired_callback: Option<Arc<dyn Fn(Token) + Send + Sync + 'static>>,
+
+    /// The number of times the access token has been refreshed.
+    refresh_count: Arc<AtomicUsize>,
+
+    /// The available genre seeds, once fetched.
+    genre_seeds: GenreSeedCache,
+
+    /// Whether the HTTP client was supplied by the caller, in which case it is never rebuilt.
+    #[cfg(not(target_arch = "wasm32"))]
+    injected_client: bool,
+
+    /// The proxy to route requests through, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy: Option<Proxy>,
+
+    /// The policy for following redirects.
+    #[cfg(not(target_arch = "wasm32"))]
+    redirect_policy: Arc<RedirectPolicy>,
+
+    /// The total time allowed for each request.
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Duration,
+
+    /// How rate limited requests are retried, if at all.
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    retry: Option<RetryConfig>,
+
+    /// How long before it expires the access token is refreshed.
+    refresh_skew: Duration,
+}
+
+impl<A> std::fmt::Debug for AsyncSpotify<A>
+where
+    A: AsyncAuthFlow,
+{
+    /// The access token, client secret and PKCE verifier are never printed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncSpotify")
+            .field("api_url", &self.api_url.as_str())
+            .field("auth", &std::any::type_name::<A>())
+            .field("token", &self.token.read().as_ref().map(|_| "<redacted>"))
+            .finish_non_exhaustive()
+    }
 }
 
 impl<A> AsyncSpotify<A>
@@ -631,20 +1552,170 @@ where
     A: AsyncAuthFlow + Sync,
 {
     fn new_impl(auth: A) -> SpotifyResult<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = Self::build_client(None, &Arc::new(RedirectPolicy::none()), DEFAULT_TIMEOUT)?;
+        // Browsers handle timeouts, proxies and redirects themselves.
+        #[cfg(target_arch = "wasm32")]
+        let client = AsyncClient::new();
+        Self::from_parts(auth, client)
+    }
+
+    fn from_parts(auth: A, client: AsyncClient) -> SpotifyResult<Self> {
         let api_url = Url::parse(BASE_API_URL)?;
-        let client = AsyncClient::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()?;
         let api = Self {
             client,
             api_url,
             auth,
             token: Arc::new(RwLock::new(None)),
             token_callback: None,
+            token_expired_callback: None,
+            refresh_count: Arc::default(),
+            genre_seeds: GenreSeedCache::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            injected_client: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxy: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            redirect_policy: Arc::new(RedirectPolicy::none()),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: DEFAULT_TIMEOUT,
+            #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+            retry: None,
+            refresh_skew: DEFAULT_REFRESH_SKEW,
         };
         Ok(api)
     }
 
+    fn from_injected_client(auth: A, client: AsyncClient) -> SpotifyResult<Self> {
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut api = Self::from_parts(auth, client)?;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            api.injected_client = true;
+        }
+        Ok(api)
+    }
+
+    /// Fails if the HTTP client was supplied by the caller, as it cannot be rebuilt.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn ensure_own_client(&self) -> SpotifyResult<()> {
+        if self.injected_client {
+            return Err(SpotifyError::InjectedClient);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_client(
+        proxy: Option<Proxy>,
+        redirect_policy: &Arc<RedirectPolicy>,
+        timeout: Duration,
+    ) -> reqwest::Result<AsyncClient> {
+        let redirect_policy = redirect_policy.clone();
+        let mut builder = AsyncClient::builder()
+            .timeout(timeout)
+            .redirect(RedirectPolicy::custom(move |attempt| {
+                redirect_policy.redirect(attempt)
+            }));
+        // Responses are decompressed by `reqwest`, which also advertises the encodings.
+        #[cfg(feature = "gzip")]
+        {
+            builder = builder.gzip(true);
+        }
+        #[cfg(feature = "brotli")]
+        {
+            builder = builder.brotli(true);
+        }
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy);
+        }
+        builder.build()
+    }
+
+    /// Routes all requests made by this client through the given proxy.
+    ///
+    /// This applies to both API and authorization requests.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Communication`] if the proxy URL is invalid or the
+    /// underlying HTTP client could not be rebuilt, or a [`SpotifyError::InjectedClient`] if
+    /// it was supplied by the caller.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_proxy(mut self, url: &str) -> SpotifyResult<Self> {
+        self.ensure_own_client()?;
+        let proxy = Proxy::all(url)?;
+        self.client = Self::build_client(Some(proxy.clone()), &self.redirect_policy, self.timeout)?;
+        self.proxy = Some(proxy);
+        Ok(self)
+    }
+
+    /// Sets the policy for following HTTP redirects.
+    ///
+    /// Redirects are not followed by default, so that a `301 Moved Permanently` response
+    /// is reported as [`ApiError::MovedPermanently`] along with its `Location` rather than
+    /// being followed silently.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Communication`] if the underlying HTTP client could not be
+    /// rebuilt, or a [`SpotifyError::InjectedClient`] if it was supplied by the caller.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn redirect_policy(mut self, policy: RedirectPolicy) -> SpotifyResult<Self> {
+        self.ensure_own_client()?;
+        self.redirect_policy = Arc::new(policy);
+        self.client = Self::build_client(self.proxy.clone(), &self.redirect_policy, self.timeout)?;
+        Ok(self)
+    }
+
+    /// Sets the total time allowed for each request, from connecting until the response
+    /// body has been read. The default is 10 seconds.
+    ///
+    /// This applies to both API and authorization requests. A request which takes longer
+    /// fails with a [`RestError::Timeout`] error.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Communication`] if the underlying HTTP client could not be
+    /// rebuilt, or a [`SpotifyError::InjectedClient`] if it was supplied by the caller.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(mut self, timeout: Duration) -> SpotifyResult<Self> {
+        self.ensure_own_client()?;
+        self.client = Self::build_client(self.proxy.clone(), &self.redirect_policy, timeout)?;
+        self.timeout = timeout;
+        Ok(self)
+    }
+
+    /// Retries requests which are rate limited with `429 Too Many Requests`, waiting between
+    /// attempts as described by `config`.
+    ///
+    /// Requests are not retried by default. Once `config.max_retries` is reached, the
+    /// `429` response is returned as usual. This requires the `tokio` feature, whose timer is
+    /// used to wait between attempts.
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Sets how long before it expires the access token is refreshed. The default is 30 seconds.
+    ///
+    /// The token is refreshed before a request is sent once it expires within `skew`, so that
+    /// it does not expire while the request is in flight.
+    pub fn with_refresh_skew(mut self, skew: Duration) -> Self {
+        self.refresh_skew = skew;
+        self
+    }
+
+    /// Sets a handler to be called when a request finds the access token expired and there is
+    /// no refresh token to renew it.
+    ///
+    /// The handler receives the expired token, and the request then fails with
+    /// [`AuthError::ExpiredAccessToken`] without being sent. This happens when a token without
+    /// a refresh token was set with `with_token`. The client credentials flow never calls the
+    /// handler, as its tokens are requested again instead.
+    pub fn on_token_expired(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
+        self.token_expired_callback = Some(Arc::new(handler));
+        self
+    }
+
     /// Perform a REST query with a given auth.
     async fn rest_async_auth(
         &self,
@@ -658,26 +1729,39 @@ where
             .read()
             .as_ref()
             .ok_or(AuthError::EmptyAccessToken)?
-            .is_expired();
-
-        let refresh_token = if is_expired {
-            self.token
-                .read()
-                .as_ref()
-                .ok_or(AuthError::EmptyAccessToken)?
-                .refresh_token
-                .clone()
+            .expires_within(self.refresh_skew);
+
+        let (refresh_token, expired_token) = if is_expired {
+            let token = self.token.read();
+            let token = token.as_ref().ok_or(AuthError::EmptyAccessToken)?;
+            // A token without an expiry time is still sent, and left to Spotify to reject.
+            let expired_token = (token.refresh_token.is_none() && token.expires_at.is_some())
+                .then(|| token.clone());
+            (token.refresh_token.clone(), expired_token)
         } else {
-            None
+            (None, None)
         };
 
+        if let Some(token) = expired_token {
+            match self.auth.reacquire_token_async(&self.client).await {
+                Some(new_token) => self.set_refreshed_token(new_token?),
+                None if token.is_expired() => {
+                    if let Some(callback) = &self.token_expired_callback {
+                        callback(token);
+                    }
+                    return Err(AuthError::ExpiredAccessToken.into());
+                }
+                None => {}
+            }
+        }
+
         if let Some(refresh_token) = refresh_token {
             let new_token = self
                 .auth
                 .refresh_token_async(&self.client, &refresh_token)
                 .await?;
 
-            self.set_token(new_token);
+            self.set_refreshed_token(new_token);
         }
 
         let call = || async {
@@ -687,23 +1771,26 @@ where
                     .expect("failed to get headers on the request builder"),
             )?;
 
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request).await?;
-
-            let mut http_rsp = HttpResponse::builder()
-                .status(rsp.status())
-                .version(rsp.version());
+            #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+            {
+                let request = request.body(body)?;
 
-            let headers = http_rsp
-                .headers_mut()
-                .expect("failed to get headers on the request builder");
+                let mut attempt = 0;
+                loop {
+                    let (builder, data) = clone_request(&request);
+                    let rsp = send_http_request_async(&self.client, builder, data).await?;
 
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
+                    match self.retry.and_then(|retry| retry.delay(attempt, &rsp)) {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Ok::<_, RestError>(rsp),
+                    }
+                    attempt += 1;
+                }
             }
 
-            Ok(http_rsp.body(rsp.bytes().await?)?)
+            // Without a timer to sleep on, as in browsers, rate limited requests are not retried.
+            #[cfg(not(all(feature = "tokio", not(target_arch = "wasm32"))))]
+            send_http_request_async(&self.client, request, body).await
         };
 
         call().map_err(ApiError::client).await
@@ -742,6 +1829,23 @@ where
         self.token.clone()
     }
 
+    /// Returns the scopes which have been granted for the current access token.
+    ///
+    /// This is the same as [`RestClient::granted_scopes`], without having to import the trait.
+    /// It is `None` when no token is set, or when the token does not list its scopes, as with
+    /// the Client Credentials flow. Scopes unknown to this library are skipped.
+    pub fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        RestClient::granted_scopes(self)
+    }
+
+    /// Returns the lifetime of the current access token and how often it has been refreshed.
+    ///
+    /// The refresh count includes automatic refreshes of expired tokens and explicit calls to
+    /// `refresh_token`, and is shared between clones of the client.
+    pub fn token_metrics(&self) -> TokenMetrics {
+        TokenMetrics::new(self.token.read().as_ref(), &self.refresh_count)
+    }
+
     /// Serializes the currently stored access token to a JSON string.
     ///
     /// This method converts the access token into a `String` in JSON format, allowing
@@ -754,17 +1858,53 @@ where
     /// * `Ok(Some(String))` - The serialized token string, if available.
     /// * `Ok(None)` - If no token is currently stored.
     pub fn token_to_string(&self) -> SpotifyResult<Option<String>> {
-        let token = self.token.read();
-        let Some(token) = token.as_ref() else {
-            return Ok(None);
-        };
-        let s = serde_json::to_string(token).map_err(SpotifyError::data_type::<Token>)?;
-        Ok(Some(s))
+        self.token.read().as_ref().map(Token::to_json).transpose()
+    }
+
+    /// Sets the access token for the Spotify client and returns the updated instance.
+    ///
+    /// For the user authorization flows, the scopes in the token override the configured
+    /// scopes. For the Client Credentials flow, the `refresh_token` and `scope` fields of the
+    /// token are set to `None`, and a new token is requested once it has expired.
+    pub fn with_token(mut self, mut token: Token) -> Self {
+        self.auth.accept_token(&mut token);
+        self.token = Arc::new(RwLock::new(Some(token)));
+        self
+    }
+
+    /// Parses a token serialized with [`Self::token_to_string`] and sets it as with
+    /// [`Self::with_token`].
+    ///
+    /// # Errors
+    /// Returns a `SpotifyError::DataType` if the string is not a valid token.
+    pub fn with_token_str(self, token: &str) -> SpotifyResult<Self> {
+        Ok(self.with_token(Token::from_json_str(token)?))
+    }
+
+    /// Overrides the base URL of the Spotify Web API.
+    ///
+    /// API requests are sent to this URL instead of `https://api.spotify.com/v1/`, which is
+    /// mostly useful for pointing the client at a mock server in tests, together with
+    /// [`Self::with_accounts_url`]. A trailing slash is appended to the path if it is missing,
+    /// so that endpoints are resolved below it.
+    pub fn with_base_url(mut self, url: Url) -> Self {
+        self.api_url = with_trailing_slash(url);
+        self
+    }
+
+    /// Overrides the base URL of the Spotify Accounts service.
+    ///
+    /// Authorization and token requests are sent to this URL instead of
+    /// `https://accounts.spotify.com/`, which is mostly useful for pointing the client
+    /// at a mock server in tests. A trailing slash is appended to the path if it is missing.
+    pub fn with_accounts_url(mut self, url: Url) -> Self {
+        self.auth.set_accounts_url(with_trailing_slash(url));
+        self
     }
 
     fn set_token(&self, mut token: Token) {
-        token.expires_at = chrono::Utc::now()
-            .checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
+        token.expires_at =
+            Utc::now().checked_add_signed(chrono::Duration::seconds(token.expires_in as i64));
 
         if let Some(callback) = &self.token_callback {
             callback(token.clone());
@@ -772,102 +1912,434 @@ where
 
         *self.token.write() = Some(token);
     }
+
+    fn set_refreshed_token(&self, token: Token) {
+        self.refresh_count.fetch_add(1, Ordering::Relaxed);
+        self.set_token(token);
+    }
 }
 
-impl AsyncSpotify<AuthCodePKCE> {
-    /// Creates a new instance of `Spotify` configured for the Authorization Code PKCE flow.
+impl<A> AsyncSpotify<A>
+where
+    A: AsyncAuthFlow + Sync + Send,
+{
+    /// Asynchronously fetches an artist's entire discography with duplicate releases removed.
     ///
-    /// This method initializes the `Spotify` client with an `AuthCodePKCE` authentication method.
-    /// The Authorization Code PKCE flow is typically used for client-side applications where user
-    /// authentication is required, and it uses a secure code challenge to mitigate interception risks.
+    /// This pages through the artist's albums across every group (`album`, `single`,
+    /// `compilation` and `appears_on`) and removes duplicates using [`dedup_discography`],
+    /// preferring the `album` group when the same release appears more than once.
     ///
     /// # Parameters
-    /// - `client_id`: The Client ID of your Spotify application.
-    /// - `redirect_uri`: The URI to which the user will be redirected after authentication.
-    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
-    ///
-    /// # Returns
-    /// A [`SpotifyResult`] containing the [`Spotify`] client configured with Authorization Code PKCE authentication,
-    /// or a [`SpotifyError`] if initialization fails.
-    ///
-    /// # Example
-    /// ```no_run
-    /// use spotify_web_api::{AsyncSpotify, auth::scopes};
-    ///
-    /// let client_id = "your-client-id";
-    /// let redirect_uri = "your-redirect-uri";
+    /// - `artist_id`: The Spotify ID of the artist.
     ///
-    /// let spotify = AsyncSpotify::with_authorization_code_pkce(client_id, redirect_uri, scopes::user_details())
-    ///     .expect("Failed to create Spotify client");
-    /// ```
-    pub fn with_authorization_code_pkce(
-        client_id: impl Into<String>,
-        redirect_uri: impl Into<String>,
-        scopes: impl Into<Option<HashSet<Scope>>>,
-    ) -> SpotifyResult<Self> {
-        let auth = AuthCodePKCE::new(client_id, redirect_uri, scopes);
-        Self::new_impl(auth)
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if any of the requests fail.
+    pub async fn artist_discography_deduped(
+        &self,
+        artist_id: impl Into<String>,
+    ) -> SpotifyResult<Vec<SimplifiedAlbum>> {
+        let albums: Vec<SimplifiedAlbum> = api::paged_all(discography_endpoint(artist_id))
+            .query_async(self)
+            .await?;
+        Ok(dedup_discography(albums))
     }
 
-    /// Sets the access token for the Spotify client and returns the updated instance.
+    /// Asynchronously checks whether a playlist has changed since the given snapshot.
     ///
-    /// This method allows chaining by consuming the current instance, updating the
-    /// stored access token, and returning the updated instance.
-    ///
-    /// The scopes in the token will override the scopes in the `AuthCodePKCE`.
+    /// Only the playlist's `snapshot_id` is fetched, which makes this much cheaper than
+    /// diffing the playlist's contents when polling for changes.
     ///
     /// # Parameters
-    /// * `token` - The new access token to be stored in the client.
+    /// - `playlist_id`: The Spotify ID of the playlist.
+    /// - `snapshot_id`: The snapshot ID of the playlist when it was last seen.
     ///
-    /// # Returns
-    /// The updated `Spotify` instance with the new token set.
-    pub fn with_token(mut self, token: Token) -> Self {
-        let mut scopes = HashSet::new();
-        if let Some(scope_str) = &token.scope {
-            for s in scope_str.split_whitespace() {
-                if let Ok(scope) = Scope::try_from(s) {
-                    scopes.insert(scope);
-                }
-            }
-        }
-        self.auth.set_scopes(Some(scopes));
-        self.token = Arc::new(RwLock::new(Some(token)));
-        self
-    }
-
-    /// Sets a handler to be called when the access token acquires a new value.
-    pub fn token_callback(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
-        self.token_callback = Some(Box::new(handler));
-        self
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub async fn playlist_changed_since(
+        &self,
+        playlist_id: impl Into<String>,
+        snapshot_id: &str,
+    ) -> SpotifyResult<bool> {
+        let current: PlaylistSnapshot = playlist_snapshot_endpoint(playlist_id)
+            .query_async(self)
+            .await?;
+        Ok(current.snapshot_id != snapshot_id)
     }
 
-    /// Constructs the full URL for user authorization.
+    /// Asynchronously fetches the catalog object a Spotify share link or URI points to.
     ///
-    /// This method generates the state and code verifier parameters to produce the complete
-    /// authorization URL. The user should be redirected to this URL to begin the authorization
-    /// process.
+    /// Links such as `https://open.spotify.com/track/6rqhFgbbKwnb9MLmUQDhG6` and URIs such as
+    /// `spotify:album:4aawyAB9vmqN3uQ7FjRGTy` are accepted, see [`id_from_url`].
     ///
-    /// # Returns
-    /// * `String` - The fully constructed authorization URL.
-    pub fn user_authorization_url(&mut self) -> String {
-        self.auth.user_authorization_url()
+    /// # Errors
+    /// Returns a [`SpotifyError::Id`] if the link is invalid or points to a user, or a
+    /// [`SpotifyError::Api`] if the request fails.
+    pub async fn get_by_url(&self, url: &str) -> SpotifyResult<SpotifyResource> {
+        Ok(match id_from_url(url)? {
+            AnyId::Track(id) => {
+                SpotifyResource::Track(GetTrack::from(id.id()).query_async(self).await?)
+            }
+            AnyId::Album(id) => {
+                SpotifyResource::Album(GetAlbum::from(id.id()).query_async(self).await?)
+            }
+            AnyId::Artist(id) => {
+                SpotifyResource::Artist(GetArtist::from(id.id()).query_async(self).await?)
+            }
+            AnyId::Playlist(id) => {
+                SpotifyResource::Playlist(GetPlaylist::from(id.id()).query_async(self).await?)
+            }
+            #[cfg(feature = "shows")]
+            AnyId::Show(id) => {
+                SpotifyResource::Show(GetShow::from(id.id()).query_async(self).await?)
+            }
+            #[cfg(feature = "shows")]
+            AnyId::Episode(id) => {
+                SpotifyResource::Episode(GetEpisode::from(id.id()).query_async(self).await?)
+            }
+            id => return Err(IdError::UnsupportedItemType(id._type().into()).into()),
+        })
     }
 
-    /// Verifies the authorization code and state returned in the callback URL.
-    ///
-    /// This method extracts the `code` and `state` parameters from the provided URL. It ensures
-    /// that the `state` matches the one generated earlier, rejecting the response if there is a
-    /// mismatch or if the required parameters are missing.
+    /// Fetches the profile of the current user.
     ///
-    /// # Arguments
-    /// * `url` - A string slice containing the callback URL provided by the OAuth provider.
+    /// This requires a flow which authorizes a user, such as the Authorization Code flow.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{AsyncSpotify, auth::scopes};
+    ///
+    /// # async fn example() -> spotify_web_api::SpotifyResult<()> {
+    /// let spotify = AsyncSpotify::with_authorization_code_pkce(
+    ///     "your-client-id",
+    ///     "your-redirect-uri",
+    ///     scopes::user_details(),
+    /// )?;
+    /// // Authorize the user first, then:
+    /// let profile = spotify.current_user_profile().await?;
+    /// println!("{:?}", profile.display_name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub async fn current_user_profile(&self) -> SpotifyResult<CurrentUserProfile> {
+        Ok(GetCurrentUserProfile.query_async(self).await?)
+    }
+
+    /// Fetches a track from the catalog.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{AsyncSpotify, model::TrackId};
+    ///
+    /// # async fn example() -> spotify_web_api::SpotifyResult<()> {
+    /// let spotify = AsyncSpotify::with_client_credentials("your-client-id", "your-client-secret")?;
+    /// spotify.request_token().await?;
+    ///
+    /// let id = TrackId::from_id("11dFghVXANMlKmJXsNCbNl")?;
+    /// let track = spotify.track(&id).await?;
+    /// println!("{}", track.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub async fn track(&self, id: &TrackId) -> SpotifyResult<Track> {
+        Ok(GetTrack::from(id.id()).query_async(self).await?)
+    }
+
+    /// Fetches an album from the catalog.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub async fn album(&self, id: &AlbumId) -> SpotifyResult<Album> {
+        Ok(GetAlbum::from(id.id()).query_async(self).await?)
+    }
+
+    /// Validates a genre seed for [`GetRecommendations`](api::recommendations::GetRecommendations).
+    ///
+    /// The available genre seeds are fetched on first use and cached on this client, which is
+    /// shared with its clones.
+    ///
+    /// # Errors
+    /// Returns [`GenreError::Unknown`] if `name` is not an available genre seed, or
+    /// [`GenreError::Api`] if the available genre seeds could not be fetched.
+    pub async fn genre(&self, name: impl Into<String>) -> Result<Genre, GenreError<RestError>> {
+        self.genre_seeds.genre_async(self, name).await
+    }
+
+    /// Searches the catalog for items of the given types matching `query`.
+    ///
+    /// Use [`SearchForItem`] directly to also filter by market, page through the results or
+    /// include external audio.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{AsyncSpotify, model::SearchType};
+    ///
+    /// # async fn example() -> spotify_web_api::SpotifyResult<()> {
+    /// let spotify = AsyncSpotify::with_client_credentials("your-client-id", "your-client-secret")?;
+    /// spotify.request_token().await?;
+    ///
+    /// let results = spotify.search("tania bowra", [SearchType::Artist]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if the request fails.
+    pub async fn search(
+        &self,
+        query: impl Into<String>,
+        search_types: impl IntoIterator<Item = SearchType>,
+    ) -> SpotifyResult<SearchResults> {
+        Ok(SearchForItem::new(query, search_types)
+            .query_async(self)
+            .await?)
+    }
+
+    /// Reports in which of the given markets a track can be played.
+    ///
+    /// See [`Spotify::track_availability`].
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if a request fails.
+    pub async fn track_availability(
+        &self,
+        track_id: &str,
+        markets: &[Market],
+    ) -> SpotifyResult<HashMap<Market, bool>> {
+        #[cfg(feature = "markets")]
+        {
+            let track: Track = GetTrack::from(track_id).query_async(self).await?;
+            Ok(markets_availability(&track, markets))
+        }
+
+        #[cfg(not(feature = "markets"))]
+        {
+            let mut availability = HashMap::with_capacity(markets.len());
+            for market in markets {
+                let track: Track = GetTrack {
+                    id: track_id.to_owned(),
+                    market: Some(market.clone()),
+                }
+                .query_async(self)
+                .await?;
+                availability.insert(market.clone(), track.is_available());
+            }
+            Ok(availability)
+        }
+    }
+}
+
+impl AsyncSpotify<AuthCodePKCE> {
+    /// Creates a new instance of `Spotify` configured for the Authorization Code PKCE flow.
+    ///
+    /// This method initializes the `Spotify` client with an `AuthCodePKCE` authentication method.
+    /// The Authorization Code PKCE flow is typically used for client-side applications where user
+    /// authentication is required, and it uses a secure code challenge to mitigate interception risks.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `redirect_uri`: The URI to which the user will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    ///
+    /// # Returns
+    /// A [`SpotifyResult`] containing the [`Spotify`] client configured with Authorization Code PKCE authentication,
+    /// or a [`SpotifyError`] if initialization fails.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{AsyncSpotify, auth::scopes};
+    ///
+    /// let client_id = "your-client-id";
+    /// let redirect_uri = "your-redirect-uri";
+    ///
+    /// let spotify = AsyncSpotify::with_authorization_code_pkce(client_id, redirect_uri, scopes::user_details())
+    ///     .expect("Failed to create Spotify client");
+    /// ```
+    pub fn with_authorization_code_pkce(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCodePKCE::new(client_id, redirect_uri, scopes);
+        Self::new_impl(auth)
+    }
+
+    /// Creates a new instance of `AsyncSpotify` using Authorization Code with PKCE authentication
+    /// and the given HTTP client.
+    ///
+    /// The client is used as is for both API and authorization requests, so it can carry its own
+    /// TLS roots, proxy, timeout or connection pool settings. It must be built with
+    /// `redirect(reqwest::redirect::Policy::none())`, otherwise a `301 Moved Permanently` is
+    /// followed instead of being reported as [`ApiError::MovedPermanently`].
+    ///
+    /// [`AsyncSpotify::with_proxy`], [`AsyncSpotify::redirect_policy`] and [`AsyncSpotify::with_timeout`] return
+    /// [`SpotifyError::InjectedClient`] for such a client, as they would have to replace it.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if initialization fails.
+    pub fn with_client_and_authorization_code_pkce(
+        client: AsyncClient,
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCodePKCE::new(client_id, redirect_uri, scopes);
+        Self::from_injected_client(auth, client)
+    }
+
+    /// Creates a new instance of `AsyncSpotify` from a previously obtained refresh token.
+    ///
+    /// The client is configured for the Authorization Code PKCE flow and immediately exchanges
+    /// the refresh token for a new access token, so it is ready to use without going through
+    /// user authorization again. The scopes are taken from the returned token.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if the client cannot be created or the refresh request fails.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::AsyncSpotifyPKCE;
+    ///
+    /// # async fn example() {
+    ///
+    /// let spotify = AsyncSpotifyPKCE::from_refresh_token("your-client-id", "your-redirect-uri", "refresh-token")
+    ///     .await
+    ///     .expect("Failed to create Spotify client");
+    /// # }
+    /// ```
+    pub async fn from_refresh_token(
+        client_id: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> SpotifyResult<Self> {
+        Self::with_authorization_code_pkce(client_id, redirect_uri, None)?
+            .with_refresh_token(refresh_token)
+            .await
+    }
+}
+
+impl AsyncSpotify<AuthCode> {
+    /// Creates a new instance of `AsyncSpotify` configured for the Authorization Code flow.
+    ///
+    /// The Authorization Code flow is suited for server-side applications which can securely
+    /// store the client secret. Unlike the PKCE flow, the client secret is sent with every token
+    /// request instead of a code verifier.
+    ///
+    /// # Parameters
+    /// - `client_id`: The Client ID of your Spotify application.
+    /// - `client_secret`: The Client Secret of your Spotify application.
+    /// - `redirect_uri`: The URI to which the user will be redirected after authentication.
+    /// - `scopes`: An optional set of scopes that define the permissions the application is requesting.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spotify_web_api::{AsyncSpotify, auth::scopes};
+    ///
+    /// let spotify = AsyncSpotify::with_authorization_code(
+    ///     "your-client-id",
+    ///     "your-client-secret",
+    ///     "your-redirect-uri",
+    ///     scopes::user_details(),
+    /// )
+    /// .expect("Failed to create Spotify client");
+    /// ```
+    pub fn with_authorization_code(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCode::new(client_id, client_secret, redirect_uri, scopes);
+        Self::new_impl(auth)
+    }
+
+    /// Creates a new instance of `AsyncSpotify` using Authorization Code authentication and the
+    /// given HTTP client.
+    ///
+    /// The client is used as is for both API and authorization requests, so it can carry its own
+    /// TLS roots, proxy, timeout or connection pool settings. It must be built with
+    /// `redirect(reqwest::redirect::Policy::none())`, otherwise a `301 Moved Permanently` is
+    /// followed instead of being reported as [`ApiError::MovedPermanently`].
+    ///
+    /// [`AsyncSpotify::with_proxy`], [`AsyncSpotify::redirect_policy`] and [`AsyncSpotify::with_timeout`] return
+    /// [`SpotifyError::InjectedClient`] for such a client, as they would have to replace it.
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if initialization fails.
+    pub fn with_client_and_authorization_code(
+        client: AsyncClient,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Option<HashSet<Scope>>>,
+    ) -> SpotifyResult<Self> {
+        let auth = AuthCode::new(client_id, client_secret, redirect_uri, scopes);
+        Self::from_injected_client(auth, client)
+    }
+}
+
+impl<A> AsyncSpotify<A>
+where
+    A: AsyncUserAuthFlow + Sync + Send,
+{
+    /// Exchanges a refresh token for a new access token and returns the updated instance.
+    ///
+    /// If Spotify does not issue a new refresh token, the given one is kept so that the
+    /// token can be refreshed again later. See [`AsyncSpotify::from_refresh_token`].
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError`] if the refresh request fails.
+    pub async fn with_refresh_token(self, refresh_token: impl Into<String>) -> SpotifyResult<Self> {
+        let refresh_token = refresh_token.into();
+        let mut token = self
+            .auth
+            .refresh_token_async(&self.client, &refresh_token)
+            .await?;
+        token.refresh_token.get_or_insert(refresh_token);
+
+        let spotify = self.with_token(token.clone());
+        spotify.set_token(token);
+        Ok(spotify)
+    }
+
+    /// Sets a handler to be called when the access token acquires a new value.
+    pub fn token_callback(mut self, handler: impl Fn(Token) + Send + Sync + 'static) -> Self {
+        self.token_callback = Some(Arc::new(handler));
+        self
+    }
+
+    /// Constructs the full URL for user authorization.
+    ///
+    /// This method generates the state parameter, and the code verifier for the PKCE flow, to
+    /// produce the complete authorization URL. The user should be redirected to this URL to begin
+    /// the authorization process.
+    ///
+    /// # Returns
+    /// * `String` - The fully constructed authorization URL.
+    pub fn user_authorization_url(&mut self) -> String {
+        self.auth.user_authorization_url()
+    }
+
+    /// Verifies the authorization code and state returned in the callback URL.
+    ///
+    /// This method extracts the `code` and `state` parameters from the provided URL. It ensures
+    /// that the `state` matches the one generated earlier, rejecting the response if there is a
+    /// mismatch or if the required parameters are missing.
+    ///
+    /// # Arguments
+    /// * `url` - A string slice containing the callback URL provided by the OAuth provider.
     ///
     /// # Returns
     /// * `Ok(String)` containing the authorization code if verification succeeds.
     /// * `Err(AuthError)` if the `code` or `state` is missing, or if the `state` does not match.
     ///
     /// # Errors
-    /// * `AuthError::NoState` - Returned if the `self.state` is None.
+    /// * `AuthError::NoState` - Returned if no user authorization URL was generated.
     /// * `AuthError::CodeNotFound` - Returned if the `code` parameter is missing in the URL.
     /// * `AuthError::InvalidState` - Returned if the `state` parameter is missing or does not match
     ///   the expected value.
@@ -879,7 +2351,8 @@ impl AsyncSpotify<AuthCodePKCE> {
     ///
     /// This method exchanges the authorization code obtained from the callback URL for an access token.
     /// The access token is required to authenticate API requests. The obtained token is stored
-    /// internally and is valid for the duration specified by Spotify.
+    /// internally and is valid for the duration specified by Spotify. The Authorization Code flow
+    /// authenticates the request with the client credentials, the PKCE flow with the code verifier.
     ///
     /// # Arguments
     /// * `code` - A string slice containing the authorization code provided by Spotify.
@@ -897,7 +2370,8 @@ impl AsyncSpotify<AuthCodePKCE> {
     ///
     /// This method extracts the authorization code from the callback URL and exchanges it for an
     /// access token. It combines the `verify_authorization_code` and `request_token` methods
-    /// for convenience, handling both verification and token retrieval in a single call.
+    /// for convenience, handling both verification and token retrieval in a single call, and also
+    /// checks that the URL was sent to the configured redirect URI.
     ///
     /// # Arguments
     /// * `url` - A string slice containing the callback URL redirected to by Spotify after user authorization.
@@ -917,6 +2391,35 @@ impl AsyncSpotify<AuthCodePKCE> {
         Ok(())
     }
 
+    /// Runs the whole authorization flow using a local server listening on the redirect URI.
+    ///
+    /// This binds to the host and port of the redirect URI, generates a new user authorization
+    /// URL and passes it to `open` (for example to launch it in a browser), then waits for
+    /// Spotify to redirect back to the local server and requests an access token.
+    ///
+    /// The flow is bounded by `timeout`, since the user may never complete it in the browser.
+    /// Dropping the returned future cancels the flow and closes the local server.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait for the authorization callback.
+    /// * `open` - Called with the user authorization URL once the local server is listening.
+    ///
+    /// # Errors
+    /// * `AuthError::Timeout` - If no callback arrives within `timeout`.
+    /// * `AuthError::Io` - If the local server could not listen on the redirect URI.
+    /// * `ApiError<RestError>` - If the callback is invalid or the token request fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn authorize_with_local_server(
+        &mut self,
+        timeout: Duration,
+        open: impl FnOnce(&str),
+    ) -> Result<(), ApiError<RestError>> {
+        let server = CallbackServer::bind(self.auth.redirect_uri())?;
+        open(&self.user_authorization_url());
+        let url = server.wait(timeout).await?;
+        self.request_token_from_redirect_url(&url).await
+    }
+
     /// Asynchronously refreshes the access token using the stored refresh token.
     ///
     /// This method retrieves a new access token by exchanging the stored refresh token.
@@ -943,10 +2446,52 @@ impl AsyncSpotify<AuthCodePKCE> {
             .refresh_token_async(&self.client, &refresh_token)
             .await?;
 
-        self.set_token(token);
+        self.set_refreshed_token(token);
 
         Ok(())
     }
+
+    /// Fetches the current user's top tracks over the given time range.
+    ///
+    /// See [`Spotify::top_tracks`].
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if a request fails.
+    pub async fn top_tracks(
+        &self,
+        time_range: TimeRange,
+        limit: usize,
+    ) -> SpotifyResult<Vec<Track>> {
+        let items: Vec<TopItem> =
+            paged_take(top_items_endpoint(TopItemType::Tracks, time_range), limit)
+                .query_async(self)
+                .await?;
+        Ok(items
+            .into_iter()
+            .filter_map(|item| item.into_track().ok())
+            .collect())
+    }
+
+    /// Fetches the current user's top artists over the given time range.
+    ///
+    /// See [`Spotify::top_artists`].
+    ///
+    /// # Errors
+    /// Returns a [`SpotifyError::Api`] if a request fails.
+    pub async fn top_artists(
+        &self,
+        time_range: TimeRange,
+        limit: usize,
+    ) -> SpotifyResult<Vec<Artist>> {
+        let items: Vec<TopItem> =
+            paged_take(top_items_endpoint(TopItemType::Artists, time_range), limit)
+                .query_async(self)
+                .await?;
+        Ok(items
+            .into_iter()
+            .filter_map(|item| item.into_artist().ok())
+            .collect())
+    }
 }
 
 impl AsyncSpotify<ClientCredentials> {
@@ -982,26 +2527,26 @@ impl AsyncSpotify<ClientCredentials> {
         Self::new_impl(auth)
     }
 
-    /// Sets the access token for the Spotify client and returns the updated instance.
-    ///
-    /// This method allows chaining by consuming the current instance, updating the
-    /// stored access token, and returning the updated instance.
+    /// Creates a new instance of `AsyncSpotify` using Client Credentials authentication and the
+    /// given HTTP client.
     ///
-    /// The `refresh_token`, and `scope` fields will be set to `None` in the token.
+    /// The client is used as is for both API and authorization requests, so it can carry its own
+    /// TLS roots, proxy, timeout or connection pool settings. It must be built with
+    /// `redirect(reqwest::redirect::Policy::none())`, otherwise a `301 Moved Permanently` is
+    /// followed instead of being reported as [`ApiError::MovedPermanently`].
     ///
-    /// # Parameters
-    /// * `token` - The new access token to be stored in the client.
+    /// [`AsyncSpotify::with_proxy`], [`AsyncSpotify::redirect_policy`] and [`AsyncSpotify::with_timeout`] return
+    /// [`SpotifyError::InjectedClient`] for such a client, as they would have to replace it.
     ///
-    /// # Returns
-    /// The updated `Spotify` instance with the new token set.
-    ///
-    /// # Note:
-    /// Once the token is expired, subsequent requests will fail.
-    pub fn with_token(mut self, mut token: Token) -> Self {
-        token.refresh_token = None;
-        token.scope = None;
-        self.token = Arc::new(RwLock::new(Some(token)));
-        self
+    /// # Errors
+    /// Returns a [`SpotifyError`] if initialization fails.
+    pub fn with_client_and_credentials(
+        client: AsyncClient,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> SpotifyResult<Self> {
+        let auth = ClientCredentials::new(client_id, client_secret);
+        Self::from_injected_client(auth, client)
     }
 
     /// Asynchronously requests an access token using the configured Client Credentials flow.
@@ -1031,6 +2576,15 @@ where
         log::info!("REST api call {endpoint}");
         Ok(self.api_url.join(endpoint)?)
     }
+
+    /// The scopes of the current token, unless it does not list the scopes it was granted.
+    fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        self.token
+            .read()
+            .as_ref()
+            .filter(|token| token.scope.is_some())
+            .map(Token::scopes)
+    }
 }
 
 #[async_trait]
@@ -1046,3 +2600,1353 @@ where
         self.rest_async_auth(request, body).await
     }
 }
+
+/// Appends a trailing slash to the path of a base URL, as [`Url::join`] otherwise replaces its
+/// last segment.
+fn with_trailing_slash(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url
+}
+
+// Fails the wasm32 build if one of the authorization flows stops being usable with
+// `AsyncSpotify` on that target.
+#[cfg(target_arch = "wasm32")]
+const _: () = {
+    const fn assert_async_client<C: api::AsyncClient + Send + Sync>() {}
+
+    assert_async_client::<AsyncSpotify<AuthCodePKCE>>();
+    assert_async_client::<AsyncSpotify<AuthCode>>();
+    assert_async_client::<AsyncSpotify<ClientCredentials>>();
+};
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::{
+        model::ItemType,
+        test::{
+            fixtures::{self, TRACK},
+            server::{
+                serve_bytes_once_with_headers, serve_once, serve_once_with_headers,
+                serve_responses, serve_times,
+            },
+        },
+    };
+
+    const TOKEN: &str = r#"{"access_token":"token","token_type":"Bearer","expires_in":3600}"#;
+
+    #[test]
+    fn token_request_uses_accounts_url() {
+        let (url, server) = serve_once(200, TOKEN);
+
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url);
+        spotify.request_token().unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /api/token HTTP/1.1"));
+        let token = spotify.token().read().clone().unwrap();
+        assert_eq!(token.access_token, "token");
+        assert_eq!(token.refresh_token, None);
+        assert!(token.scopes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn token_request_uses_accounts_url_async() {
+        let (url, server) = serve_once(200, TOKEN);
+
+        let spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(url);
+        spotify.request_token().await.unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /api/token HTTP/1.1"));
+    }
+
+    #[test]
+    fn token_metrics() {
+        const REFRESHED_TOKEN: &str = r#"{"access_token":"token","token_type":"Bearer","expires_in":3600,"refresh_token":"refresh_token"}"#;
+
+        let (url, server) = serve_times(2, 200, REFRESHED_TOKEN);
+
+        let spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_accounts_url(url)
+            .with_token(Token::from_json(REFRESHED_TOKEN).unwrap());
+
+        let metrics = spotify.token_metrics();
+        assert_eq!(metrics.refresh_count, 0);
+        assert_eq!(metrics.expires_at, None);
+
+        spotify.refresh_token().unwrap();
+        spotify.clone().refresh_token().unwrap();
+
+        assert_eq!(server.join().unwrap().len(), 2);
+        let metrics = spotify.token_metrics();
+        assert_eq!(metrics.refresh_count, 2);
+        let (issued_at, expires_at) = (metrics.issued_at.unwrap(), metrics.expires_at.unwrap());
+        assert_eq!(expires_at - issued_at, TimeDelta::hours(1));
+    }
+
+    #[test]
+    fn debug_redacts_token() {
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token(Token {
+                access_token: "secret-access-token".to_owned(),
+                refresh_token: Some("secret-refresh-token".to_owned()),
+                ..Default::default()
+            });
+
+        let debug = format!("{spotify:?}");
+        assert!(debug.contains("https://api.spotify.com/v1/"));
+        assert!(debug.contains("ClientCredentials"));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("secret"));
+
+        let spotify =
+            AsyncSpotify::with_authorization_code_pkce("client_id", "redirect_uri", None).unwrap();
+        let debug = format!("{spotify:?}");
+        assert!(debug.contains("https://api.spotify.com/v1/"));
+        assert!(debug.contains("token: None"));
+    }
+
+    #[test]
+    fn clones_share_token() {
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret").unwrap();
+        let clone = spotify.clone();
+
+        *spotify.token().write() = Some(Token {
+            access_token: "token".to_owned(),
+            ..Default::default()
+        });
+
+        assert!(Arc::ptr_eq(&spotify.token(), &clone.token()));
+        assert_eq!(clone.token().read().as_ref().unwrap().access_token, "token");
+
+        let spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret").unwrap();
+        let clone = spotify.clone();
+        assert!(Arc::ptr_eq(&spotify.token(), &clone.token()));
+    }
+
+    #[test]
+    fn clone_moves_into_thread() {
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let mut spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .token_callback(|_| {});
+        spotify.api_url = api_url;
+        *spotify.token().write() = Some(Token::from_json(TOKEN).unwrap());
+
+        let clone = spotify.clone();
+        std::thread::spawn(move || {
+            api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+                .query(&clone)
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+
+        assert!(api.join().unwrap().contains("Bearer token"));
+    }
+
+    #[tokio::test]
+    async fn local_server_authorization_times_out() {
+        let mut spotify = AsyncSpotify::with_authorization_code_pkce(
+            "client_id",
+            "http://127.0.0.1:0/callback",
+            None,
+        )
+        .unwrap();
+
+        let mut opened = None;
+        let err = spotify
+            .authorize_with_local_server(Duration::from_millis(100), |url| {
+                opened = Some(url.to_owned());
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Auth(AuthError::Timeout)));
+        assert!(
+            opened
+                .unwrap()
+                .starts_with("https://accounts.spotify.com/authorize?")
+        );
+        assert!(spotify.token().read().is_none());
+    }
+
+    #[test]
+    fn proxy() {
+        assert!(
+            Spotify::with_client_credentials("client_id", "client_secret")
+                .unwrap()
+                .with_proxy("http://127.0.0.1:8080")
+                .is_ok()
+        );
+        assert!(
+            AsyncSpotify::with_client_credentials("client_id", "client_secret")
+                .unwrap()
+                .with_proxy("http://127.0.0.1:8080")
+                .is_ok()
+        );
+
+        let err = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_proxy("http://[::1")
+            .err()
+            .unwrap();
+        assert!(matches!(err, SpotifyError::Communication(_)));
+    }
+
+    #[test]
+    fn redirects_are_not_followed() {
+        const LOCATION: &str = "http://127.0.0.1:1/tracks/11dFghVXANMlKmJXsNCbNl";
+        let (url, _server) = serve_once_with_headers(301, &[("Location", LOCATION)], "");
+        let spotify = playlist_client(url);
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap_err();
+
+        if let ApiError::MovedPermanently { location: got } = err {
+            assert_eq!(got.as_deref(), Some(LOCATION));
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+
+    #[test]
+    fn redirect_policy() {
+        assert!(
+            Spotify::with_client_credentials("client_id", "client_secret")
+                .unwrap()
+                .with_proxy("http://127.0.0.1:8080")
+                .unwrap()
+                .redirect_policy(RedirectPolicy::limited(5))
+                .is_ok()
+        );
+        assert!(
+            AsyncSpotify::with_client_credentials("client_id", "client_secret")
+                .unwrap()
+                .redirect_policy(RedirectPolicy::default())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn injected_client() {
+        let (url, server) = serve_once(200, TRACK);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-custom", HeaderValue::from_static("injected"));
+        let client = Client::builder().default_headers(headers).build().unwrap();
+
+        let mut spotify =
+            Spotify::with_client_and_credentials(client, "client_id", "client_secret")
+                .unwrap()
+                .with_token(Token::from_json(TOKEN).unwrap());
+        spotify.api_url = url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        assert!(server.join().unwrap().contains("x-custom: injected"));
+    }
+
+    #[test]
+    fn injected_client_without_redirects() {
+        const LOCATION: &str = "http://127.0.0.1:1/tracks/11dFghVXANMlKmJXsNCbNl";
+        let (url, _server) = serve_once_with_headers(301, &[("Location", LOCATION)], "");
+
+        let client = Client::builder()
+            .redirect(RedirectPolicy::none())
+            .build()
+            .unwrap();
+        let mut spotify =
+            Spotify::with_client_and_credentials(client, "client_id", "client_secret")
+                .unwrap()
+                .with_token(Token::from_json(TOKEN).unwrap());
+        spotify.api_url = url;
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap_err();
+
+        if let ApiError::MovedPermanently { location: got } = err {
+            assert_eq!(got.as_deref(), Some(LOCATION));
+        } else {
+            panic!("unexpected error: {err}");
+        }
+    }
+
+    #[test]
+    fn injected_client_is_not_rebuilt() {
+        let spotify = || {
+            Spotify::with_client_and_credentials(Client::new(), "client_id", "client_secret")
+                .unwrap()
+        };
+
+        assert!(matches!(
+            spotify().with_timeout(Duration::from_secs(1)),
+            Err(SpotifyError::InjectedClient)
+        ));
+        assert!(matches!(
+            spotify().with_proxy("http://127.0.0.1:8080"),
+            Err(SpotifyError::InjectedClient)
+        ));
+        assert!(matches!(
+            spotify().redirect_policy(RedirectPolicy::limited(5)),
+            Err(SpotifyError::InjectedClient)
+        ));
+
+        let spotify = AsyncSpotify::with_client_and_credentials(
+            AsyncClient::new(),
+            "client_id",
+            "client_secret",
+        )
+        .unwrap();
+        assert!(matches!(
+            spotify.with_timeout(Duration::from_secs(1)),
+            Err(SpotifyError::InjectedClient)
+        ));
+    }
+
+    const RATE_LIMITED: &str = r#"{"error":{"status":429,"message":"API rate limit exceeded"}}"#;
+
+    #[test]
+    fn rate_limited_requests_are_retried() {
+        let (url, server) = serve_responses(vec![
+            (429, &[("Retry-After", "0")], RATE_LIMITED),
+            (429, &[("Retry-After", "0")], RATE_LIMITED),
+            (200, &[], TRACK),
+        ]);
+        let spotify = playlist_client(url).with_retry(RetryConfig::default());
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(
+            requests
+                .iter()
+                .all(|request| request.starts_with("GET /tracks/11dFghVXANMlKmJXsNCbNl?"))
+        );
+    }
+
+    #[test]
+    fn rate_limited_retries_are_exhausted() {
+        let (url, server) =
+            serve_responses(vec![(429, &[], RATE_LIMITED), (429, &[], RATE_LIMITED)]);
+        let spotify = playlist_client(url).with_retry(RetryConfig {
+            max_retries: 1,
+            respect_retry_after: false,
+            base_backoff: Duration::from_millis(1),
+            ..RetryConfig::default()
+        });
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap_err();
+
+        assert!(
+            matches!(err, ApiError::SpotifyObjectWithStatus { status, .. } if status == http::StatusCode::TOO_MANY_REQUESTS)
+        );
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn rate_limited_requests_are_retried_async() {
+        let (url, server) = serve_responses(vec![
+            (429, &[("Retry-After", "0")], RATE_LIMITED),
+            (429, &[("Retry-After", "0")], RATE_LIMITED),
+            (200, &[], TRACK),
+        ]);
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token(Token::from_json(TOKEN).unwrap())
+            .with_retry(RetryConfig::default());
+        spotify.api_url = url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query_async(&spotify)
+            .await
+            .unwrap();
+
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn retry_delay() {
+        let rate_limited = |retry_after: Option<&str>| {
+            let mut response = HttpResponse::builder().status(429);
+            if let Some(retry_after) = retry_after {
+                response = response.header("Retry-After", retry_after);
+            }
+            response.body(Bytes::new()).unwrap()
+        };
+        let config = RetryConfig {
+            max_retries: 2,
+            respect_retry_after: true,
+            base_backoff: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        };
+
+        assert_eq!(
+            config.delay(0, &rate_limited(Some("3"))),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            config.delay(1, &rate_limited(None)),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(config.delay(2, &rate_limited(Some("3"))), None);
+        assert_eq!(
+            RetryConfig {
+                respect_retry_after: false,
+                ..config
+            }
+            .delay(0, &rate_limited(Some("3"))),
+            Some(Duration::from_millis(100))
+        );
+
+        assert_eq!(
+            config.delay(0, &rate_limited(Some("86400"))),
+            Some(Duration::from_secs(5))
+        );
+
+        let ok = HttpResponse::builder().body(Bytes::new()).unwrap();
+        assert_eq!(config.delay(0, &ok), None);
+    }
+
+    #[test]
+    fn request_timeout() {
+        // The listener accepts connections but never answers them.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+        let spotify = playlist_client(url)
+            .with_timeout(Duration::from_millis(1))
+            .unwrap();
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap_err();
+
+        assert!(
+            matches!(&err, ApiError::Client(RestError::Timeout(err)) if err.is_timeout()),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn connection_refused() {
+        // Nothing listens on the port once the listener has been dropped.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        drop(listener);
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&playlist_client(url))
+            .unwrap_err();
+
+        assert!(
+            matches!(&err, ApiError::Client(RestError::Connect(err)) if err.is_connect()),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn truncated_body() {
+        use std::io::{BufRead as _, Write as _};
+
+        // The connection is closed before the announced body has been sent.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = std::io::BufReader::new(stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1000\r\n\r\n{")
+                .unwrap();
+        });
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&playlist_client(url))
+            .unwrap_err();
+        server.join().unwrap();
+
+        assert!(
+            matches!(&err, ApiError::Client(RestError::Decode(err)) if err.is_decode()),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn authorization_url_uses_accounts_url() {
+        let url = Url::parse("http://localhost:8080/").unwrap();
+        let mut spotify =
+            Spotify::with_authorization_code_pkce("client_id", "http://localhost/callback", None)
+                .unwrap()
+                .with_accounts_url(url);
+
+        assert!(
+            spotify
+                .user_authorization_url()
+                .starts_with("http://localhost:8080/authorize?")
+        );
+    }
+
+    const SNAPSHOT: &str = r#"{"snapshot_id":"AAAAB8C+GbF4WXCnzsWxNkBlXDgASCHl"}"#;
+
+    fn playlist_client(api_url: Url) -> Spotify<ClientCredentials> {
+        let token = Token::from_json(TOKEN).unwrap();
+        let mut spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token(token);
+        spotify.api_url = api_url;
+        spotify
+    }
+
+    #[test]
+    fn playlist_changed_since() {
+        let (url, server) = serve_once(200, SNAPSHOT);
+        let spotify = playlist_client(url);
+        assert!(
+            !spotify
+                .playlist_changed_since(
+                    "3cEYpjA9oz9GiPac4AsH4n",
+                    "AAAAB8C+GbF4WXCnzsWxNkBlXDgASCHl"
+                )
+                .unwrap()
+        );
+        let request = server.join().unwrap();
+        assert!(
+            request
+                .starts_with("GET /playlists/3cEYpjA9oz9GiPac4AsH4n?fields=snapshot_id HTTP/1.1")
+        );
+
+        let (url, _server) = serve_once(200, SNAPSHOT);
+        let spotify = playlist_client(url);
+        assert!(
+            spotify
+                .playlist_changed_since(
+                    "3cEYpjA9oz9GiPac4AsH4n",
+                    "AAAAA1xd8RsOIGu6bm2wAMzqzLwCJYxA"
+                )
+                .unwrap()
+        );
+    }
+
+    const REFRESHED_TOKEN: &str = r#"{"access_token":"token","token_type":"Bearer","scope":"user-read-private","expires_in":3600}"#;
+
+    #[test]
+    fn pkce_with_refresh_token() {
+        let (url, server) = serve_once(200, REFRESHED_TOKEN);
+
+        let spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_accounts_url(url)
+            .with_refresh_token("refresh_token")
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /api/token HTTP/1.1"));
+
+        let token = spotify.token();
+        let token = token.read();
+        let token = token.as_ref().unwrap();
+        assert_eq!(token.access_token, "token");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh_token"));
+        assert!(!token.is_expired());
+    }
+
+    #[tokio::test]
+    async fn pkce_with_refresh_token_async() {
+        let (url, server) = serve_once(200, REFRESHED_TOKEN);
+
+        let spotify = AsyncSpotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_accounts_url(url)
+            .with_refresh_token("refresh_token")
+            .await
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /api/token HTTP/1.1"));
+        assert_eq!(
+            spotify.token().read().as_ref().unwrap().access_token,
+            "token"
+        );
+    }
+
+    #[test]
+    fn token_string_round_trip() {
+        let token = Token::from_json(
+            r#"{"access_token":"token","token_type":"Bearer","scope":"user-read-private","expires_in":3600,"refresh_token":"refresh_token"}"#,
+        )
+        .unwrap();
+
+        let stored = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token(token.clone())
+            .token_to_string()
+            .unwrap()
+            .unwrap();
+
+        let mut restored = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token_str(&stored)
+            .unwrap();
+
+        assert_eq!(restored.token().read().as_ref(), Some(&token));
+        assert!(
+            restored
+                .user_authorization_url()
+                .contains("scope=user-read-private")
+        );
+
+        let err = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token_str("not a token")
+            .unwrap_err();
+        assert!(matches!(err, SpotifyError::DataType { .. }));
+    }
+
+    #[tokio::test]
+    async fn token_string_round_trip_async() {
+        let token = Token::from_json(REFRESHED_TOKEN).unwrap();
+
+        let stored = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token(token.clone())
+            .token_to_string()
+            .unwrap()
+            .unwrap();
+
+        let restored = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token_str(&stored)
+            .unwrap();
+
+        assert_eq!(
+            restored
+                .token()
+                .read()
+                .as_ref()
+                .map(|token| &token.access_token),
+            Some(&token.access_token)
+        );
+    }
+
+    const PLAYLIST_TOKEN: &str = r#"{"access_token":"token","token_type":"Bearer","scope":"playlist-read-private","expires_in":3600}"#;
+
+    #[test]
+    fn missing_scope_fails_before_request() {
+        let mut spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token_str(PLAYLIST_TOKEN)
+            .unwrap();
+        // Nothing listens here, so any request which is sent fails to connect.
+        spotify.api_url = Url::parse("http://127.0.0.1:9/").unwrap();
+
+        let err = spotify.current_user_profile().unwrap_err();
+
+        assert!(matches!(
+            err,
+            SpotifyError::Api(ApiError::InsufficientScope {
+                needed: Some(Scope::UserReadPrivate),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn granted_scope_is_sent() {
+        let (url, server) = serve_once(200, "{}");
+        let mut spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token_str(REFRESHED_TOKEN)
+            .unwrap();
+        spotify.api_url = url;
+
+        api::ignore(GetCurrentUserProfile).query(&spotify).unwrap();
+
+        assert!(server.join().unwrap().starts_with("GET /me"));
+    }
+
+    #[tokio::test]
+    async fn missing_scope_fails_before_request_async() {
+        let mut spotify =
+            AsyncSpotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+                .unwrap()
+                .with_token_str(PLAYLIST_TOKEN)
+                .unwrap();
+        spotify.api_url = Url::parse("http://127.0.0.1:9/").unwrap();
+
+        let err = api::ignore(GetCurrentUserProfile)
+            .query_async(&spotify)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::InsufficientScope {
+                needed: Some(Scope::UserReadPrivate),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn gzip_encoded_response() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write as _;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(TRACK.as_bytes()).unwrap();
+
+        let (url, server) = serve_bytes_once_with_headers(
+            200,
+            &[("Content-Encoding", "gzip")],
+            encoder.finish().unwrap(),
+        );
+        let spotify = playlist_client(url);
+
+        let track: Track = GetTrack::from("11dFghVXANMlKmJXsNCbNl")
+            .query(&spotify)
+            .unwrap();
+        assert_eq!(track.id, "11dFghVXANMlKmJXsNCbNl");
+
+        // Without the feature, the response is decompressed by `query::decode_body` instead.
+        let request = server.join().unwrap().to_ascii_lowercase();
+        #[cfg(feature = "gzip")]
+        assert!(request.contains("accept-encoding: gzip"));
+        #[cfg(not(feature = "gzip"))]
+        assert!(!request.contains("accept-encoding"));
+    }
+
+    fn expiring_token(expires_in: TimeDelta) -> Token {
+        let mut token = Token::from_json(
+            r#"{"access_token":"old","token_type":"Bearer","expires_in":3600,"refresh_token":"refresh_token"}"#,
+        )
+        .unwrap();
+        token.expires_at = Some(Utc::now() + expires_in);
+        token
+    }
+
+    #[test]
+    fn token_is_refreshed_within_skew() {
+        let (accounts_url, accounts) = serve_once(200, REFRESHED_TOKEN);
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let mut spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_accounts_url(accounts_url)
+            .with_token(expiring_token(TimeDelta::seconds(20)));
+        spotify.api_url = api_url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        assert!(
+            accounts
+                .join()
+                .unwrap()
+                .starts_with("POST /api/token HTTP/1.1")
+        );
+        assert!(api.join().unwrap().contains("Bearer token"));
+        assert_eq!(spotify.token_metrics().refresh_count, 1);
+    }
+
+    #[test]
+    fn token_is_not_refreshed_outside_skew() {
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let mut spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token(expiring_token(TimeDelta::seconds(20)))
+            .with_refresh_skew(Duration::from_secs(5));
+        spotify.api_url = api_url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        assert!(api.join().unwrap().contains("Bearer old"));
+        assert_eq!(spotify.token_metrics().refresh_count, 0);
+    }
+
+    #[tokio::test]
+    async fn token_is_refreshed_within_skew_async() {
+        let (accounts_url, accounts) = serve_once(200, REFRESHED_TOKEN);
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let mut spotify =
+            AsyncSpotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+                .unwrap()
+                .with_accounts_url(accounts_url)
+                .with_token(expiring_token(TimeDelta::minutes(1)))
+                .with_refresh_skew(Duration::from_secs(120));
+        spotify.api_url = api_url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query_async(&spotify)
+            .await
+            .unwrap();
+
+        assert!(
+            accounts
+                .join()
+                .unwrap()
+                .starts_with("POST /api/token HTTP/1.1")
+        );
+        assert!(api.join().unwrap().contains("Bearer token"));
+        assert_eq!(spotify.token_metrics().refresh_count, 1);
+    }
+
+    fn token_without_refresh(expires_in: TimeDelta) -> Token {
+        let mut token = Token::from_json(TOKEN).unwrap();
+        token.expires_at = Some(Utc::now() + expires_in);
+        token
+    }
+
+    #[test]
+    fn expired_token_callback() {
+        let expired = Arc::new(parking_lot::Mutex::new(None));
+
+        let spotify =
+            Spotify::with_authorization_code("client_id", "client_secret", "redirect_uri", None)
+                .unwrap()
+                .with_token(token_without_refresh(TimeDelta::minutes(-1)))
+                .on_token_expired({
+                    let expired = expired.clone();
+                    move |token| *expired.lock() = Some(token)
+                });
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Auth(AuthError::ExpiredAccessToken)));
+        assert_eq!(expired.lock().as_ref().unwrap().access_token, "token");
+    }
+
+    #[test]
+    fn unexpired_token_does_not_call_expired_callback() {
+        let (api_url, api) = serve_once(200, TRACK);
+        let called = Arc::new(AtomicUsize::new(0));
+
+        let mut spotify =
+            Spotify::with_authorization_code("client_id", "client_secret", "redirect_uri", None)
+                .unwrap()
+                .with_token(token_without_refresh(TimeDelta::seconds(20)))
+                .on_token_expired({
+                    let called = called.clone();
+                    move |_| {
+                        called.fetch_add(1, Ordering::SeqCst);
+                    }
+                });
+        spotify.api_url = api_url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        assert!(api.join().unwrap().contains("Bearer token"));
+        assert_eq!(called.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn granted_scopes() {
+        let spotify =
+            Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None).unwrap();
+        assert_eq!(spotify.granted_scopes(), None);
+
+        let spotify = spotify.with_token(
+            Token::from_json(
+                r#"{"access_token":"token","token_type":"Bearer","scope":"user-read-private playlist-read-private user-read-email","expires_in":3600}"#,
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            spotify.granted_scopes(),
+            Some(HashSet::from([
+                Scope::UserReadPrivate,
+                Scope::PlaylistReadPrivate,
+                Scope::UserReadEmail,
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn granted_scopes_async() {
+        let (accounts_url, _accounts) = serve_once(200, PLAYLIST_TOKEN);
+
+        let spotify = AsyncSpotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_accounts_url(accounts_url)
+            .with_refresh_token("refresh_token")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            spotify.granted_scopes(),
+            Some(HashSet::from([Scope::PlaylistReadPrivate]))
+        );
+    }
+
+    #[test]
+    fn client_credentials_granted_scopes_are_unknown() {
+        let spotify = playlist_client(Url::parse("http://localhost").unwrap());
+        assert_eq!(spotify.granted_scopes(), None);
+    }
+
+    #[test]
+    fn base_urls_point_at_mock() {
+        let (accounts_url, accounts) = serve_once(200, TOKEN);
+        let (api_url, api) = serve_once(200, TRACK);
+
+        // The missing trailing slash is added, so `v1` is kept when joining endpoints.
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_base_url(api_url.join("v1").unwrap())
+            .with_accounts_url(accounts_url);
+        spotify.request_token().unwrap();
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        assert!(
+            accounts
+                .join()
+                .unwrap()
+                .starts_with("POST /api/token HTTP/1.1")
+        );
+        assert!(
+            api.join()
+                .unwrap()
+                .starts_with("GET /v1/tracks/11dFghVXANMlKmJXsNCbNl?")
+        );
+    }
+
+    #[tokio::test]
+    async fn base_urls_point_at_mock_async() {
+        let (accounts_url, accounts) = serve_once(200, TOKEN);
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_base_url(api_url.join("v1/").unwrap())
+            .with_accounts_url(accounts_url);
+        spotify.request_token().await.unwrap();
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query_async(&spotify)
+            .await
+            .unwrap();
+
+        assert!(
+            accounts
+                .join()
+                .unwrap()
+                .starts_with("POST /api/token HTTP/1.1")
+        );
+        assert!(
+            api.join()
+                .unwrap()
+                .starts_with("GET /v1/tracks/11dFghVXANMlKmJXsNCbNl?")
+        );
+    }
+
+    const REACQUIRED_TOKEN: &str =
+        r#"{"access_token":"reacquired","token_type":"Bearer","expires_in":3600}"#;
+
+    #[test]
+    fn expired_client_credentials_token_is_reacquired() {
+        let (accounts_url, accounts) = serve_once(200, REACQUIRED_TOKEN);
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let mut spotify = Spotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(accounts_url)
+            .with_token(token_without_refresh(TimeDelta::minutes(-1)))
+            .on_token_expired(|_| panic!("the token should have been reacquired"));
+        spotify.api_url = api_url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query(&spotify)
+            .unwrap();
+
+        let accounts = accounts.join().unwrap();
+        assert!(accounts.starts_with("POST /api/token HTTP/1.1"));
+        assert!(accounts.to_lowercase().contains("authorization: basic "));
+        assert!(api.join().unwrap().contains("Bearer reacquired"));
+        assert_eq!(spotify.token_metrics().refresh_count, 1);
+    }
+
+    #[tokio::test]
+    async fn expired_client_credentials_token_is_reacquired_async() {
+        let (accounts_url, accounts) = serve_once(200, REACQUIRED_TOKEN);
+        let (api_url, api) = serve_once(200, TRACK);
+
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_accounts_url(accounts_url)
+            .with_token(token_without_refresh(TimeDelta::minutes(-1)));
+        spotify.api_url = api_url;
+
+        api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query_async(&spotify)
+            .await
+            .unwrap();
+
+        assert!(
+            accounts
+                .join()
+                .unwrap()
+                .starts_with("POST /api/token HTTP/1.1")
+        );
+        assert!(api.join().unwrap().contains("Bearer reacquired"));
+        assert_eq!(spotify.token_metrics().refresh_count, 1);
+    }
+
+    #[tokio::test]
+    async fn expired_token_callback_async() {
+        let called = Arc::new(AtomicUsize::new(0));
+
+        let spotify = AsyncSpotify::with_authorization_code(
+            "client_id",
+            "client_secret",
+            "redirect_uri",
+            None,
+        )
+        .unwrap()
+        .with_token(token_without_refresh(TimeDelta::minutes(-1)))
+        .on_token_expired({
+            let called = called.clone();
+            move |_| {
+                called.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let err = api::ignore(GetTrack::from("11dFghVXANMlKmJXsNCbNl"))
+            .query_async(&spotify)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Auth(AuthError::ExpiredAccessToken)));
+        assert_eq!(called.load(Ordering::SeqCst), 1);
+    }
+
+    const PLAYLIST: &str = r#"{
+        "collaborative": false,
+        "description": null,
+        "external_urls": { "spotify": "string" },
+        "followers": { "href": null, "total": 0 },
+        "href": "string",
+        "id": "3cEYpjA9oz9GiPac4AsH4n",
+        "images": [],
+        "name": "Spotify Web API Testing playlist",
+        "owner": {
+            "external_urls": { "spotify": "string" },
+            "href": "string",
+            "id": "jmperezperez",
+            "type": "user",
+            "uri": "string",
+            "display_name": null
+        },
+        "primary_color": null,
+        "public": true,
+        "snapshot_id": "AAAAB8C+GbF4WXCnzsWxNkBlXDgASCHl",
+        "tracks": {
+            "href": "string",
+            "limit": 100,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 0,
+            "items": []
+        },
+        "type": "playlist",
+        "uri": "spotify:playlist:3cEYpjA9oz9GiPac4AsH4n"
+    }"#;
+
+    #[test]
+    fn get_by_url_track() {
+        let (url, server) = serve_once(200, TRACK);
+        let spotify = playlist_client(url);
+
+        let resource = spotify
+            .get_by_url("https://open.spotify.com/track/11dFghVXANMlKmJXsNCbNl?si=abc")
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /tracks/11dFghVXANMlKmJXsNCbNl"));
+        let SpotifyResource::Track(track) = resource else {
+            panic!("unexpected resource: {resource:?}");
+        };
+        assert_eq!(track.name, "Cut To The Feeling");
+    }
+
+    #[tokio::test]
+    async fn get_by_url_playlist() {
+        let (url, server) = serve_once(200, PLAYLIST);
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token(Token::from_json(TOKEN).unwrap());
+        spotify.api_url = url;
+
+        let resource = spotify
+            .get_by_url("spotify:playlist:3cEYpjA9oz9GiPac4AsH4n")
+            .await
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /playlists/3cEYpjA9oz9GiPac4AsH4n"));
+        let SpotifyResource::Playlist(playlist) = resource else {
+            panic!("unexpected resource: {resource:?}");
+        };
+        assert_eq!(playlist.name, "Spotify Web API Testing playlist");
+    }
+
+    #[test]
+    fn current_user_profile() {
+        const PROFILE: &str = r#"{
+            "display_name": "JM Wizzler",
+            "external_urls": { "spotify": "string" },
+            "followers": { "href": null, "total": 0 },
+            "href": "string",
+            "id": "wizzler",
+            "images": [],
+            "type": "user",
+            "uri": "spotify:user:wizzler"
+        }"#;
+
+        let (url, server) = serve_once(200, PROFILE);
+        let spotify = playlist_client(url);
+
+        let profile = spotify.current_user_profile().unwrap();
+
+        assert!(server.join().unwrap().starts_with("GET /me?"));
+        assert_eq!(profile.id, "wizzler");
+        assert_eq!(profile.display_name.as_deref(), Some("JM Wizzler"));
+    }
+
+    #[test]
+    fn search() {
+        let (url, server) = serve_once(200, "{}");
+        let spotify = playlist_client(url);
+
+        let results = spotify
+            .search("tania bowra", [SearchType::Artist, SearchType::Track])
+            .unwrap();
+
+        assert!(
+            server
+                .join()
+                .unwrap()
+                .starts_with("GET /search?q=tania+bowra&type=artist%2Ctrack ")
+        );
+        assert!(results.artists.is_none());
+    }
+
+    #[tokio::test]
+    async fn track_async() {
+        let (url, server) = serve_once(200, TRACK);
+        let mut spotify = AsyncSpotify::with_client_credentials("client_id", "client_secret")
+            .unwrap()
+            .with_token(Token::from_json(TOKEN).unwrap());
+        spotify.api_url = url;
+
+        let id = TrackId::from_id("11dFghVXANMlKmJXsNCbNl").unwrap();
+        let track = spotify.track(&id).await.unwrap();
+
+        assert!(
+            server
+                .join()
+                .unwrap()
+                .starts_with("GET /tracks/11dFghVXANMlKmJXsNCbNl?")
+        );
+        assert_eq!(track.name, "Cut To The Feeling");
+    }
+
+    #[cfg(feature = "markets")]
+    #[test]
+    fn track_availability() {
+        let mut track = fixtures::track("11dFghVXANMlKmJXsNCbNl");
+        track["available_markets"] = serde_json::json!(["CA", "US"]);
+
+        let (url, server) = serve_bytes_once_with_headers(200, &[], track.to_string().into_bytes());
+        let spotify = playlist_client(url);
+
+        let availability = spotify
+            .track_availability(
+                "11dFghVXANMlKmJXsNCbNl",
+                &["US".parse().unwrap(), "JP".parse().unwrap()],
+            )
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /tracks/11dFghVXANMlKmJXsNCbNl?"));
+        assert_eq!(availability.len(), 2);
+        assert!(availability["US"]);
+        assert!(!availability["JP"]);
+    }
+
+    #[cfg(not(feature = "markets"))]
+    #[test]
+    fn track_availability() {
+        let mut track = fixtures::track("11dFghVXANMlKmJXsNCbNl");
+        track["is_playable"] = false.into();
+        track["restrictions"] = serde_json::json!({ "reason": "market" });
+
+        let (url, server) = serve_bytes_once_with_headers(200, &[], track.to_string().into_bytes());
+        let spotify = playlist_client(url);
+
+        let availability = spotify
+            .track_availability("11dFghVXANMlKmJXsNCbNl", &["JP".parse().unwrap()])
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /tracks/11dFghVXANMlKmJXsNCbNl?market=JP"));
+        assert!(!availability["JP"]);
+    }
+
+    #[test]
+    fn top_tracks() {
+        const TOP_TRACKS: &str = r#"{
+            "href": "string",
+            "limit": 5,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1,
+            "items": [{
+                "album": {
+                    "album_type": "album",
+                    "total_tracks": 1,
+                    "external_urls": { "spotify": "string" },
+                    "href": "string",
+                    "id": "string",
+                    "images": [],
+                    "name": "string",
+                    "type": "album",
+                    "uri": "string",
+                    "artists": []
+                },
+                "artists": [],
+                "disc_number": 1,
+                "duration_ms": 1000,
+                "explicit": false,
+                "external_ids": {},
+                "external_urls": { "spotify": "string" },
+                "href": "string",
+                "id": "11dFghVXANMlKmJXsNCbNl",
+                "name": "Cut To The Feeling",
+                "popularity": 0,
+                "track_number": 1,
+                "type": "track",
+                "uri": "spotify:track:11dFghVXANMlKmJXsNCbNl",
+                "is_local": false
+            }]
+        }"#;
+
+        let (url, server) = serve_once(200, TOP_TRACKS);
+        let mut spotify = Spotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+            .unwrap()
+            .with_token(Token::from_json(TOKEN).unwrap());
+        spotify.api_url = url;
+
+        let tracks = spotify.top_tracks(TimeRange::ShortTerm, 5).unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /me/top/tracks?time_range=short_term&offset=0&limit=5 "));
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].name, "Cut To The Feeling");
+    }
+
+    #[tokio::test]
+    async fn top_artists() {
+        let top_artists = serde_json::json!({
+            "href": "string",
+            "limit": 10,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1,
+            "items": [fixtures::artist("0TnOYISbd1XYRBk9myaseg")]
+        });
+
+        let (url, server) =
+            serve_bytes_once_with_headers(200, &[], top_artists.to_string().into_bytes());
+        let mut spotify =
+            AsyncSpotify::with_authorization_code_pkce("client_id", "redirect_uri", None)
+                .unwrap()
+                .with_token(Token::from_json(TOKEN).unwrap());
+        spotify.api_url = url;
+
+        let artists = spotify.top_artists(TimeRange::LongTerm, 10).await.unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /me/top/artists?time_range=long_term&offset=0&limit=10 "));
+        assert_eq!(artists.len(), 1);
+        assert_eq!(artists[0].name, "Pitbull");
+    }
+
+    #[test]
+    fn get_by_url_user() {
+        let spotify = Spotify::with_client_credentials("client_id", "client_secret").unwrap();
+
+        let err = spotify
+            .get_by_url("https://open.spotify.com/user/smedjan")
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SpotifyError::Id(IdError::UnsupportedItemType(ItemType::User))
+        ));
+    }
+
+    #[cfg(all(debug_assertions, feature = "tokio"))]
+    #[tokio::test]
+    async fn blocking_client_in_async_runtime_warns_once() {
+        crate::test::logger::init();
+        let warned = AtomicBool::new(false);
+
+        assert!(warn_if_in_async_runtime(&warned));
+        assert!(warn_if_in_async_runtime(&warned));
+
+        let logged = crate::test::logger::logged();
+        assert_eq!(logged.len(), 1, "{logged:?}");
+        assert_eq!(logged[0].0, log::Level::Warn);
+        assert!(logged[0].1.contains("use `AsyncSpotify` instead"));
+    }
+
+    #[cfg(all(debug_assertions, feature = "tokio"))]
+    #[test]
+    fn blocking_client_outside_async_runtime() {
+        crate::test::logger::init();
+        let warned = AtomicBool::new(false);
+
+        assert!(!warn_if_in_async_runtime(&warned));
+        assert!(crate::test::logger::logged().is_empty());
+    }
+}
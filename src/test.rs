@@ -1,4 +1,8 @@
 pub mod client;
+pub mod fixtures;
+#[cfg(all(debug_assertions, feature = "tokio"))]
+pub mod logger;
+pub mod server;
 
 /// Asserts that the given JSON string can be deserialized into the given type.
 macro_rules! assert_deserialized {
@@ -31,6 +31,9 @@ pub struct ExpectedUrl {
 
     // #[builder(default = "false")]
     pub paginated: bool,
+
+    // #[builder(default)]
+    pub response_headers: Vec<(&'static str, &'static str)>,
 }
 
 impl ExpectedUrl {
@@ -68,6 +71,16 @@ impl ExpectedUrl {
         assert_eq!(url.fragment(), None);
     }
 
+    /// Whether a request matches this expectation, without asserting on it.
+    fn matches(&self, method: &Method, url: &Url) -> bool {
+        let mut query = url.query_pairs().collect::<Vec<_>>();
+        let mut expected = self.query.clone();
+        query.sort();
+        expected.sort();
+
+        self.method == method && url.path() == format!("/v1/{}", self.endpoint) && query == expected
+    }
+
     #[inline(always)]
     fn is_pagination_key(key: &str) -> bool {
         matches!(key, "limit" | "offset")
@@ -82,6 +95,7 @@ pub struct ExpectedUrlBuilder {
     body: Option<Vec<u8>>,
     status: StatusCode,
     paginated: bool,
+    response_headers: Vec<(&'static str, &'static str)>,
 }
 
 impl ExpectedUrlBuilder {
@@ -122,6 +136,11 @@ impl ExpectedUrlBuilder {
         self
     }
 
+    pub fn response_header(&mut self, key: &'static str, value: &'static str) -> &mut Self {
+        self.response_headers.push((key, value));
+        self
+    }
+
     pub fn build(&self) -> ExpectedUrl {
         ExpectedUrl {
             method: self.method.clone(),
@@ -131,6 +150,7 @@ impl ExpectedUrlBuilder {
             body: self.body.clone().unwrap_or_default(),
             status: self.status,
             paginated: self.paginated,
+            response_headers: self.response_headers.clone(),
         }
     }
 }
@@ -145,6 +165,7 @@ impl Default for ExpectedUrlBuilder {
             body: None,
             status: StatusCode::OK,
             paginated: false,
+            response_headers: Vec::new(),
         }
     }
 }
@@ -152,15 +173,19 @@ impl Default for ExpectedUrlBuilder {
 #[derive(Debug, Clone)]
 struct MockResponse {
     status: StatusCode,
+    headers: Vec<(&'static str, &'static str)>,
     data: Vec<u8>,
 }
 
 impl MockResponse {
     fn response(&self) -> Response<Vec<u8>> {
-        Response::builder()
-            .status(self.status)
-            .body(self.data.clone())
-            .unwrap()
+        let mut response = Response::builder().status(self.status);
+
+        for (key, value) in &self.headers {
+            response = response.header(*key, *value);
+        }
+
+        response.body(self.data.clone()).unwrap()
     }
 }
 
@@ -187,6 +212,7 @@ impl SingleTestClient {
         );
         let response = MockResponse {
             status: expected.status,
+            headers: expected.response_headers.clone(),
             data: data.into(),
         };
 
@@ -274,11 +300,86 @@ impl AsyncClient for SingleTestClient {
     }
 }
 
+/// A test client which answers several distinct requests, matched by method, path and query.
+pub struct MultiTestClient {
+    responses: Vec<(ExpectedUrl, Vec<u8>)>,
+    requests: parking_lot::Mutex<Vec<Url>>,
+}
+
+impl MultiTestClient {
+    pub fn new_json<T>(responses: impl IntoIterator<Item = (ExpectedUrl, T)>) -> Self
+    where
+        T: Serialize,
+    {
+        Self {
+            responses: responses
+                .into_iter()
+                .map(|(expected, data)| (expected, serde_json::to_vec(&data).unwrap()))
+                .collect(),
+            requests: parking_lot::Mutex::default(),
+        }
+    }
+
+    /// The URLs which have been requested so far.
+    pub fn requests(&self) -> Vec<Url> {
+        self.requests.lock().clone()
+    }
+}
+
+impl RestClient for MultiTestClient {
+    type Error = TestClientError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!(
+            "https://api.spotify.com/v1/{endpoint}"
+        ))?)
+    }
+}
+
+impl Client for MultiTestClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let url = Url::parse(&format!("{}", request.uri_ref().unwrap())).unwrap();
+        let method = request.method_ref().unwrap();
+
+        let (expected, data) = self
+            .responses
+            .iter()
+            .find(|(expected, _)| expected.matches(method, &url))
+            .expect("no matching request found");
+
+        expected.check(method, &url);
+        assert_eq!(&body, &expected.body);
+        self.requests.lock().push(url);
+
+        Ok(Response::builder()
+            .status(expected.status)
+            .body(data.clone())
+            .unwrap()
+            .map(Into::into))
+    }
+}
+
+#[async_trait]
+impl AsyncClient for MultiTestClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        <Self as Client>::rest(self, request, body)
+    }
+}
+
 const DEFAULT_LIMIT: usize = 20;
 
 pub struct PagedTestClient<T> {
     expected: ExpectedUrl,
     data: Vec<T>,
+    requests: parking_lot::Mutex<Vec<Url>>,
 }
 
 impl<T> PagedTestClient<T> {
@@ -289,8 +390,30 @@ impl<T> PagedTestClient<T> {
         Self {
             expected,
             data: data.into_iter().collect(),
+            requests: parking_lot::Mutex::default(),
         }
     }
+
+    /// The URLs of the pages which have been requested so far.
+    pub fn requests(&self) -> Vec<Url> {
+        self.requests.lock().clone()
+    }
+
+    /// The `offset` and `limit` of the pages which have been requested so far.
+    pub fn page_params(&self) -> Vec<(usize, usize)> {
+        self.requests()
+            .iter()
+            .map(|url| {
+                let param = |name: &str| {
+                    url.query_pairs()
+                        .find(|(key, _)| key == name)
+                        .and_then(|(_, value)| value.parse().ok())
+                        .unwrap()
+                };
+                (param("offset"), param("limit"))
+            })
+            .collect()
+    }
 }
 
 impl<T> RestClient for PagedTestClient<T> {
@@ -315,6 +438,7 @@ where
         let url = Url::parse(&format!("{}", request.uri_ref().unwrap())).unwrap();
 
         self.expected.check(request.method_ref().unwrap(), &url);
+        self.requests.lock().push(url.clone());
 
         assert_eq!(
             &body,
@@ -365,35 +489,36 @@ where
 
         assert_eq!(*request.method(), Method::GET);
 
+        // Like Spotify, page links keep the other query parameters of the request.
+        let page_url = |offset: usize| {
+            let params = url
+                .query_pairs()
+                .filter(|(key, _)| !ExpectedUrl::is_pagination_key(key))
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect::<Vec<_>>();
+            let mut page_url = url.clone();
+            page_url
+                .query_pairs_mut()
+                .clear()
+                .extend_pairs(params)
+                .append_pair("offset", offset.to_string().as_str())
+                .append_pair("limit", limit.to_string().as_str());
+            page_url.to_string()
+        };
+
         let previous = if offset > 0 {
             let previous_offset = if limit > offset {
                 0
             } else {
                 offset.saturating_sub(limit)
             };
-            Some(
-                url.clone()
-                    .query_pairs_mut()
-                    .clear()
-                    .append_pair("offset", previous_offset.to_string().as_str())
-                    .append_pair("limit", limit.to_string().as_str())
-                    .finish()
-                    .to_string(),
-            )
+            Some(page_url(previous_offset))
         } else {
             None
         };
 
         let next = if range.end < self.data.len() {
-            Some(
-                url.clone()
-                    .query_pairs_mut()
-                    .clear()
-                    .append_pair("offset", range.end.to_string().as_str())
-                    .append_pair("limit", limit.to_string().as_str())
-                    .finish()
-                    .to_string(),
-            )
+            Some(page_url(range.end))
         } else {
             None
         };
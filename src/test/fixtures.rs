@@ -0,0 +1,140 @@
+use serde_json::Value;
+
+/// A minimal artist, as returned by the `artists/{id}` endpoint.
+pub const ARTIST: &str = r#"{
+    "external_urls": { "spotify": "string" },
+    "followers": { "href": null, "total": 0 },
+    "genres": [],
+    "href": "string",
+    "id": "0TnOYISbd1XYRBk9myaseg",
+    "images": [],
+    "name": "Pitbull",
+    "popularity": 0,
+    "type": "artist",
+    "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+}"#;
+
+/// A minimal track, as returned by the `tracks/{id}` endpoint.
+pub const TRACK: &str = r#"{
+    "album": {
+        "album_type": "album",
+        "total_tracks": 1,
+        "external_urls": { "spotify": "string" },
+        "href": "string",
+        "id": "string",
+        "images": [],
+        "name": "string",
+        "release_date": "1981-12",
+        "release_date_precision": "month",
+        "type": "album",
+        "uri": "string",
+        "artists": []
+    },
+    "artists": [],
+    "disc_number": 1,
+    "duration_ms": 1000,
+    "explicit": false,
+    "external_ids": {},
+    "external_urls": { "spotify": "string" },
+    "href": "string",
+    "id": "11dFghVXANMlKmJXsNCbNl",
+    "name": "Cut To The Feeling",
+    "popularity": 0,
+    "track_number": 1,
+    "type": "track",
+    "uri": "spotify:track:11dFghVXANMlKmJXsNCbNl",
+    "is_local": false
+}"#;
+
+/// A minimal episode, as returned by the `episodes/{id}` endpoint.
+#[cfg(feature = "shows")]
+pub const EPISODE: &str = r#"{
+    "audio_preview_url": null,
+    "description": "string",
+    "html_description": "string",
+    "duration_ms": 1000,
+    "explicit": false,
+    "external_urls": { "spotify": "string" },
+    "href": "string",
+    "id": "5Xt5DXGzch68nYYamXrNxZ",
+    "images": [],
+    "is_externally_hosted": false,
+    "is_playable": true,
+    "language": "en",
+    "languages": ["en"],
+    "name": "string",
+    "release_date": "1981-12-15",
+    "release_date_precision": "day",
+    "resume_point": { "fully_played": false, "resume_position_ms": 0 },
+    "type": "episode",
+    "uri": "spotify:episode:5Xt5DXGzch68nYYamXrNxZ",
+    "show": {
+        "available_markets": ["US"],
+        "copyrights": [],
+        "description": "string",
+        "html_description": "string",
+        "explicit": false,
+        "external_urls": { "spotify": "string" },
+        "href": "string",
+        "id": "string",
+        "images": [],
+        "is_externally_hosted": false,
+        "languages": ["en"],
+        "media_type": "audio",
+        "name": "string",
+        "publisher": "string",
+        "type": "show",
+        "uri": "string",
+        "total_episodes": 1
+    }
+}"#;
+
+/// A minimal chapter, as listed by the `audiobooks/{id}/chapters` endpoint.
+///
+/// Spotify reports chapters with the `episode` type.
+#[cfg(feature = "audiobooks")]
+pub const CHAPTER: &str = r#"{
+    "chapter_number": 1,
+    "description": "string",
+    "html_description": "string",
+    "duration_ms": 1686230,
+    "explicit": false,
+    "external_urls": { "spotify": "string" },
+    "href": "string",
+    "id": "5Xt5DXGzch68nYYamXrNxZ",
+    "images": [],
+    "languages": ["en"],
+    "name": "string",
+    "release_date": "1981-12-15",
+    "type": "episode",
+    "uri": "spotify:episode:5Xt5DXGzch68nYYamXrNxZ"
+}"#;
+
+/// The [`ARTIST`] fixture with the given ID, to be adjusted further by the test.
+pub fn artist(id: &str) -> Value {
+    with_id(ARTIST, "artist", id)
+}
+
+/// The [`TRACK`] fixture with the given ID, to be adjusted further by the test.
+pub fn track(id: &str) -> Value {
+    with_id(TRACK, "track", id)
+}
+
+/// The [`EPISODE`] fixture with the given ID, to be adjusted further by the test.
+#[cfg(feature = "shows")]
+pub fn episode(id: &str) -> Value {
+    with_id(EPISODE, "episode", id)
+}
+
+/// The [`CHAPTER`] fixture with the given ID, to be adjusted further by the test.
+#[cfg(feature = "audiobooks")]
+pub fn chapter(id: &str) -> Value {
+    with_id(CHAPTER, "episode", id)
+}
+
+fn with_id(fixture: &str, item_type: &str, id: &str) -> Value {
+    let mut item: Value = serde_json::from_str(fixture).unwrap();
+    item["id"] = id.into();
+    item["uri"] = format!("spotify:{item_type}:{id}").into();
+    item
+}
@@ -0,0 +1,53 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use parking_lot::Mutex;
+use std::{
+    sync::Once,
+    thread::{self, ThreadId},
+};
+
+/// A logger which keeps every record, along with the thread that logged it, so that tests
+/// running in parallel only see their own records.
+struct CapturingLogger {
+    records: Mutex<Vec<(ThreadId, Level, String)>>,
+}
+
+static LOGGER: CapturingLogger = CapturingLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        self.records.lock().push((
+            thread::current().id(),
+            record.level(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the capturing logger for warnings and errors, if it is not installed yet.
+pub fn init() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Warn);
+    });
+}
+
+/// The records logged by the current thread so far.
+pub fn logged() -> Vec<(Level, String)> {
+    let current = thread::current().id();
+    LOGGER
+        .records
+        .lock()
+        .iter()
+        .filter(|(thread, _, _)| *thread == current)
+        .map(|(_, level, message)| (*level, message.clone()))
+        .collect()
+}
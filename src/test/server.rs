@@ -0,0 +1,126 @@
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+};
+use url::Url;
+
+/// Serves a single HTTP request on a local port, answering with the given JSON body.
+///
+/// Returns the base URL of the server along with a handle which resolves to the
+/// request line and headers that were received.
+pub fn serve_once(status: u16, body: &'static str) -> (Url, JoinHandle<String>) {
+    serve_once_with_headers(status, &[], body)
+}
+
+/// Like [`serve_once`], but also sends the given response headers.
+pub fn serve_once_with_headers(
+    status: u16,
+    headers: &'static [(&'static str, &'static str)],
+    body: &'static str,
+) -> (Url, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+    let handle = thread::spawn(move || respond(&listener, status, headers, body));
+
+    (url, handle)
+}
+
+/// Serves `count` HTTP requests in sequence, answering each with the given JSON body.
+///
+/// The handle resolves to the request line and headers of every request received.
+pub fn serve_times(
+    count: usize,
+    status: u16,
+    body: &'static str,
+) -> (Url, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+    let handle = thread::spawn(move || {
+        (0..count)
+            .map(|_| respond(&listener, status, &[], body))
+            .collect()
+    });
+
+    (url, handle)
+}
+
+/// A canned response: the status, extra headers and JSON body.
+pub type Response = (u16, &'static [(&'static str, &'static str)], &'static str);
+
+/// Serves one HTTP request per response, answering them in order.
+///
+/// The handle resolves to the request line and headers of every request received.
+pub fn serve_responses(responses: Vec<Response>) -> (Url, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+    let handle = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, headers, body)| respond(&listener, status, headers, body))
+            .collect()
+    });
+
+    (url, handle)
+}
+
+/// Like [`serve_once_with_headers`], but answers with a binary body, such as a compressed one.
+pub fn serve_bytes_once_with_headers(
+    status: u16,
+    headers: &'static [(&'static str, &'static str)],
+    body: Vec<u8>,
+) -> (Url, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+
+    let handle = thread::spawn(move || respond(&listener, status, headers, &body));
+
+    (url, handle)
+}
+
+fn respond(
+    listener: &TcpListener,
+    status: u16,
+    headers: &[(&str, &str)],
+    body: impl AsRef<[u8]>,
+) -> String {
+    let body = body.as_ref();
+    let (stream, _) = listener.accept().unwrap();
+    let mut reader = BufReader::new(stream);
+
+    let mut head = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if let Some((key, value)) = line.split_once(':')
+            && key.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap();
+        }
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+        head.push_str(&line);
+    }
+
+    let mut request_body = vec![0; content_length];
+    reader.read_exact(&mut request_body).unwrap();
+
+    let extra_headers = headers
+        .iter()
+        .map(|(key, value)| format!("{key}: {value}\r\n"))
+        .collect::<String>();
+    let response = format!(
+        "HTTP/1.1 {status} OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{extra_headers}\r\n",
+        body.len(),
+    );
+    let stream = reader.get_mut();
+    stream.write_all(response.as_bytes()).unwrap();
+    stream.write_all(body).unwrap();
+
+    head
+}